@@ -13,9 +13,17 @@
 // limitations under the License.
 
 use crate::{
+    config::Config,
     db::DB,
+    errors::GHDError,
     events,
-    gh::{self, Github},
+    gh::{
+        self,
+        types::{UserRefreshCompleted, UserRefreshEntry},
+        Github,
+    },
+    notifications,
+    state::State,
     ManagedState,
 };
 use log::{debug, error, info, warn};
@@ -23,73 +31,459 @@ use tauri::Manager;
 
 mod types;
 
-pub struct BGTask {}
+pub struct BGTask {
+    n: i64,
+    was_paused: bool,
+    backoff_until: Option<i64>,
+}
 
 impl BGTask {
     pub fn new() -> Self {
-        BGTask {}
+        BGTask {
+            n: 1,
+            was_paused: false,
+            backoff_until: None,
+        }
     }
 
     pub async fn run(self: &mut Self, app: tauri::AppHandle) {
-        let window = app.get_window("main").unwrap();
-        let mstate = app.try_state::<ManagedState>().unwrap();
+        let window = match app.get_window("main") {
+            Some(w) => w,
+            None => {
+                error!("Unable to find main window, not running.");
+                return;
+            }
+        };
+        let mstate = match app.try_state::<ManagedState>() {
+            Some(v) => v,
+            None => {
+                error!("Unable to obtain managed state, not running.");
+                return;
+            }
+        };
 
-        let mut n = 1;
         loop {
-            let state = &mstate.state().await;
-            let db = &state.db;
-            let _cfg = &state.config;
-            let gh = &state.gh;
+            let state = mstate.state();
+            self.iterate(&app, &window, &state).await;
+            let poll_interval = state.get_config().await.poll_interval;
+            self.sleep_for(&mstate, poll_interval).await;
+        }
+    }
+
+    /// Perform a single iteration of the background task, refreshing whichever
+    /// users are due for a refresh.
+    ///
+    async fn iterate(
+        self: &mut Self,
+        app: &tauri::AppHandle,
+        window: &tauri::Window,
+        state: &State,
+    ) {
+        let db = &state.db;
+        let cfg = &state.get_config().await;
+        let gh = &state.gh;
 
-            window.emit("iteration", n).unwrap();
-            n += 1;
+        events::emit(&window, events::EV_ITERATION, self.n);
+        self.n += 1;
+
+        if !has_token(&gh, &db).await {
+            return;
+        }
 
-            if !has_token(&gh, &db).await {
-                self.sleep_for_a_bit().await;
-                continue;
+        // back off for a while if we have recently hit an error.
+        if let Some(until) = self.backoff_until {
+            if chrono::Utc::now().timestamp() < until {
+                return;
             }
+            debug!("error backoff elapsed, resuming");
+            self.backoff_until = None;
+        }
+
+        // don't issue requests while we are rate limited; wait until the rate
+        // limit resets.
+        if let Some(until) = gh.rate_limit_paused_until() {
+            if !self.was_paused {
+                info!("rate limited, pausing until {}", until);
+                events::emit_rate_limit_update(&window, &gh.get_rate_limit());
+                self.was_paused = true;
+            }
+            return;
+        } else if self.was_paused {
+            info!("rate limit reset, resuming");
+            events::emit_rate_limit_update(&window, &gh.get_rate_limit());
+            self.was_paused = false;
+        }
 
-            let to_refresh = gh::refresh::get_to_refresh_users(&db).await;
-            for user in &to_refresh {
-                debug!("should refresh user '{}'", user.login);
-                match gh.refresh_user(&db, &user.login).await {
-                    Ok(true) => {
-                        info!("refreshed user '{}'", user.login);
-                        events::emit_user_data_update(&window, &user.login);
+        let mut to_refresh =
+            match gh::refresh::get_to_refresh_users(&db, &cfg.refresh_interval)
+                .await
+            {
+                Ok(res) => res,
+                Err(err) => {
+                    error!("Unable to obtain users to refresh: {}", err);
+                    self.backoff_until = Some(backoff_ts(cfg.error_backoff));
+                    return;
+                }
+            };
+
+        // users are ordered by priority; if we're running low on budget, only
+        // refresh those with the highest priority and leave the others until
+        // the budget resets.
+        if gh.rate_limit_is_low() {
+            if let Some(top) = to_refresh.first().map(|u| u.priority) {
+                let before = to_refresh.len();
+                to_refresh.retain(|u| u.priority >= top);
+                if to_refresh.len() < before {
+                    debug!(
+                        "rate limit budget low, deferring {} users",
+                        before - to_refresh.len()
+                    );
+                }
+            }
+        }
+        if self
+            .refresh_users(&app, &window, &state, &cfg, &to_refresh)
+            .await
+        {
+            return;
+        }
+
+        // teams, repositories and saved queries have no priority; leave them
+        // until the budget resets if we're running low.
+        let teams = match self
+            .process_each(
+                &state,
+                &cfg,
+                "refreshing team",
+                gh::teams::get_to_refresh_teams(&db, &cfg.refresh_interval),
+                |team| team.combined_slug(),
+                move |team| async move {
+                    match gh.refresh_team(&db, &team).await {
+                        Ok(_) => {
+                            events::emit_team_update(&window, &team);
+                            Ok(())
+                        }
+                        Err(err) => Err(err),
                     }
-                    Ok(false) => {}
-                    Err(crate::errors::GHDError::BadTokenError) => {
-                        warn!("invalidate token");
-                        gh.invalidate_token(&db).await;
-                        continue;
+                },
+            )
+            .await
+        {
+            Ok(n) => n,
+            Err(_) => return,
+        };
+
+        let repos = match self
+            .process_each(
+                &state,
+                &cfg,
+                "refreshing repository",
+                gh::repos::get_to_refresh_repos(&db, &cfg.refresh_interval),
+                |repo| repo.full_name(),
+                move |repo| async move {
+                    match gh.refresh_repo(&db, &repo).await {
+                        Ok(_) => {
+                            events::emit_repo_update(&window, &repo);
+                            Ok(())
+                        }
+                        Err(err) => Err(err),
                     }
-                    Err(err) => {
-                        error!(
-                            "error refreshing user '{}': {:?}",
-                            user.login, err,
-                        );
+                },
+            )
+            .await
+        {
+            Ok(n) => n,
+            Err(_) => return,
+        };
+
+        let queries = match self
+            .process_each(
+                &state,
+                &cfg,
+                "refreshing saved query",
+                gh::saved_queries::get_to_refresh_queries(
+                    &db,
+                    &cfg.refresh_interval,
+                ),
+                |query| query.name.clone(),
+                move |query| async move {
+                    match gh.refresh_query(&db, &query).await {
+                        Ok(_) => {
+                            events::emit_query_update(&window, &query);
+                            Ok(())
+                        }
+                        Err(err) => Err(err),
+                    }
+                },
+            )
+            .await
+        {
+            Ok(n) => n,
+            Err(_) => return,
+        };
+
+        // full reconciliations are the least urgent of all.
+        let reconciled = match self
+            .process_each(
+                &state,
+                &cfg,
+                "reconciling user",
+                gh::refresh::get_to_reconcile_users(
+                    &db,
+                    &cfg.reconcile_interval,
+                ),
+                |user| user.login.clone(),
+                move |user| async move {
+                    match gh.reconcile_user(&db, &user.login).await {
+                        Ok(changes) => {
+                            if !changes.is_empty() {
+                                events::emit_issues_changed(&window, &changes);
+                            }
+                            Ok(())
+                        }
+                        Err(err) => Err(err),
                     }
+                },
+            )
+            .await
+        {
+            Ok(n) => n,
+            Err(_) => return,
+        };
+
+        if !to_refresh.is_empty()
+            || teams > 0
+            || repos > 0
+            || queries > 0
+            || reconciled > 0
+        {
+            events::emit_rate_limit_update(&window, &gh.get_rate_limit());
+        }
+    }
+
+    /// Refresh the provided users concurrently, in batches sharing a single
+    /// search request, running at most as many batches at a time as
+    /// configured. Only one batch runs at a time while the rate limit budget
+    /// is low, and no new batches are started once we have been rate limited
+    /// or our token has been found invalid. Each batch's refreshes are
+    /// committed, and reported, as soon as the batch completes. Returns
+    /// whether the iteration should stop, because we have been rate limited
+    /// or our token has been found invalid.
+    ///
+    async fn refresh_users(
+        self: &mut Self,
+        app: &tauri::AppHandle,
+        window: &tauri::Window,
+        state: &State,
+        cfg: &Config,
+        users: &Vec<UserRefreshEntry>,
+    ) -> bool {
+        let gh = &state.gh;
+        let mut pending = users.chunks(gh::gql::MAX_SEARCH_BATCH_SIZE);
+        let mut running = tokio::task::JoinSet::new();
+        let mut stop = false;
+
+        loop {
+            let limit = if gh.rate_limit_is_low() {
+                1
+            } else {
+                cfg.refresh_concurrency as usize
+            };
+            while !stop && running.len() < limit {
+                if gh.rate_limit_paused_until().is_some() {
+                    stop = true;
+                    break;
                 }
+                let batch = match pending.next() {
+                    Some(b) => b,
+                    None => break,
+                };
+                let logins: Vec<String> =
+                    batch.iter().map(|u| u.login.clone()).collect();
+                debug!("should refresh users {:?}", logins);
+                running.spawn(refresh_users(
+                    app.clone(),
+                    window.clone(),
+                    state.clone(),
+                    logins,
+                ));
             }
 
-            let users = match gh::users::get_tracked_users(&db).await {
-                Ok(res) => res,
-                Err(err) => {
-                    panic!("Unable to obtain tracked users: {:?}", err);
+            let results = match running.join_next().await {
+                None => break,
+                Some(Ok(v)) => v,
+                Some(Err(err)) => {
+                    error!("user refresh task failed: {}", err);
+                    self.backoff_until = Some(backoff_ts(cfg.error_backoff));
+                    continue;
                 }
             };
+            for (login, res) in results {
+                match res {
+                    Ok(_) => {}
+                    Err(GHDError::BadTokenError) => {
+                        // other refreshes may have found the same; only
+                        // invalidate the token once.
+                        if !stop {
+                            warn!("invalidate token");
+                            if let Err(err) =
+                                gh.invalidate_token(&state.db).await
+                            {
+                                error!("Unable to invalidate token: {}", err);
+                                self.backoff_until =
+                                    Some(backoff_ts(cfg.error_backoff));
+                            }
+                        }
+                        stop = true;
+                    }
+                    Err(GHDError::RateLimitedError) => {
+                        warn!(
+                            "rate limited refreshing user '{}', backing off",
+                            login
+                        );
+                        stop = true;
+                    }
+                    Err(err) => {
+                        error!("error refreshing user '{}': {}", login, err);
+                        self.backoff_until =
+                            Some(backoff_ts(cfg.error_backoff));
+                    }
+                };
+            }
+        }
+        stop
+    }
 
-            for user in &users {
-                if gh::refresh::should_refresh_user(&db, &user.login).await {}
+    /// Obtain the entries due for processing through `get`, and process each
+    /// of them through `process`, unless the rate limit budget is low. `what`
+    /// describes the processing, e.g. `refreshing team`, and `name` each
+    /// entry, for logging purposes. We stop processing once we have been rate
+    /// limited, or once we are paused until the rate limit resets, and back
+    /// off if processing an entry fails. Returns the number of entries
+    /// obtained, or an error if they couldn't be obtained or our token has
+    /// been found invalid.
+    ///
+    async fn process_each<T, G, P, F>(
+        self: &mut Self,
+        state: &State,
+        cfg: &Config,
+        what: &str,
+        get: G,
+        name: fn(&T) -> String,
+        process: P,
+    ) -> Result<usize, GHDError>
+    where
+        G: std::future::Future<Output = Result<Vec<T>, GHDError>>,
+        P: Fn(T) -> F,
+        F: std::future::Future<Output = Result<(), GHDError>>,
+    {
+        let gh = &state.gh;
+        if gh.rate_limit_is_low() || gh.rate_limit_paused_until().is_some() {
+            return Ok(0);
+        }
+        let entries = match get.await {
+            Ok(res) => res,
+            Err(err) => {
+                error!("Unable to obtain entries for {}: {}", what, err);
+                self.backoff_until = Some(backoff_ts(cfg.error_backoff));
+                return Err(err);
             }
+        };
 
-            self.sleep_for_a_bit().await;
+        let n = entries.len();
+        for entry in entries {
+            if gh.rate_limit_paused_until().is_some() {
+                debug!("rate limited, stop {}", what);
+                break;
+            }
+            let name = name(&entry);
+            debug!("{} '{}'", what, name);
+            match process(entry).await {
+                Ok(_) => {
+                    info!("done {} '{}'", what, name);
+                }
+                Err(GHDError::BadTokenError) => {
+                    warn!("invalidate token");
+                    if let Err(err) = gh.invalidate_token(&state.db).await {
+                        error!("Unable to invalidate token: {}", err);
+                        self.backoff_until =
+                            Some(backoff_ts(cfg.error_backoff));
+                    }
+                    return Err(GHDError::BadTokenError);
+                }
+                Err(GHDError::RateLimitedError) => {
+                    warn!("rate limited {} '{}', backing off", what, name);
+                    break;
+                }
+                Err(err) => {
+                    error!("error {} '{}': {}", what, name, err);
+                    self.backoff_until = Some(backoff_ts(cfg.error_backoff));
+                }
+            }
         }
+        Ok(n)
+    }
+
+    /// Sleep for `secs` seconds, or until settings have changed.
+    ///
+    async fn sleep_for(self: &Self, mstate: &ManagedState, secs: i64) {
+        let duration = tokio::time::Duration::from_secs(secs as u64);
+        tokio::select! {
+            _ = tokio::time::sleep(duration) => {}
+            _ = mstate.settings_changed.notified() => {
+                debug!("settings changed, waking up");
+            }
+        };
     }
+}
+
+/// Refresh users `logins` as a batch, reporting each user's changes and
+/// notifying about relevant activity once the batch completes. Returns each
+/// user's login along with its refresh's result, so the caller can react to
+/// errors.
+///
+async fn refresh_users(
+    app: tauri::AppHandle,
+    window: tauri::Window,
+    state: State,
+    logins: Vec<String>,
+) -> Vec<(String, Result<(), GHDError>)> {
+    let db = &state.db;
+    let mut results = vec![];
+
+    for (login, res) in state.gh.refresh_users(&db, &logins).await {
+        let (updated, res) = match res {
+            Ok(res) => {
+                if res.updated {
+                    info!("refreshed user '{}'", login);
+                }
+                if !res.changes.is_empty() {
+                    events::emit_issues_changed(&window, &res.changes);
+                }
+                notifications::notify(&app, &db, &res.activity).await;
+                (res.updated, Ok(()))
+            }
+            Err(err) => (false, Err(err)),
+        };
 
-    async fn sleep_for_a_bit(self: &Self) {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        events::emit_user_refreshed(
+            &window,
+            &UserRefreshCompleted {
+                login: login.clone(),
+                updated,
+                error: res.as_ref().err().map(|err| err.to_string()),
+            },
+        );
+        results.push((login, res));
     }
+    results
+}
+
+/// Obtain the timestamp until which we should back off after an error, given
+/// a backoff period of `secs` seconds.
+///
+fn backoff_ts(secs: i64) -> i64 {
+    chrono::Utc::now().timestamp() + secs
 }
 
 async fn has_token(gh: &Github, db: &DB) -> bool {
@@ -14,18 +14,11 @@
 
 use chrono::TimeZone;
 
-pub fn datetime_to_ts(datetime: &str) -> i64 {
-    if datetime.is_empty() {
-        panic!("Provided datetime is empty!");
+pub fn datetime_to_ts(datetime: &str) -> Result<i64, ()> {
+    match chrono::DateTime::parse_from_rfc3339(datetime) {
+        Ok(res) => Ok(res.timestamp()),
+        Err(_) => Err(()),
     }
-
-    let parsed = match chrono::DateTime::parse_from_rfc3339(datetime) {
-        Ok(res) => res,
-        Err(err) => {
-            panic!("Error parsing datetime '{}': {}", datetime, err);
-        }
-    };
-    return parsed.timestamp();
 }
 
 /// Transforms an optional `chrono::DateTime` to an optional `i64` timestamp.
@@ -42,7 +35,10 @@ pub fn ts_to_datetime(ts: i64) -> Result<chrono::DateTime<chrono::Utc>, ()> {
         return Err(());
     }
 
-    Ok(chrono::Utc.timestamp_opt(ts, 0).unwrap())
+    match chrono::Utc.timestamp_opt(ts, 0) {
+        chrono::LocalResult::Single(dt) => Ok(dt),
+        _ => Err(()),
+    }
 }
 
 pub fn has_expired(t: &chrono::DateTime<chrono::Utc>, secs: i64) -> bool {
@@ -12,22 +12,257 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug)]
-pub enum ConfigError {
-    SettingNotFoundError,
-    TokenNotFoundError,
+use log::{debug, warn};
+
+use crate::{db::DB, errors::GHDError};
+
+pub const SETTING_REFRESH_INTERVAL: &str = "refresh_interval";
+pub const SETTING_POLL_INTERVAL: &str = "poll_interval";
+pub const SETTING_ERROR_BACKOFF: &str = "error_backoff";
+pub const SETTING_REFRESH_CONCURRENCY: &str = "refresh_concurrency";
+pub const SETTING_RECONCILE_INTERVAL: &str = "reconcile_interval";
+pub const SETTING_MAX_PAGES: &str = "max_pages";
+
+/// Describes a numeric setting, its default value, and the range of values it
+/// may take.
+///
+struct SettingSpec {
+    key: &'static str,
+    default: i64,
+    min: i64,
+    max: i64,
 }
 
+static SETTINGS: [SettingSpec; 6] = [
+    SettingSpec {
+        key: SETTING_REFRESH_INTERVAL,
+        default: 60,
+        min: 10,
+        max: 86400,
+    },
+    SettingSpec {
+        key: SETTING_POLL_INTERVAL,
+        default: 1,
+        min: 1,
+        max: 60,
+    },
+    SettingSpec {
+        key: SETTING_ERROR_BACKOFF,
+        default: 30,
+        min: 5,
+        max: 3600,
+    },
+    SettingSpec {
+        key: SETTING_REFRESH_CONCURRENCY,
+        default: 4,
+        min: 1,
+        max: 16,
+    },
+    SettingSpec {
+        key: SETTING_RECONCILE_INTERVAL,
+        default: 86400,
+        min: 3600,
+        max: 604800,
+    },
+    SettingSpec {
+        key: SETTING_MAX_PAGES,
+        default: 10,
+        min: 1,
+        max: 100,
+    },
+];
+
 #[derive(sqlx::FromRow)]
 pub struct ConfigEntry {
     pub key: String,
     pub value: String,
 }
 
-pub struct Config {}
+/// GHD's settings, as persisted in the database's `settings` table. Intervals
+/// are in seconds.
+///
+/// * `refresh_interval` - How often a tracked user should be refreshed.
+/// * `poll_interval` - How long the background task sleeps between iterations.
+/// * `error_backoff` - How long to back off for after an unexpected error.
+/// * `refresh_concurrency` - How many users may be refreshed at the same time.
+/// * `reconcile_interval` - How often a tracked user's open Issues and Pull
+///   Requests should be fully reconciled.
+/// * `max_pages` - How many pages of results to follow for a paginated query.
+///
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct Config {
+    pub refresh_interval: i64,
+    pub poll_interval: i64,
+    pub error_backoff: i64,
+    pub refresh_concurrency: i64,
+    pub reconcile_interval: i64,
+    pub max_pages: i64,
+}
 
 impl Default for Config {
     fn default() -> Self {
-        Config {}
+        Config {
+            refresh_interval: get_default(SETTING_REFRESH_INTERVAL),
+            poll_interval: get_default(SETTING_POLL_INTERVAL),
+            error_backoff: get_default(SETTING_ERROR_BACKOFF),
+            refresh_concurrency: get_default(SETTING_REFRESH_CONCURRENCY),
+            reconcile_interval: get_default(SETTING_RECONCILE_INTERVAL),
+            max_pages: get_default(SETTING_MAX_PAGES),
+        }
+    }
+}
+
+impl Config {
+    /// Load settings from the database. Settings that have never been set take
+    /// their default value, as do settings whose persisted value is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    ///
+    pub async fn load(db: &DB) -> Result<Config, GHDError> {
+        let entries = match sqlx::query_as::<_, ConfigEntry>(
+            "SELECT key, value FROM settings",
+        )
+        .fetch_all(db.pool())
+        .await
+        {
+            Ok(res) => res,
+            Err(err) => return Err(GHDError::db("loading settings", err)),
+        };
+
+        let mut cfg = Config::default();
+        for entry in &entries {
+            if get_spec(&entry.key).is_none() {
+                // not ours; e.g., notification settings.
+                continue;
+            }
+            if let Err(err) = cfg.apply(&entry.key, &entry.value) {
+                warn!("Ignoring persisted setting: {}", err);
+            }
+        }
+        debug!("loaded settings: {:?}", cfg);
+        Ok(cfg)
+    }
+
+    /// Validate and persist a new `value` for setting `key`, updating this
+    /// config in place if successful.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `key` - The setting's name.
+    /// * `value` - The setting's new value.
+    ///
+    pub async fn set(
+        self: &mut Self,
+        db: &DB,
+        key: &String,
+        value: &String,
+    ) -> Result<(), GHDError> {
+        let mut cfg = self.clone();
+        if let Err(err) = cfg.apply(&key, &value) {
+            return Err(err);
+        }
+
+        match sqlx::query(
+            "INSERT OR REPLACE INTO settings (key, value) VALUES (?, ?)",
+        )
+        .bind(&key)
+        .bind(value.trim())
+        .execute(db.pool())
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("persisting setting '{}'", key),
+                    err,
+                ));
+            }
+        };
+
+        *self = cfg;
+        Ok(())
+    }
+
+    /// Parse and validate `value` for setting `key`, assigning it if valid.
+    ///
+    fn apply(self: &mut Self, key: &str, value: &str) -> Result<(), GHDError> {
+        if get_spec(&key).is_none() {
+            return Err(GHDError::SettingNotFoundError {
+                key: String::from(key),
+            });
+        }
+
+        let v = match value.trim().parse::<i64>() {
+            Ok(v) => v,
+            Err(_) => {
+                return Err(GHDError::invalid_setting(
+                    key,
+                    &format!("'{}' is not an integer", value),
+                ));
+            }
+        };
+        if let Err(err) = check_range(&key, v) {
+            return Err(err);
+        }
+
+        self.assign(key, v);
+        Ok(())
+    }
+
+    fn assign(self: &mut Self, key: &str, value: i64) {
+        match key {
+            SETTING_REFRESH_INTERVAL => self.refresh_interval = value,
+            SETTING_POLL_INTERVAL => self.poll_interval = value,
+            SETTING_ERROR_BACKOFF => self.error_backoff = value,
+            SETTING_REFRESH_CONCURRENCY => self.refresh_concurrency = value,
+            SETTING_RECONCILE_INTERVAL => self.reconcile_interval = value,
+            SETTING_MAX_PAGES => self.max_pages = value,
+            _ => {}
+        };
+    }
+}
+
+/// Check whether `value` is within the allowed range for setting `key`. This
+/// allows values that override a setting elsewhere, e.g. a user's refresh
+/// interval, to be held to the same bounds.
+///
+/// # Arguments
+///
+/// * `key` - The setting's name.
+/// * `value` - The value to check.
+///
+pub fn check_range(key: &str, value: i64) -> Result<(), GHDError> {
+    let spec = match get_spec(&key) {
+        Some(v) => v,
+        None => {
+            return Err(GHDError::SettingNotFoundError {
+                key: String::from(key),
+            });
+        }
+    };
+    if value < spec.min || value > spec.max {
+        return Err(GHDError::invalid_setting(
+            key,
+            &format!("{} is out of range [{}, {}]", value, spec.min, spec.max),
+        ));
+    }
+    Ok(())
+}
+
+/// Obtain the specification for setting `key`, if it is known.
+///
+fn get_spec(key: &str) -> Option<&'static SettingSpec> {
+    SETTINGS.iter().find(|s| s.key == key)
+}
+
+/// Obtain the default value for setting `key`.
+///
+fn get_default(key: &str) -> i64 {
+    match get_spec(key) {
+        Some(spec) => spec.default,
+        None => 0,
     }
 }
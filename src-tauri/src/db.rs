@@ -12,69 +12,130 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use log::{debug, info};
-use sqlx::{migrate::MigrateDatabase, sqlite::SqliteQueryResult, SqlitePool};
+use std::str::FromStr;
+
+use log::{debug, error, info};
+use sqlx::{
+    migrate::MigrateDatabase,
+    sqlite::{
+        SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions,
+        SqliteQueryResult,
+    },
+    SqlitePool,
+};
 
 use crate::errors::GHDError;
 
 /// Current DB Schema version
 ///
 // version 2: add 'invalid' token table column
+// version 3: add 'api_url' token table column
+// version 4: add 'issue_assignees' table
+// version 5: add pull request info cache tables
+// version 6: add pull request CI checks state
+// version 7: add pull request mergeability and merge queue state
+// version 8: add issue comment counts and pull request review requests
+// version 9: add per user refresh interval and priority
+// version 10: add teams and team review requests
+// version 11: add tracked repositories
+// version 12: add saved search queries
+// version 13: add issue mentions
+// version 14: add user involvement to user issues
+// version 15: add user reconciliation time
+// version 16: add sync cursors
 //
-const GHD_DB_VERSION: u32 = 2;
+const GHD_DB_VERSION: u32 = 16;
+
+/// Number of seconds a connection waits for another to release its lock on
+/// the database, before failing.
+///
+const GHD_DB_BUSY_TIMEOUT_SECS: u64 = 30;
 
+/// A handle to GHD's database. Only obtainable through `DB::open()`, so a
+/// handle is always connected.
+///
+#[derive(Clone)]
 pub struct DB {
     pub uri: String,
-    pub pool: Option<SqlitePool>,
+    pool: SqlitePool,
+    writer: SqlitePool,
 }
 
 impl DB {
-    pub fn new(path: &std::path::PathBuf) -> DB {
+    /// Open the database at `path`, creating it if it does not exist, or
+    /// migrating it to the current schema version if needed. Refreshes run
+    /// concurrently, so the database is opened in WAL mode, letting readers
+    /// proceed alongside a writer; transactions are run one at a time, through
+    /// `DB::begin()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The database file's path.
+    ///
+    pub async fn open(path: &std::path::PathBuf) -> Result<DB, GHDError> {
         let uri = format!("sqlite://{}", path.display());
-
-        DB { uri, pool: None }
-    }
-
-    pub async fn connect(self: &mut Self) {
-        if let Some(_) = self.pool {
-            panic!("Attempting to connect to connected database!");
+        if let Err(err) = setup(&uri).await {
+            return Err(err);
         }
 
-        self.pool =
-            Some(SqlitePool::connect(&self.uri).await.unwrap_or_else(|_| {
-                panic!("Unable to open database!");
-            }));
-    }
-
-    pub async fn setup(self: Self) -> Self {
-        if !sqlx::Sqlite::database_exists(&self.uri)
+        let opts = match SqliteConnectOptions::from_str(&uri) {
+            Ok(v) => v.journal_mode(SqliteJournalMode::Wal).busy_timeout(
+                std::time::Duration::from_secs(GHD_DB_BUSY_TIMEOUT_SECS),
+            ),
+            Err(err) => return Err(GHDError::db("parsing database uri", err)),
+        };
+        let pool = match SqlitePool::connect_with(opts.clone()).await {
+            Ok(pool) => pool,
+            Err(err) => return Err(GHDError::db("opening database", err)),
+        };
+        // transactions read before they write; concurrent transactions would
+        // fail to upgrade to a write lock, rather than wait for it.
+        match SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(opts)
             .await
-            .unwrap_or(false)
         {
-            sqlx::Sqlite::create_database(&self.uri).await.unwrap();
-            match create_db_schema(&self.uri).await {
-                Ok(_) => info!("Database created successfully."),
-                Err(err) => panic!("{}", err),
-            };
-        } else {
-            debug!("database exists, maybe migrate?");
-            match maybe_migrate(&self.uri).await {
-                Ok(_) => {}
-                Err(err) => panic!("error migrating db: {:?}", err),
-            };
+            Ok(writer) => Ok(DB { uri, pool, writer }),
+            Err(err) => Err(GHDError::db("opening database writer", err)),
         }
+    }
 
-        self
+    /// Begin a transaction. Only one transaction runs at a time; others wait
+    /// for it to finish.
+    ///
+    pub async fn begin(
+        self: &Self,
+    ) -> Result<sqlx::Transaction<'static, sqlx::Sqlite>, sqlx::Error> {
+        self.writer.begin().await
     }
 
     pub fn pool(self: &Self) -> &SqlitePool {
-        match &self.pool {
-            Some(pool) => pool,
-            None => {
-                panic!("Attempting to obtain pool for unconnected database!");
+        &self.pool
+    }
+}
+
+/// Create the database at `uri` if it does not exist, or migrate it if it
+/// does.
+///
+async fn setup(uri: &str) -> Result<(), GHDError> {
+    if !sqlx::Sqlite::database_exists(&uri).await.unwrap_or(false) {
+        if let Err(err) = sqlx::Sqlite::create_database(&uri).await {
+            return Err(GHDError::db("creating database", err));
+        }
+        match create_db_schema(&uri).await {
+            Ok(_) => info!("Database created successfully."),
+            Err(err) => {
+                return Err(GHDError::db("creating database schema", err));
             }
+        };
+    } else {
+        debug!("database exists, maybe migrate?");
+        if let Err(err) = maybe_migrate(&uri).await {
+            return Err(err);
         }
     }
+
+    Ok(())
 }
 
 async fn create_db_schema(uri: &str) -> Result<SqliteQueryResult, sqlx::Error> {
@@ -106,26 +167,41 @@ async fn create_db_schema(uri: &str) -> Result<SqliteQueryResult, sqlx::Error> {
         closed_at       INTEGER,
         is_pull_request BOOL NOT NULL,
         last_viewed     INTEGER,
-        archived_at     INTEGER
+        archived_at     INTEGER,
+        total_comments  INTEGER
     );
     CREATE TABLE IF NOT EXISTS pull_requests (
-        id              INTEGER PRIMARY KEY NOT NULL,
-        is_draft        BOOL NOT NULL,
-        review_decision TEXT NOT NULL,
-        merged_at       INTEGER,
+        id                      INTEGER PRIMARY KEY NOT NULL,
+        is_draft                BOOL NOT NULL,
+        review_decision         TEXT NOT NULL,
+        merged_at               INTEGER,
+        checks_state            TEXT,
+        mergeable               TEXT,
+        merge_state_status      TEXT,
+        base_ref_name           TEXT,
+        head_ref_name           TEXT,
+        merge_queue_position    INTEGER,
+        merge_queue_state       TEXT,
+        merge_queue_enqueued_at INTEGER,
+        merge_queue_eta         INTEGER,
         FOREIGN KEY (id) REFERENCES issues (id)
     );
     CREATE TABLE IF NOT EXISTS user_issues (
         user_id     INTEGER NOT NULL,
         issue_id    INTEGER NOT NULL,
         archived    BOOL NOT NULL,
+        involvement INTEGER NOT NULL DEFAULT 0,
         PRIMARY KEY (user_id, issue_id),
         FOREIGN KEY (user_id) REFERENCES users (id),
         FOREIGN KEY (issue_id) REFERENCES issues (id)
     );
     CREATE TABLE IF NOT EXISTS user_refresh (
-        id          INTEGER PRIMARY KEY NOT NULL,
-        refresh_at  INTEGER,
+        id                  INTEGER PRIMARY KEY NOT NULL,
+        refresh_at          INTEGER,
+        refresh_interval    INTEGER,
+        priority            INTEGER NOT NULL DEFAULT 0,
+        reconcile_at        INTEGER,
+        sync_cursor         INTEGER,
         FOREIGN KEY(id) REFERENCES users(id)
     );
     CREATE TABLE IF NOT EXISTS tokens (
@@ -133,20 +209,171 @@ async fn create_db_schema(uri: &str) -> Result<SqliteQueryResult, sqlx::Error> {
         token       TEXT NOT NULL,
         user_id     INTEGER,
         invalid     BOOL NOT NULL,
+        api_url     TEXT,
         UNIQUE(token, user_id)
     );
+    CREATE TABLE IF NOT EXISTS issue_assignees (
+        issue_id    INTEGER NOT NULL,
+        login       TEXT NOT NULL,
+        PRIMARY KEY (issue_id, login),
+        FOREIGN KEY (issue_id) REFERENCES issues (id)
+    );
+    CREATE TABLE IF NOT EXISTS issue_mentions (
+        issue_id    INTEGER NOT NULL,
+        login       TEXT NOT NULL,
+        PRIMARY KEY (issue_id, login),
+        FOREIGN KEY (issue_id) REFERENCES issues (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_review_requests (
+        pr_id       INTEGER NOT NULL,
+        login       TEXT NOT NULL,
+        PRIMARY KEY (pr_id, login),
+        FOREIGN KEY (pr_id) REFERENCES pull_requests (id)
+    );
+    CREATE TABLE IF NOT EXISTS teams (
+        id          INTEGER PRIMARY KEY NOT NULL,
+        org         TEXT NOT NULL,
+        slug        TEXT NOT NULL,
+        name        TEXT NOT NULL,
+        refresh_at  INTEGER,
+        UNIQUE(org, slug)
+    );
+    CREATE TABLE IF NOT EXISTS team_members (
+        team_id     INTEGER NOT NULL,
+        user_id     INTEGER NOT NULL,
+        login       TEXT NOT NULL,
+        name        TEXT NOT NULL,
+        avatar_url  TEXT NOT NULL,
+        PRIMARY KEY (team_id, login),
+        FOREIGN KEY (team_id) REFERENCES teams (id)
+    );
+    CREATE TABLE IF NOT EXISTS team_issues (
+        team_id     INTEGER NOT NULL,
+        issue_id    INTEGER NOT NULL,
+        PRIMARY KEY (team_id, issue_id),
+        FOREIGN KEY (team_id) REFERENCES teams (id),
+        FOREIGN KEY (issue_id) REFERENCES issues (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_team_review_requests (
+        pr_id       INTEGER NOT NULL,
+        team        TEXT NOT NULL,
+        PRIMARY KEY (pr_id, team),
+        FOREIGN KEY (pr_id) REFERENCES pull_requests (id)
+    );
+    CREATE TABLE IF NOT EXISTS tracked_repos (
+        id          INTEGER PRIMARY KEY NOT NULL,
+        owner       TEXT NOT NULL,
+        name        TEXT NOT NULL,
+        refresh_at  INTEGER,
+        sync_cursor INTEGER,
+        UNIQUE(owner, name)
+    );
+    CREATE TABLE IF NOT EXISTS saved_queries (
+        id          INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+        name        TEXT UNIQUE NOT NULL,
+        query       TEXT NOT NULL,
+        refresh_at  INTEGER,
+        sync_cursor INTEGER
+    );
+    CREATE TABLE IF NOT EXISTS query_issues (
+        query_id    INTEGER NOT NULL,
+        issue_id    INTEGER NOT NULL,
+        PRIMARY KEY (query_id, issue_id),
+        FOREIGN KEY (query_id) REFERENCES saved_queries (id),
+        FOREIGN KEY (issue_id) REFERENCES issues (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_info (
+        id                      INTEGER PRIMARY KEY NOT NULL,
+        number                  INTEGER NOT NULL,
+        title                   TEXT NOT NULL,
+        body_html               TEXT NOT NULL,
+        author_id               INTEGER NOT NULL,
+        author_login            TEXT NOT NULL,
+        author_name             TEXT NOT NULL,
+        author_avatar_url       TEXT NOT NULL,
+        repo_owner              TEXT NOT NULL,
+        repo_name               TEXT NOT NULL,
+        url                     TEXT NOT NULL,
+        state                   TEXT NOT NULL,
+        is_draft                BOOL NOT NULL,
+        milestone_title         TEXT,
+        milestone_state         TEXT,
+        milestone_due_on        INTEGER,
+        total_comments          INTEGER NOT NULL,
+        checks_state            TEXT,
+        mergeable               TEXT,
+        merge_state_status      TEXT,
+        base_ref_name           TEXT,
+        head_ref_name           TEXT,
+        merge_queue_position    INTEGER,
+        merge_queue_state       TEXT,
+        merge_queue_enqueued_at INTEGER,
+        merge_queue_eta         INTEGER,
+        fetched_at              INTEGER NOT NULL,
+        FOREIGN KEY (id) REFERENCES issues (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_labels (
+        pr_id       INTEGER NOT NULL,
+        name        TEXT NOT NULL,
+        color       TEXT NOT NULL,
+        PRIMARY KEY (pr_id, name),
+        FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_participants (
+        pr_id       INTEGER NOT NULL,
+        user_id     INTEGER NOT NULL,
+        login       TEXT NOT NULL,
+        name        TEXT NOT NULL,
+        avatar_url  TEXT NOT NULL,
+        PRIMARY KEY (pr_id, login),
+        FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_reviews (
+        pr_id       INTEGER NOT NULL,
+        seq         INTEGER NOT NULL,
+        user_id     INTEGER NOT NULL,
+        login       TEXT NOT NULL,
+        name        TEXT NOT NULL,
+        avatar_url  TEXT NOT NULL,
+        state       TEXT NOT NULL,
+        PRIMARY KEY (pr_id, seq),
+        FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+    );
+    CREATE TABLE IF NOT EXISTS pull_request_checks (
+        pr_id       INTEGER NOT NULL,
+        seq         INTEGER NOT NULL,
+        name        TEXT NOT NULL,
+        state       TEXT NOT NULL,
+        description TEXT,
+        url         TEXT,
+        PRIMARY KEY (pr_id, seq),
+        FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+    );
     ";
 
-    let result = sqlx::query(&query).execute(&pool).await;
+    let mut result = sqlx::query(&query).execute(&pool).await;
+    if result.is_ok() {
+        // newly created databases are always at the latest version.
+        result =
+            sqlx::query(&format!("PRAGMA user_version={}", GHD_DB_VERSION))
+                .execute(&pool)
+                .await;
+    }
     pool.close().await;
 
     result
 }
 
 async fn maybe_migrate(uri: &str) -> Result<(), GHDError> {
-    let pool = SqlitePool::connect(uri).await.unwrap_or_else(|err| {
-        panic!("Unable to connect to db at '{}': {}", uri, err);
-    });
+    let pool = match SqlitePool::connect(uri).await {
+        Ok(pool) => pool,
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("connecting to db at '{}'", uri),
+                err,
+            ));
+        }
+    };
 
     let version = match sqlx::query_scalar::<_, u32>(
         "SELECT user_version from pragma_user_version",
@@ -156,7 +383,7 @@ async fn maybe_migrate(uri: &str) -> Result<(), GHDError> {
     {
         Ok(v) => v,
         Err(err) => {
-            panic!("unable to obtain db version: {}", err);
+            return Err(GHDError::db("obtaining db version", err));
         }
     };
     debug!(
@@ -172,15 +399,10 @@ async fn maybe_migrate(uri: &str) -> Result<(), GHDError> {
         while v < GHD_DB_VERSION {
             let to = v + 1;
             info!("migrate db from version {} to {}", v, to);
-            match migrate(&pool, v, to).await {
-                Ok(()) => {}
-                Err(err) => {
-                    panic!(
-                        "Error migrating db from version {} to {}: {}",
-                        v, to, err
-                    );
-                }
-            };
+            if let Err(err) = migrate(&pool, v, to).await {
+                error!("Error migrating db from version {} to {}", v, to);
+                return Err(err);
+            }
             v += 1;
         }
     }
@@ -192,13 +414,15 @@ async fn migrate(
     pool: &sqlx::Pool<sqlx::Sqlite>,
     from: u32,
     to: u32,
-) -> Result<(), sqlx::Error> {
+) -> Result<(), GHDError> {
     if from < to - 1 {
-        panic!(
-            "We can't migrate db versions separated by more than one version!"
-        );
+        return Err(GHDError::invalid_state(
+            "can't migrate db versions separated by more than one version",
+        ));
     } else if from > to {
-        panic!("We can't migrate db from the future!");
+        return Err(GHDError::invalid_state(
+            "can't migrate db from the future",
+        ));
     } else if from == to {
         // nothing to do
         return Ok(());
@@ -208,36 +432,45 @@ async fn migrate(
         // migrate version 0 to version 1
         assert_eq!(to, 1);
 
-        let mut tx = pool.begin().await.unwrap_or_else(|err| {
-            panic!("unable to start transaction: {}", err);
-        });
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
 
         let query = "ALTER TABLE issues ADD COLUMN archived_at INTEGER";
         match sqlx::query(&query).execute(&mut tx).await {
             Ok(_) => {}
             Err(err) => {
-                panic!("Unable to alter table: {}", err);
+                return Err(GHDError::db("unable to alter table", err));
             }
         };
         match sqlx::query("PRAGMA user_version=1").execute(&mut tx).await {
             Ok(_) => {}
             Err(err) => {
-                panic!("Unable to increase db version: {}", err);
-            }
-        };
-        match tx.commit().await {
-            Ok(_) => {}
-            Err(err) => {
-                return Err(err);
+                return Err(GHDError::db("unable to increase db version", err));
             }
         };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
     } else if from == 1 {
         // migrate version 1 to version 2
         assert_eq!(to, 2);
 
-        let mut tx = pool.begin().await.unwrap_or_else(|err| {
-            panic!("unable to start transaction: {}", err);
-        });
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
 
         match sqlx::query("ALTER TABLE tokens ADD COLUMN invalid BOOL")
             .execute(&mut tx)
@@ -245,7 +478,10 @@ async fn migrate(
         {
             Ok(_) => {}
             Err(err) => {
-                panic!("Unable to alter table 'tokens': {}", err);
+                return Err(GHDError::db(
+                    "unable to alter table 'tokens'",
+                    err,
+                ));
             }
         };
         match sqlx::query("UPDATE tokens SET invalid = True")
@@ -254,10 +490,10 @@ async fn migrate(
         {
             Ok(_) => {}
             Err(err) => {
-                panic!(
-                    "Unable to set default token invalid values to True: {}",
-                    err
-                );
+                return Err(GHDError::db(
+                    "unable to set default token invalid values to True",
+                    err,
+                ));
             }
         };
         match sqlx::query(
@@ -271,24 +507,721 @@ async fn migrate(
         {
             Ok(_) => {}
             Err(err) => {
-                panic!(
-                    "Unable to set latest token invalid value to False: {}",
-                    err
-                );
+                return Err(GHDError::db(
+                    "unable to set latest token invalid value to False",
+                    err,
+                ));
             }
         };
         match sqlx::query("PRAGMA user_version=2").execute(&mut tx).await {
             Ok(_) => {}
             Err(err) => {
-                panic!("Unable to increase db version: {}", err);
+                return Err(GHDError::db("unable to increase db version", err));
             }
         };
-        match tx.commit().await {
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 2 {
+        // migrate version 2 to version 3
+        assert_eq!(to, 3);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing tokens have a NULL 'api_url', meaning the default Github
+        // API endpoint.
+        match sqlx::query("ALTER TABLE tokens ADD COLUMN api_url TEXT")
+            .execute(&mut tx)
+            .await
+        {
             Ok(_) => {}
             Err(err) => {
-                return Err(err);
+                return Err(GHDError::db(
+                    "unable to alter table 'tokens'",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=3").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 3 {
+        // migrate version 3 to version 4
+        assert_eq!(to, 4);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // assignees for existing issues will be populated as they are
+        // refreshed.
+        match sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS issue_assignees (
+                issue_id    INTEGER NOT NULL,
+                login       TEXT NOT NULL,
+                PRIMARY KEY (issue_id, login),
+                FOREIGN KEY (issue_id) REFERENCES issues (id)
+            )
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to create table 'issue_assignees'",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=4").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 4 {
+        // migrate version 4 to version 5
+        assert_eq!(to, 5);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        match sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS pull_request_info (
+                id                  INTEGER PRIMARY KEY NOT NULL,
+                number              INTEGER NOT NULL,
+                title               TEXT NOT NULL,
+                body_html           TEXT NOT NULL,
+                author_id           INTEGER NOT NULL,
+                author_login        TEXT NOT NULL,
+                author_name         TEXT NOT NULL,
+                author_avatar_url   TEXT NOT NULL,
+                repo_owner          TEXT NOT NULL,
+                repo_name           TEXT NOT NULL,
+                url                 TEXT NOT NULL,
+                state               TEXT NOT NULL,
+                is_draft            BOOL NOT NULL,
+                milestone_title     TEXT,
+                milestone_state     TEXT,
+                milestone_due_on    INTEGER,
+                total_comments      INTEGER NOT NULL,
+                fetched_at          INTEGER NOT NULL,
+                FOREIGN KEY (id) REFERENCES issues (id)
+            );
+            CREATE TABLE IF NOT EXISTS pull_request_labels (
+                pr_id       INTEGER NOT NULL,
+                name        TEXT NOT NULL,
+                color       TEXT NOT NULL,
+                PRIMARY KEY (pr_id, name),
+                FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+            );
+            CREATE TABLE IF NOT EXISTS pull_request_participants (
+                pr_id       INTEGER NOT NULL,
+                user_id     INTEGER NOT NULL,
+                login       TEXT NOT NULL,
+                name        TEXT NOT NULL,
+                avatar_url  TEXT NOT NULL,
+                PRIMARY KEY (pr_id, login),
+                FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+            );
+            CREATE TABLE IF NOT EXISTS pull_request_reviews (
+                pr_id       INTEGER NOT NULL,
+                seq         INTEGER NOT NULL,
+                user_id     INTEGER NOT NULL,
+                login       TEXT NOT NULL,
+                name        TEXT NOT NULL,
+                avatar_url  TEXT NOT NULL,
+                state       TEXT NOT NULL,
+                PRIMARY KEY (pr_id, seq),
+                FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to create pull request info tables",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=5").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 5 {
+        // migrate version 5 to version 6
+        assert_eq!(to, 6);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing pull requests will have their checks state populated as
+        // they are refreshed.
+        match sqlx::query(
+            "
+            ALTER TABLE pull_requests ADD COLUMN checks_state TEXT;
+            ALTER TABLE pull_request_info ADD COLUMN checks_state TEXT;
+            CREATE TABLE IF NOT EXISTS pull_request_checks (
+                pr_id       INTEGER NOT NULL,
+                seq         INTEGER NOT NULL,
+                name        TEXT NOT NULL,
+                state       TEXT NOT NULL,
+                description TEXT,
+                url         TEXT,
+                PRIMARY KEY (pr_id, seq),
+                FOREIGN KEY (pr_id) REFERENCES pull_request_info (id)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add pull request checks state",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=6").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 6 {
+        // migrate version 6 to version 7
+        assert_eq!(to, 7);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing pull requests will have their merge state populated as
+        // they are refreshed.
+        match sqlx::query(
+            "
+            ALTER TABLE pull_requests ADD COLUMN mergeable TEXT;
+            ALTER TABLE pull_requests ADD COLUMN merge_state_status TEXT;
+            ALTER TABLE pull_requests ADD COLUMN base_ref_name TEXT;
+            ALTER TABLE pull_requests ADD COLUMN head_ref_name TEXT;
+            ALTER TABLE pull_requests ADD COLUMN merge_queue_position INTEGER;
+            ALTER TABLE pull_requests ADD COLUMN merge_queue_state TEXT;
+            ALTER TABLE pull_requests ADD COLUMN merge_queue_enqueued_at INTEGER;
+            ALTER TABLE pull_requests ADD COLUMN merge_queue_eta INTEGER;
+            ALTER TABLE pull_request_info ADD COLUMN mergeable TEXT;
+            ALTER TABLE pull_request_info ADD COLUMN merge_state_status TEXT;
+            ALTER TABLE pull_request_info ADD COLUMN base_ref_name TEXT;
+            ALTER TABLE pull_request_info ADD COLUMN head_ref_name TEXT;
+            ALTER TABLE pull_request_info ADD COLUMN merge_queue_position INTEGER;
+            ALTER TABLE pull_request_info ADD COLUMN merge_queue_state TEXT;
+            ALTER TABLE pull_request_info ADD COLUMN merge_queue_enqueued_at INTEGER;
+            ALTER TABLE pull_request_info ADD COLUMN merge_queue_eta INTEGER;
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add pull request merge state",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=7").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 7 {
+        // migrate version 7 to version 8
+        assert_eq!(to, 8);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing issues will have their comment counts and review requests
+        // populated as they are refreshed.
+        match sqlx::query(
+            "
+            ALTER TABLE issues ADD COLUMN total_comments INTEGER;
+            CREATE TABLE IF NOT EXISTS pull_request_review_requests (
+                pr_id       INTEGER NOT NULL,
+                login       TEXT NOT NULL,
+                PRIMARY KEY (pr_id, login),
+                FOREIGN KEY (pr_id) REFERENCES pull_requests (id)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add issue activity tracking",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=8").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 8 {
+        // migrate version 8 to version 9
+        assert_eq!(to, 9);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing users keep refreshing at the global interval, with the
+        // default priority.
+        match sqlx::query(
+            "
+            ALTER TABLE user_refresh ADD COLUMN refresh_interval INTEGER;
+            ALTER TABLE user_refresh
+                ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add user refresh interval and priority",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=9").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 9 {
+        // migrate version 9 to version 10
+        assert_eq!(to, 10);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing pull requests will have their team review requests
+        // populated as they are refreshed.
+        match sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS teams (
+                id          INTEGER PRIMARY KEY NOT NULL,
+                org         TEXT NOT NULL,
+                slug        TEXT NOT NULL,
+                name        TEXT NOT NULL,
+                refresh_at  INTEGER,
+                UNIQUE(org, slug)
+            );
+            CREATE TABLE IF NOT EXISTS team_members (
+                team_id     INTEGER NOT NULL,
+                user_id     INTEGER NOT NULL,
+                login       TEXT NOT NULL,
+                name        TEXT NOT NULL,
+                avatar_url  TEXT NOT NULL,
+                PRIMARY KEY (team_id, login),
+                FOREIGN KEY (team_id) REFERENCES teams (id)
+            );
+            CREATE TABLE IF NOT EXISTS team_issues (
+                team_id     INTEGER NOT NULL,
+                issue_id    INTEGER NOT NULL,
+                PRIMARY KEY (team_id, issue_id),
+                FOREIGN KEY (team_id) REFERENCES teams (id),
+                FOREIGN KEY (issue_id) REFERENCES issues (id)
+            );
+            CREATE TABLE IF NOT EXISTS pull_request_team_review_requests (
+                pr_id       INTEGER NOT NULL,
+                team        TEXT NOT NULL,
+                PRIMARY KEY (pr_id, team),
+                FOREIGN KEY (pr_id) REFERENCES pull_requests (id)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to add teams tables", err));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=10").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 10 {
+        // migrate version 10 to version 11
+        assert_eq!(to, 11);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        match sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS tracked_repos (
+                id          INTEGER PRIMARY KEY NOT NULL,
+                owner       TEXT NOT NULL,
+                name        TEXT NOT NULL,
+                refresh_at  INTEGER,
+                UNIQUE(owner, name)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add tracked repositories table",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=11").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
             }
         };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 11 {
+        // migrate version 11 to version 12
+        assert_eq!(to, 12);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        match sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS saved_queries (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+                name        TEXT UNIQUE NOT NULL,
+                query       TEXT NOT NULL,
+                refresh_at  INTEGER
+            );
+            CREATE TABLE IF NOT EXISTS query_issues (
+                query_id    INTEGER NOT NULL,
+                issue_id    INTEGER NOT NULL,
+                PRIMARY KEY (query_id, issue_id),
+                FOREIGN KEY (query_id) REFERENCES saved_queries (id),
+                FOREIGN KEY (issue_id) REFERENCES issues (id)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add saved queries tables",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=12").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 12 {
+        // migrate version 12 to version 13
+        assert_eq!(to, 13);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        match sqlx::query(
+            "
+            CREATE TABLE IF NOT EXISTS issue_mentions (
+                issue_id    INTEGER NOT NULL,
+                login       TEXT NOT NULL,
+                PRIMARY KEY (issue_id, login),
+                FOREIGN KEY (issue_id) REFERENCES issues (id)
+            );
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add issue mentions table",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=13").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 13 {
+        // migrate version 13 to version 14
+        assert_eq!(to, 14);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing rows will have their involvement set on the issue's next
+        // update.
+        match sqlx::query(
+            "
+            ALTER TABLE user_issues
+                ADD COLUMN involvement INTEGER NOT NULL DEFAULT 0;
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add user issues involvement",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=14").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 14 {
+        // migrate version 14 to version 15
+        assert_eq!(to, 15);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // existing users will be reconciled on the next iteration.
+        match sqlx::query(
+            "
+            ALTER TABLE user_refresh ADD COLUMN reconcile_at INTEGER;
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    "unable to add user reconciliation time",
+                    err,
+                ));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=15").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
+    } else if from == 15 {
+        // migrate version 15 to version 16
+        assert_eq!(to, 16);
+
+        let mut tx = match pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting migration transaction",
+                    err,
+                ));
+            }
+        };
+
+        // refresh times have so far doubled as sync cursors; keep searching
+        // from where we left off.
+        match sqlx::query(
+            "
+            ALTER TABLE user_refresh ADD COLUMN sync_cursor INTEGER;
+            UPDATE user_refresh SET sync_cursor = refresh_at
+                WHERE refresh_at > 0;
+            ALTER TABLE tracked_repos ADD COLUMN sync_cursor INTEGER;
+            UPDATE tracked_repos SET sync_cursor = refresh_at;
+            ALTER TABLE saved_queries ADD COLUMN sync_cursor INTEGER;
+            UPDATE saved_queries SET sync_cursor = refresh_at;
+            ",
+        )
+        .execute(&mut tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to add sync cursors", err));
+            }
+        };
+        match sqlx::query("PRAGMA user_version=16").execute(&mut tx).await {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db("unable to increase db version", err));
+            }
+        };
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db("committing migration transaction", err));
+        }
     }
 
     Ok(())
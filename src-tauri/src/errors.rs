@@ -12,7 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Copy, Clone)]
+/// Represents an entry in the `errors` array returned by the GraphQL API.
+///
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+pub struct GQLError {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    pub path: Option<Vec<serde_json::Value>>,
+}
+
+/// GHD's error type. Variants carrying context are serialized with their
+/// fields, so the frontend can show something meaningful to the user; all
+/// variants are serialized with a `type` field naming the variant.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "type")]
 pub enum GHDError {
     TokenNotFoundError,
     BadTokenError,
@@ -20,9 +35,156 @@ pub enum GHDError {
     UserNotFoundError,
     NeverRefreshedError,
     BadRequest,
-    UnknownError,
     NotFoundError,
     DBVersionInTheFuture,
     RepositoryNotFoundError,
     PullRequestNotFoundError,
+    IssueNotFoundError,
+    TeamNotFoundError,
+    SavedQueryNotFoundError,
+    RateLimitedError,
+    ForbiddenError {
+        context: String,
+    },
+    NetworkError {
+        context: String,
+        source: String,
+    },
+    HTTPError {
+        context: String,
+        status: u16,
+    },
+    GraphQLError {
+        context: String,
+        errors: Vec<GQLError>,
+    },
+    DecodeError {
+        context: String,
+        source: String,
+    },
+    DBError {
+        context: String,
+        source: String,
+    },
+    UnexpectedDataError {
+        context: String,
+    },
+    InvalidStateError {
+        context: String,
+    },
+    SettingNotFoundError {
+        key: String,
+    },
+    InvalidSettingError {
+        key: String,
+        context: String,
+    },
+    InvalidSavedQueryError {
+        context: String,
+    },
+}
+
+impl GHDError {
+    /// Obtain a `GHDError::DBError` from a database error, with `context`
+    /// describing what we were trying to do.
+    ///
+    pub fn db<E: std::fmt::Display>(context: &str, err: E) -> Self {
+        GHDError::DBError {
+            context: String::from(context),
+            source: err.to_string(),
+        }
+    }
+
+    /// Obtain a `GHDError::NetworkError` from a request error, with `context`
+    /// describing what we were trying to do.
+    ///
+    pub fn network<E: std::fmt::Display>(context: &str, err: E) -> Self {
+        GHDError::NetworkError {
+            context: String::from(context),
+            source: err.to_string(),
+        }
+    }
+
+    /// Obtain a `GHDError::DecodeError` from a decoding error, with `context`
+    /// describing what we were trying to decode.
+    ///
+    pub fn decode<E: std::fmt::Display>(context: &str, err: E) -> Self {
+        GHDError::DecodeError {
+            context: String::from(context),
+            source: err.to_string(),
+        }
+    }
+
+    /// Obtain a `GHDError::UnexpectedDataError`, with `context` describing
+    /// what was unexpected.
+    ///
+    pub fn unexpected(context: &str) -> Self {
+        GHDError::UnexpectedDataError {
+            context: String::from(context),
+        }
+    }
+
+    /// Obtain a `GHDError::InvalidStateError`, with `context` describing the
+    /// invalid state.
+    ///
+    pub fn invalid_state(context: &str) -> Self {
+        GHDError::InvalidStateError {
+            context: String::from(context),
+        }
+    }
+
+    /// Obtain a `GHDError::InvalidSettingError` for setting `key`, with
+    /// `context` describing why the value is invalid.
+    ///
+    pub fn invalid_setting(key: &str, context: &str) -> Self {
+        GHDError::InvalidSettingError {
+            key: String::from(key),
+            context: String::from(context),
+        }
+    }
 }
+
+impl std::fmt::Display for GHDError {
+    fn fmt(self: &Self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GHDError::NetworkError { context, source } => {
+                write!(f, "network error: {}: {}", context, source)
+            }
+            GHDError::HTTPError { context, status } => {
+                write!(f, "http error: {}: status {}", context, status)
+            }
+            GHDError::GraphQLError { context, errors } => {
+                let msgs: Vec<&str> =
+                    errors.iter().map(|e| e.message.as_str()).collect();
+                write!(f, "graphql error: {}: {}", context, msgs.join("; "))
+            }
+            GHDError::DecodeError { context, source } => {
+                write!(f, "decode error: {}: {}", context, source)
+            }
+            GHDError::DBError { context, source } => {
+                write!(f, "database error: {}: {}", context, source)
+            }
+            GHDError::ForbiddenError { context } => {
+                write!(f, "forbidden: {}", context)
+            }
+            GHDError::UnexpectedDataError { context } => {
+                write!(f, "unexpected data: {}", context)
+            }
+            GHDError::InvalidStateError { context } => {
+                write!(f, "invalid state: {}", context)
+            }
+            GHDError::SettingNotFoundError { key } => {
+                write!(f, "unknown setting: {}", key)
+            }
+            GHDError::InvalidSettingError { key, context } => {
+                write!(f, "invalid value for setting '{}': {}", key, context)
+            }
+            GHDError::InvalidSavedQueryError { context } => {
+                write!(f, "invalid saved query: {}", context)
+            }
+            err => write!(f, "{:?}", err),
+        }
+    }
+}
+
+impl std::error::Error for GHDError {}
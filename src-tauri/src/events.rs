@@ -12,21 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use log::debug;
+use log::{debug, error};
 
-use crate::gh::types::GithubUser;
+use crate::{
+    config::Config,
+    gh::{
+        ratelimit::RateLimitInfo,
+        types::{
+            GithubTeam, GithubUser, IssuesChangeset, SavedQuery, TrackedRepo,
+            UserRefreshCompleted,
+        },
+    },
+};
 
 pub const EV_ITERATION: &str = "iteration";
-pub const EV_USER_DATA_UPDATE: &str = "user_data_update";
 pub const EV_USER_UPDATE: &str = "user_update";
+pub const EV_USER_REFRESHED: &str = "user_refreshed";
 pub const EV_TOKEN_SET: &str = "token_set";
 pub const EV_TOKEN_INVALID: &str = "token_invalid";
+pub const EV_RATE_LIMIT_UPDATE: &str = "rate_limit_update";
+pub const EV_PR_INFO_UPDATE: &str = "pr_info_update";
+pub const EV_ISSUES_CHANGED: &str = "issues_changed";
+pub const EV_SETTINGS_CHANGED: &str = "settings_changed";
+pub const EV_USER_REMOVED: &str = "user_removed";
+pub const EV_TEAM_UPDATE: &str = "team_update";
+pub const EV_REPO_UPDATE: &str = "repo_update";
+pub const EV_QUERY_UPDATE: &str = "query_update";
+pub const EV_QUERY_REMOVED: &str = "query_removed";
 
 pub fn emit<S>(w: &tauri::Window, ev: &str, payload: S)
 where
     S: serde::Serialize + Clone,
 {
-    w.emit(ev, payload).unwrap();
+    // the frontend will catch up on its next refresh; not worth failing for.
+    if let Err(err) = w.emit(ev, payload) {
+        error!("Unable to emit event '{}': {}", ev, err);
+    }
 }
 
 pub fn emit_token_set(w: &tauri::Window) {
@@ -42,7 +63,52 @@ pub fn emit_user_update(w: &tauri::Window, user: &GithubUser) {
     emit(w, EV_USER_UPDATE, user);
 }
 
-pub fn emit_user_data_update(w: &tauri::Window, login: &String) {
-    debug!("emite user data update for '{}'", login);
-    emit(w, EV_USER_DATA_UPDATE, login);
+pub fn emit_user_refreshed(w: &tauri::Window, res: &UserRefreshCompleted) {
+    debug!("emit user refreshed for {}", res.login);
+    emit(w, EV_USER_REFRESHED, res);
+}
+
+pub fn emit_user_removed(w: &tauri::Window, user: &GithubUser) {
+    debug!("emit user removed for {}", user.login);
+    emit(w, EV_USER_REMOVED, user);
+}
+
+pub fn emit_team_update(w: &tauri::Window, team: &GithubTeam) {
+    debug!("emit team update for {}", team.combined_slug());
+    emit(w, EV_TEAM_UPDATE, team);
+}
+
+pub fn emit_repo_update(w: &tauri::Window, repo: &TrackedRepo) {
+    debug!("emit repo update for {}", repo.full_name());
+    emit(w, EV_REPO_UPDATE, repo);
+}
+
+pub fn emit_query_update(w: &tauri::Window, query: &SavedQuery) {
+    debug!("emit query update for {}", query.name);
+    emit(w, EV_QUERY_UPDATE, query);
+}
+
+pub fn emit_query_removed(w: &tauri::Window, query: &SavedQuery) {
+    debug!("emit query removed for {}", query.name);
+    emit(w, EV_QUERY_REMOVED, query);
+}
+
+pub fn emit_issues_changed(w: &tauri::Window, changes: &IssuesChangeset) {
+    debug!("emit issues changed for user {}", changes.user_id);
+    emit(w, EV_ISSUES_CHANGED, changes);
+}
+
+pub fn emit_rate_limit_update(w: &tauri::Window, info: &RateLimitInfo) {
+    debug!("emit rate limit update");
+    emit(w, EV_RATE_LIMIT_UPDATE, info);
+}
+
+pub fn emit_pr_info_update(w: &tauri::Window, prid: &i64) {
+    debug!("emit pr info update for {}", prid);
+    emit(w, EV_PR_INFO_UPDATE, prid);
+}
+
+pub fn emit_settings_changed(w: &tauri::Window, cfg: &Config) {
+    debug!("emit settings changed");
+    emit(w, EV_SETTINGS_CHANGED, cfg);
 }
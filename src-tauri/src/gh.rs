@@ -12,28 +12,89 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
 use log::{debug, warn};
 use sqlx::Row;
 
 use crate::{db::DB, errors::GHDError};
 
-use self::types::{GithubUser, PullRequestInfo, PullRequestTableEntry};
+use self::types::{
+    GithubEndpoint, GithubTeam, GithubUser, InboxItem, Involvement, IssueInfo,
+    IssueTableEntry, IssuesChangeset, PullRequestInfo, PullRequestTableEntry,
+    SavedQuery, SavedQueryResults, TrackedRepo, UserRefreshResult, UserUpdate,
+};
 
+pub mod activity;
 pub mod api;
 pub mod gql;
+pub mod inbox;
+pub mod issues;
+pub mod prinfo;
 pub mod prs;
+pub mod ratelimit;
 pub mod refresh;
+pub mod repos;
 pub mod rest;
+pub mod saved_queries;
+pub mod sync;
+pub mod teams;
 pub mod types;
 pub mod users;
 
-pub struct Github {}
+/// Github client. Cloning the client is cheap, and all clones share the same
+/// rate limit tracking and the same maximum number of pages to follow for
+/// paginated queries.
+///
+#[derive(Clone)]
+pub struct Github {
+    ratelimit: ratelimit::RateLimitTracker,
+    max_pages: Arc<AtomicUsize>,
+}
 
 impl Github {
-    /// Obtain new Github instance.
+    /// Obtain new Github instance, following at most `max_pages` pages for
+    /// paginated queries.
+    ///
+    pub fn new(max_pages: i64) -> Self {
+        Github {
+            ratelimit: ratelimit::RateLimitTracker::new(),
+            max_pages: Arc::new(AtomicUsize::new(max_pages as usize)),
+        }
+    }
+
+    /// Set the maximum number of pages to follow for paginated queries.
+    ///
+    pub fn set_max_pages(self: &Self, max_pages: i64) {
+        self.max_pages.store(max_pages as usize, Ordering::Relaxed);
+    }
+
+    /// Obtain the current API rate limit information.
+    ///
+    pub fn get_rate_limit(self: &Self) -> ratelimit::RateLimitInfo {
+        self.ratelimit.get()
+    }
+
+    /// Obtain the time until which we should refrain from issuing API
+    /// requests, if we are currently rate limited.
+    ///
+    pub fn rate_limit_paused_until(
+        self: &Self,
+    ) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.ratelimit.paused_until()
+    }
+
+    /// Check whether our API budget is running low, in which case we should
+    /// be selective about which requests we issue.
     ///
-    pub fn new() -> Self {
-        Github {}
+    pub fn rate_limit_is_low(self: &Self) -> bool {
+        self.ratelimit.is_budget_low()
     }
 
     /// Obtain token from the database, if exists. Returns a String if the token
@@ -44,9 +105,28 @@ impl Github {
     /// * `db` - The GHD Database handle.
     ///
     pub async fn get_token(self: &Self, db: &DB) -> Result<String, GHDError> {
+        match self.get_endpoint(&db).await {
+            Ok(res) => Ok(res.token),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Obtain the Github API endpoint from the database, including its token,
+    /// if a valid token exists. Returns a `GHDError::TokenNotFoundError` if no
+    /// token has been set, or `GHDError::BadTokenError` if the existing tokens
+    /// have been invalidated.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The GHD Database handle.
+    ///
+    pub async fn get_endpoint(
+        self: &Self,
+        db: &DB,
+    ) -> Result<GithubEndpoint, GHDError> {
         let val: Result<sqlx::sqlite::SqliteRow, sqlx::Error> = sqlx::query(
             "
-                SELECT token FROM tokens
+                SELECT token, api_url FROM tokens
                 WHERE id = (SELECT MAX(id) FROM tokens WHERE invalid = False);
             ",
         )
@@ -55,12 +135,30 @@ impl Github {
 
         match &val {
             Ok(res) => {
-                match res.try_get("token") {
-                    Ok(res) => return Ok(res),
+                let token: String = match res.try_get("token") {
+                    Ok(res) => res,
+                    Err(err) => {
+                        return Err(GHDError::db(
+                            "obtaining token column",
+                            err,
+                        ));
+                    }
+                };
+                let api_url: Option<String> = match res.try_get("api_url") {
+                    Ok(res) => res,
                     Err(err) => {
-                        panic!("Unable to obtain token column: {}", err);
+                        return Err(GHDError::db(
+                            "obtaining api_url column",
+                            err,
+                        ));
                     }
                 };
+                return Ok(GithubEndpoint::new(
+                    &token,
+                    &api_url,
+                    &self.ratelimit,
+                    self.max_pages.load(Ordering::Relaxed),
+                ));
             }
             Err(_) => {}
         };
@@ -95,98 +193,113 @@ impl Github {
     ///
     /// * `db` - The GHD Database handle.
     /// * `token` - String containing the API Token to persist.
+    /// * `api_url` - Optional Github API base URL, for Github Enterprise Server
+    ///   instances. Defaults to `https://api.github.com` if not provided.
     /// * `cb` - Callback function to be called once the Token is persisted.
     ///
     pub async fn set_token<F>(
         self: &Self,
         db: &DB,
         token: &String,
+        api_url: &Option<String>,
         cb: F,
     ) -> Result<(), GHDError>
     where
         F: FnOnce(&GithubUser),
     {
-        debug!("setting token {}", token);
+        let endpoint = GithubEndpoint::new(
+            &token,
+            &api_url,
+            &self.ratelimit,
+            self.max_pages.load(Ordering::Relaxed),
+        );
+        debug!("setting token for {}", endpoint.api_url);
         debug!("  obtaining user for token");
-        let user: GithubUser = match users::whoami(token).await {
+        let user: GithubUser = match users::whoami(&endpoint).await {
             Ok(res) => res,
-            Err(err) => {
-                return match err {
-                    reqwest::StatusCode::FORBIDDEN => {
-                        Err(GHDError::BadTokenError)
-                    }
-                    reqwest::StatusCode::UNAUTHORIZED => {
-                        Err(GHDError::BadTokenError)
-                    }
-                    _ => Err(GHDError::UnknownError),
-                };
-            }
+            Err(err) => return Err(err),
         };
         debug!("  user: {}, {}", user.login, user.name);
 
-        let mut tx = match db.pool().begin().await {
+        let mut tx = match db.begin().await {
             Ok(res) => res,
             Err(err) => {
-                panic!("Error starting transaction to set token: {}", err);
+                return Err(GHDError::db(
+                    "starting transaction to set token",
+                    err,
+                ));
             }
         };
 
         let user_exists = users::user_exists(&db, &user.login).await;
         if !user_exists {
-            users::add_user_to_db(&mut tx, &user).await;
+            if let Err(err) = users::add_user_to_db(&mut tx, &user).await {
+                return Err(err);
+            }
         }
 
-        sqlx::query(
+        if let Err(err) = sqlx::query(
             "
-                INSERT OR REPLACE into tokens (token, user_id, invalid)
-                VALUES (?, ?, False)
+                INSERT OR REPLACE into tokens (token, user_id, invalid, api_url)
+                VALUES (?, ?, False, ?)
             ",
         )
         .bind(token)
         .bind(&user.id)
+        .bind(&endpoint.api_url)
         .execute(&mut tx)
         .await
-        .unwrap_or_else(|err| {
-            panic!("Error inserting token into database: {}", err);
-        });
+        {
+            return Err(GHDError::db("inserting token into database", err));
+        }
 
-        tx.commit().await.unwrap_or_else(|err| {
-            panic!("Unable to commit transaction to set token: {}", err);
-        });
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db(
+                "committing transaction to set token",
+                err,
+            ));
+        }
         debug!("  user and token have been set!");
 
         if !user_exists {
-            self.populate_user(&db, &user.login).await.unwrap();
+            if let Err(err) = self.populate_user(&db, &user.login).await {
+                // the user will be populated on its next refresh.
+                warn!("Unable to populate user '{}': {}", user.login, err);
+            }
         }
 
         cb(&user);
         Ok(())
     }
 
-    pub async fn invalidate_token(self: &Self, db: &DB) {
+    pub async fn invalidate_token(
+        self: &Self,
+        db: &DB,
+    ) -> Result<(), GHDError> {
         let query = "
             UPDATE tokens SET invalid = True
             WHERE id = (SELECT MAX(id) FROM tokens WHERE invalid = False)
         ";
 
-        let mut tx = match db.pool().begin().await {
+        let mut tx = match db.begin().await {
             Ok(res) => res,
             Err(err) => {
-                panic!(
-                    "Error starting transaction to invalidate token: {}",
-                    err
-                );
+                return Err(GHDError::db(
+                    "starting transaction to invalidate token",
+                    err,
+                ));
             }
         };
-        sqlx::query(query)
-            .execute(&mut tx)
-            .await
-            .unwrap_or_else(|err| {
-                panic!("Error updating token validity: {}", err);
-            });
-        tx.commit().await.unwrap_or_else(|err| {
-            panic!("Unable to commit token invalidation transaction: {}", err);
-        });
+        if let Err(err) = sqlx::query(query).execute(&mut tx).await {
+            return Err(GHDError::db("updating token validity", err));
+        }
+        match tx.commit().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(GHDError::db(
+                "committing token invalidation transaction",
+                err,
+            )),
+        }
     }
 
     /// Obtain user by their login. First tries the database, and will fallback
@@ -207,24 +320,18 @@ impl Github {
             Err(_) => {}
         };
 
-        let token: String = match self.get_token(db).await {
-            Ok(t) => t.clone(),
+        let endpoint = match self.get_endpoint(db).await {
+            Ok(ep) => ep,
             Err(err) => return Err(err),
         };
 
-        let ghreq = rest::GithubRequest::new(&token);
+        let ghreq = rest::GithubRequest::new(&endpoint);
         let reqstr = format!("/users/{}", login);
         let req = ghreq.get(&reqstr);
         match ghreq.send::<rest::GithubUserReply>(req).await {
-            Ok(res) => return Ok(users::user_reply_to_user(res)),
-            Err(err) => {
-                return match err {
-                    reqwest::StatusCode::NOT_FOUND => {
-                        Err(GHDError::UserNotFoundError)
-                    }
-                    _ => Err(GHDError::UnknownError),
-                };
-            }
+            Ok(res) => Ok(users::user_reply_to_user(res)),
+            Err(GHDError::NotFoundError) => Err(GHDError::UserNotFoundError),
+            Err(err) => Err(err),
         }
     }
 
@@ -263,26 +370,38 @@ impl Github {
             Err(err) => return Err(err),
         };
 
-        let mut tx = match db.pool().begin().await {
+        let mut tx = match db.begin().await {
             Ok(res) => res,
             Err(err) => {
-                panic!("Error starting transaction to track user: {}", err);
+                return Err(GHDError::db(
+                    "starting transaction to track user",
+                    err,
+                ));
             }
         };
 
-        users::add_user_to_db(&mut tx, &user).await;
+        if let Err(err) = users::add_user_to_db(&mut tx, &user).await {
+            return Err(err);
+        }
 
-        tx.commit().await.unwrap_or_else(|err| {
-            panic!("Unable to commit transaction to track new user: {}", err);
-        });
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db(
+                "committing transaction to track new user",
+                err,
+            ));
+        }
 
-        self.populate_user(&db, &user.login).await.unwrap();
+        if let Err(err) = self.populate_user(&db, &user.login).await {
+            // the user will be populated on its next refresh.
+            warn!("Unable to populate user '{}': {}", user.login, err);
+        }
 
         cb(&user);
         Ok(user)
     }
 
-    /// Populate the database for a newly-added user.
+    /// Populate the database for a newly-added user, returning the resulting
+    /// changes.
     ///
     /// # Arguments
     ///
@@ -293,76 +412,86 @@ impl Github {
         self: &Self,
         db: &DB,
         login: &String,
-    ) -> Result<(), GHDError> {
+    ) -> Result<IssuesChangeset, GHDError> {
         // sanity checks: user exists in the database, and last update was
         // never.
         let user = match users::get_user_by_login(&db, &login).await {
             Ok(u) => u,
-            Err(GHDError::UserNotFoundError) => {
-                panic!(
-                    "Trying to populate a user that is not in the database: {}",
-                    login
-                );
-            }
-            Err(err) => {
-                panic!("Unexpected error: {:?}", err);
-            }
+            Err(err) => return Err(err),
         };
-        match refresh::get_user_refresh(&db, &user.id).await {
+        match refresh::get_user_sync_cursor(&db, &user.id).await {
             Ok(_) => {
-                panic!("User has been previously updated: {}", login);
+                return Err(GHDError::invalid_state(&format!(
+                    "user has been previously updated: {}",
+                    login
+                )));
             }
             Err(GHDError::NeverRefreshedError) => {}
-            Err(err) => {
-                panic!("Unexpected error: {:?}", err);
-            }
+            Err(err) => return Err(err),
         };
 
         // obtain user information through GraphQL API
-        let token: String = match self.get_token(&db).await {
-            Ok(t) => t.clone(),
-            Err(_) => {
-                panic!("Token not set!");
-            }
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
         };
 
-        // let res = match gql::get_user_info(&token, &login).await {
-        let res = match gql::get_user_open_issues(&token, &login).await {
+        let res = match gql::get_user_open_issues(&endpoint, &login).await {
             Ok(info) => info,
-            Err(err) => {
-                panic!("Unexpected error populating user from GQL: {:?}", err);
-            }
+            Err(err) => return Err(err),
         };
 
-        let mut tx = match db.pool().begin().await {
+        let mut tx = match db.begin().await {
             Ok(res) => res,
             Err(err) => {
-                panic!("Error starting transaction to populate user: {}", err);
+                return Err(GHDError::db(
+                    "starting transaction to populate user",
+                    err,
+                ));
             }
         };
 
-        if let Err(err) =
-            prs::consume_issues(&mut tx, &user.id, &res.issues, &res.prs).await
-        {
-            panic!(
-                "Error consuming pull requests when populating user: {:?}",
-                err
-            );
-        };
-
-        users::update_user_refresh(&mut tx, &user.id, &res.when).await;
+        let changes =
+            match prs::consume_issues(&mut tx, &user, &res.issues, &res.prs)
+                .await
+            {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
 
-        tx.commit().await.unwrap_or_else(|err| {
-            panic!(
-                "Unable to commit populate transaction for user '{}': {}",
-                user.login, err
-            );
-        });
+        if let Err(err) = users::update_user_refresh(
+            &mut tx,
+            &user.id,
+            &chrono::Utc::now(),
+            &Some(res.cursor),
+        )
+        .await
+        {
+            return Err(err);
+        }
+        // we have just obtained all open issues; no need to reconcile them.
+        if let Err(err) = refresh::update_user_reconcile(
+            &mut tx,
+            &user.id,
+            &chrono::Utc::now(),
+        )
+        .await
+        {
+            return Err(err);
+        }
 
-        Ok(())
+        match tx.commit().await {
+            Ok(_) => Ok(changes),
+            Err(err) => Err(GHDError::db(
+                &format!("committing populate transaction for '{}'", login),
+                err,
+            )),
+        }
     }
 
-    /// Refreshes the specified user's data.
+    /// Refreshes the specified user's data. If the user is GHD's main user,
+    /// the result will also contain any activity the user may want to be
+    /// notified about.
     ///
     /// # Arguments
     ///
@@ -373,187 +502,1095 @@ impl Github {
         self: &Self,
         db: &DB,
         login: &String,
-    ) -> Result<bool, GHDError> {
+    ) -> Result<UserRefreshResult, GHDError> {
         let user = match users::get_user_by_login(&db, &login).await {
             Ok(u) => u,
-            Err(GHDError::UserNotFoundError) => {
-                panic!(
-                    "Trying to refresh a user that is not on the database: {}",
-                    login
-                );
-            }
-            Err(err) => {
-                panic!("Unexpected error: {:?}", err);
-            }
+            Err(err) => return Err(err),
         };
 
-        let token = match self.get_token(&db).await {
-            Ok(t) => t.clone(),
-            Err(_) => {
-                panic!("Token not set!");
-            }
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
         };
 
-        let last_update = match refresh::get_user_refresh(&db, &user.id).await {
+        let cursor = match refresh::get_user_sync_cursor(&db, &user.id).await {
             Ok(v) => v,
-            Err(err) => {
-                panic!("Unexpected error: {:?}", err);
+            Err(GHDError::NeverRefreshedError) => {
+                // we may have failed to populate this user when it was first
+                // added; try again.
+                debug!("user '{}' never refreshed, populate.", login);
+                return match self.populate_user(&db, &login).await {
+                    Ok(changes) => Ok(UserRefreshResult {
+                        updated: true,
+                        changes,
+                        activity: vec![],
+                    }),
+                    Err(err) => Err(err),
+                };
             }
+            Err(err) => return Err(err),
         };
 
-        let res =
-            match gql::get_user_updates(&token, &login, &last_update).await {
-                Ok(updates) => updates,
-                Err(GHDError::BadTokenError) => {
-                    warn!("Token invalid or expired!");
-                    return Err(GHDError::BadTokenError);
-                }
-                Err(err) => {
-                    panic!(
-                    "Unexpected error obtaining user updates from GQL: {:?}",
-                    err
-                );
-                }
-            };
-
-        let mut tx = match db.pool().begin().await {
-            Ok(res) => res,
-            Err(err) => {
-                panic!("Error starting transaction to update user: {}", err);
+        let res = match gql::get_user_updates(&endpoint, &login, &cursor).await
+        {
+            Ok(updates) => updates,
+            Err(GHDError::BadTokenError) => {
+                warn!("Token invalid or expired!");
+                return Err(GHDError::BadTokenError);
             }
+            Err(err) => return Err(err),
         };
 
-        let mut ret = true;
-        if res.prs.is_empty() && res.issues.is_empty() {
-            debug!("nothing to update for user '{}'.", user.login);
-            ret = false;
-        }
-
-        if let Err(err) =
-            prs::consume_issues(&mut tx, &user.id, &res.issues, &res.prs).await
-        {
-            panic!(
-                "Error updating pull requests for user '{}': {:?}",
-                login, err
-            );
-        }
-        users::update_user_refresh(&mut tx, &user.id, &res.when).await;
-
-        tx.commit().await.unwrap_or_else(|err| {
-            panic!(
-                "Unable to commit update transaction for user '{}': {}",
-                user.login, err
-            );
-        });
-
-        Ok(ret)
+        self.apply_user_update(&db, &user, &cursor, &res).await
     }
 
-    /// Obtain all Pull Requests from the provided author `login`.
+    /// Refreshes the specified users' data, running their searches in a
+    /// single request; at most `gql::MAX_SEARCH_BATCH_SIZE` logins may be
+    /// provided. Users that have never been refreshed are populated
+    /// individually instead. Returns each user's login along with its
+    /// refresh's result, in the same order as `logins`.
     ///
-    pub async fn get_pulls_by_author(
-        self: &Self,
-        db: &DB,
-        login: &String,
-    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
-        prs::get_prs_by_author(&db, &login).await
-    }
-
-    /// Obtain all Pull Requests the provided `login` is involved with, except
-    /// those that have been authored by `login`.
+    /// # Arguments
     ///
-    pub async fn get_involved_pulls(
+    /// * `db` - A GHD Database handle.
+    /// * `logins` - Logins of the users to be refreshed.
+    ///
+    pub async fn refresh_users(
         self: &Self,
         db: &DB,
-        login: &String,
-    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
-        prs::get_involved_prs(&db, &login).await
+        logins: &[String],
+    ) -> Vec<(String, Result<UserRefreshResult, GHDError>)> {
+        let mut results: Vec<Option<Result<UserRefreshResult, GHDError>>> =
+            logins.iter().map(|_| None).collect();
+        let mut batched: Vec<(usize, GithubUser)> = vec![];
+        let mut since: Vec<(String, chrono::DateTime<chrono::Utc>)> = vec![];
+
+        for (idx, login) in logins.iter().enumerate() {
+            let user = match users::get_user_by_login(&db, &login).await {
+                Ok(u) => u,
+                Err(err) => {
+                    results[idx] = Some(Err(err));
+                    continue;
+                }
+            };
+            match refresh::get_user_sync_cursor(&db, &user.id).await {
+                Ok(v) => {
+                    since.push((login.clone(), v));
+                    batched.push((idx, user));
+                }
+                Err(GHDError::NeverRefreshedError) => {
+                    results[idx] = Some(self.refresh_user(&db, &login).await);
+                }
+                Err(err) => results[idx] = Some(Err(err)),
+            };
+        }
+
+        if !batched.is_empty() {
+            let updates = match self.get_endpoint(&db).await {
+                Ok(endpoint) => gql::get_users_updates(&endpoint, &since).await,
+                Err(err) => batched.iter().map(|_| Err(err.clone())).collect(),
+            };
+            for (((idx, user), (_, cursor)), update) in
+                batched.iter().zip(since.iter()).zip(updates)
+            {
+                results[*idx] = Some(match update {
+                    Ok(res) => {
+                        self.apply_user_update(&db, &user, &cursor, &res).await
+                    }
+                    Err(err) => Err(err),
+                });
+            }
+        }
+
+        logins
+            .iter()
+            .cloned()
+            .zip(results.into_iter().map(|r| {
+                r.unwrap_or(Err(GHDError::unexpected("user not refreshed")))
+            }))
+            .collect()
     }
 
-    /// Obtain a specific Pull Request's information.
+    /// Fully reconcile the specified user's open Issues and Pull Requests with
+    /// those Github currently reports, removing those that disappeared; e.g.,
+    /// because they were deleted, transferred, made private, or no longer
+    /// involve the user. Incremental refreshes can't notice these. Nothing is
+    /// removed if Github's results were truncated, nor is anything updated
+    /// while or shortly before Github's results were obtained. Returns the
+    /// resulting changes.
     ///
     /// # Arguments
     ///
     /// * `db` - A GHD Database handle.
-    /// * `prid` - A Pull Request database ID.
+    /// * `login` - String containing the login of the user to be reconciled.
     ///
-    pub async fn get_pull_request_info(
+    pub async fn reconcile_user(
         self: &Self,
         db: &DB,
-        prid: &i64,
-    ) -> Result<PullRequestInfo, GHDError> {
-        let entry = match prs::get_issue_by_id(&db, &prid).await {
+        login: &String,
+    ) -> Result<IssuesChangeset, GHDError> {
+        let user = match users::get_user_by_login(&db, &login).await {
+            Ok(u) => u,
             Err(err) => return Err(err),
-            Ok(res) => res,
         };
 
-        let token = match self.get_token(&db).await {
-            Ok(t) => t.clone(),
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
             Err(err) => return Err(err),
         };
 
-        let res = match gql::get_pull_request_info(
-            &token,
-            &entry.repo_owner,
-            &entry.repo_name,
-            &entry.number,
+        // a concurrent refresh may record entries Github's results don't
+        // have yet, and the search index lags behind; only entries last
+        // updated well before the results were obtained can be removed.
+        let snapshot = chrono::Utc::now()
+            - chrono::Duration::seconds(sync::SYNC_OVERLAP_SECS);
+        let res = match gql::get_user_open_issues(&endpoint, &login).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to reconcile user",
+                    err,
+                ));
+            }
+        };
+
+        let mut changes = IssuesChangeset::new(&user.id);
+        if res.truncated {
+            warn!(
+                "Open issues truncated for user '{}', not reconciling.",
+                login
+            );
+        } else {
+            let seen: HashSet<i64> = res
+                .issues
+                .iter()
+                .map(|issue| issue.id)
+                .chain(res.prs.iter().map(|pr| pr.issue.id))
+                .collect();
+            changes.removed = match prs::reconcile_user_issues(
+                &mut tx, &user.id, &seen, &snapshot,
+            )
+            .await
+            {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+        }
+
+        if let Err(err) = refresh::update_user_reconcile(
+            &mut tx,
+            &user.id,
+            &chrono::Utc::now(),
         )
         .await
         {
-            Ok(r) => r,
-            Err(err) => return Err(err),
-        };
+            return Err(err);
+        }
 
-        Ok(res)
+        match tx.commit().await {
+            Ok(_) => Ok(changes),
+            Err(err) => Err(GHDError::db(
+                &format!("committing reconcile transaction for '{}'", login),
+                err,
+            )),
+        }
     }
 
-    /// Marks a specified Pull Request as having been viewed.
+    /// Apply an update obtained for the specified user, persisting its Issues
+    /// and Pull Requests, and updating the user's refresh time. If the user is
+    /// GHD's main user, the result will also contain any activity the user may
+    /// want to be notified about.
     ///
     /// # Arguments
     ///
     /// * `db` - A GHD Database handle.
-    /// * `prid` - The Pull Request's database ID.
+    /// * `user` - The user the update was obtained for.
+    /// * `since` - The sync cursor the update was obtained from.
+    /// * `res` - The update to apply.
     ///
-    pub async fn mark_pull_request_viewed(
+    async fn apply_user_update(
         self: &Self,
         db: &DB,
-        prid: &i64,
-    ) -> Result<(), GHDError> {
-        prs::mark_viewed(&db, &prid).await
+        user: &GithubUser,
+        since: &chrono::DateTime<chrono::Utc>,
+        res: &UserUpdate,
+    ) -> Result<UserRefreshResult, GHDError> {
+        let login = &user.login;
+
+        // we only care about activity relevant to the main user; failing to
+        // obtain it should not prevent us from refreshing.
+        let is_main_user = match users::get_main_user(&db).await {
+            Ok(main) => main.id == user.id,
+            Err(_) => false,
+        };
+        let activity = if is_main_user {
+            match activity::get_activity(&db, &login, &res).await {
+                Ok(v) => v,
+                Err(err) => {
+                    warn!("Unable to obtain activity for '{}': {}", login, err);
+                    vec![]
+                }
+            }
+        } else {
+            vec![]
+        };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to update user",
+                    err,
+                ));
+            }
+        };
+
+        let changes =
+            match prs::consume_issues(&mut tx, &user, &res.issues, &res.prs)
+                .await
+            {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+        // searches overlap, so we will often see items we already have.
+        let updated = !changes.is_empty();
+        if !updated {
+            debug!("nothing to update for user '{}'.", user.login);
+        }
+        if res.truncated {
+            warn!(
+                "Search results truncated for user '{}', keeping sync cursor.",
+                login
+            );
+        }
+        let cursor = sync::cursor_after_refresh(
+            &Some(*since),
+            &res.cursor,
+            res.truncated,
+        );
+        if let Err(err) = users::update_user_refresh(
+            &mut tx,
+            &user.id,
+            &chrono::Utc::now(),
+            &cursor,
+        )
+        .await
+        {
+            return Err(err);
+        }
+
+        match tx.commit().await {
+            Ok(_) => Ok(UserRefreshResult {
+                updated,
+                changes,
+                activity,
+            }),
+            Err(err) => Err(GHDError::db(
+                &format!("committing update transaction for '{}'", login),
+                err,
+            )),
+        }
     }
 
-    /// Marks multiple Pull Requests as having been viewed.
+    /// Track the specified team by its combined slug; e.g., `org/team`. If the
+    /// team is already being tracked, return the existing team. Otherwise,
+    /// will obtain the team and its members from Github. If the team is
+    /// ultimately added to the database, will callback the provided function
+    /// once the data is persisted.
     ///
     /// # Arguments
     ///
-    /// * `db` - A GHD Database handle.
-    /// * `prs` - A Vector of Pull Request database IDs.
+    /// * `db` - The GHD Database handle.
+    /// * `team` - String containing the team's combined slug.
+    /// * `cb` - Callback function that will be called if a new team is added
+    ///   and the data has been persisted.
     ///
-    pub async fn mark_pull_request_viewed_many(
+    pub async fn track_team<F>(
         self: &Self,
         db: &DB,
-        prs: &Vec<i64>,
-    ) -> Result<(), GHDError> {
-        prs::mark_viewed_many(&db, &prs).await
+        team: &String,
+        cb: F,
+    ) -> Result<GithubTeam, GHDError>
+    where
+        F: FnOnce(&GithubTeam),
+    {
+        let (org, slug) = match teams::parse_team(&team) {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        match teams::get_team_by_slug(&db, &org, &slug).await {
+            Ok(res) => {
+                debug!("team {} already exists!", team);
+                return Ok(res);
+            }
+            Err(_) => {}
+        };
+
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
+        let info = match gql::get_team_info(&endpoint, &org, &slug).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to track team",
+                    err,
+                ));
+            }
+        };
+        if let Err(err) = teams::store_team(&mut tx, &info).await {
+            return Err(err);
+        }
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db(
+                "committing transaction to track new team",
+                err,
+            ));
+        }
+
+        if let Err(err) = self.refresh_team(&db, &info.team).await {
+            // the team will be populated on its next refresh.
+            warn!("Unable to populate team '{}': {}", team, err);
+        }
+
+        cb(&info.team);
+        Ok(info.team)
     }
 
-    /// Archive the provided issue with ID `issue_id`.
+    /// Refreshes the specified team's members, and the Pull Requests for
+    /// which a review has been requested from the team.
     ///
     /// # Arguments
     ///
     /// * `db` - A GHD Database handle.
-    /// * `issue_id` - The Issue's database ID.
+    /// * `team` - The team to be refreshed.
     ///
-    pub async fn archive_issue(
+    pub async fn refresh_team(
         self: &Self,
         db: &DB,
-        issue_id: &i64,
+        team: &GithubTeam,
     ) -> Result<(), GHDError> {
-        prs::archive_issue(&db, &issue_id).await
-    }
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
 
-    /// Archive multiple issues.
-    ///
+        let info =
+            match gql::get_team_info(&endpoint, &team.org, &team.slug).await {
+                Ok(res) => res,
+                Err(err) => return Err(err),
+            };
+        let res = match gql::get_team_review_requests(&endpoint, &team).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to update team",
+                    err,
+                ));
+            }
+        };
+        if let Err(err) = teams::store_team(&mut tx, &info).await {
+            return Err(err);
+        }
+        if let Err(err) =
+            prs::consume_team_pulls(&mut tx, &info.team, &res.prs).await
+        {
+            return Err(err);
+        }
+        if let Err(err) =
+            teams::update_team_refresh(&mut tx, &team.id, &chrono::Utc::now())
+                .await
+        {
+            return Err(err);
+        }
+
+        match tx.commit().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(GHDError::db(
+                &format!(
+                    "committing update transaction for team '{}'",
+                    team.combined_slug()
+                ),
+                err,
+            )),
+        }
+    }
+
+    /// Obtain all open Pull Requests for which a review has been requested
+    /// from the provided `team`, by its combined slug; e.g., `org/team`.
+    ///
+    pub async fn get_team_review_requests(
+        self: &Self,
+        db: &DB,
+        team: &String,
+    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+        let (org, slug) = match teams::parse_team(&team) {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        let team = match teams::get_team_by_slug(&db, &org, &slug).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        teams::get_review_requests(&db, &team).await
+    }
+
+    /// Track the specified repository by its full name; e.g., `owner/name`.
+    /// If the repository is already being tracked, return the existing
+    /// repository. Otherwise, will check the repository exists on Github. If
+    /// the repository is ultimately added to the database, will callback the
+    /// provided function once the data is persisted.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The GHD Database handle.
+    /// * `repo` - String containing the repository's full name.
+    /// * `cb` - Callback function that will be called if a new repository is
+    ///   added and the data has been persisted.
+    ///
+    pub async fn track_repo<F>(
+        self: &Self,
+        db: &DB,
+        repo: &String,
+        cb: F,
+    ) -> Result<TrackedRepo, GHDError>
+    where
+        F: FnOnce(&TrackedRepo),
+    {
+        let (owner, name) = match repos::parse_repo(&repo) {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        match repos::get_repo_by_name(&db, &owner, &name).await {
+            Ok(res) => {
+                debug!("repository {} already tracked!", repo);
+                return Ok(res);
+            }
+            Err(_) => {}
+        };
+
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
+        let tracked =
+            match repos::get_repo_from_github(&endpoint, &owner, &name).await {
+                Ok(res) => res,
+                Err(err) => return Err(err),
+            };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to track repository",
+                    err,
+                ));
+            }
+        };
+        if let Err(err) = repos::add_repo_to_db(&mut tx, &tracked).await {
+            return Err(err);
+        }
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db(
+                "committing transaction to track new repository",
+                err,
+            ));
+        }
+
+        if let Err(err) = self.refresh_repo(&db, &tracked).await {
+            // the repository will be populated on its next refresh.
+            warn!("Unable to populate repository '{}': {}", repo, err);
+        }
+
+        cb(&tracked);
+        Ok(tracked)
+    }
+
+    /// Refreshes the specified repository's Issues and Pull Requests. If the
+    /// repository has never been refreshed, obtain all its open Issues and
+    /// Pull Requests; otherwise, only those updated since the last refresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `repo` - The repository to be refreshed.
+    ///
+    pub async fn refresh_repo(
+        self: &Self,
+        db: &DB,
+        repo: &TrackedRepo,
+    ) -> Result<(), GHDError> {
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
+
+        let last_update = match repos::get_repo_sync_cursor(&db, &repo.id).await
+        {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        let res =
+            match gql::get_repo_updates(&endpoint, &repo, &last_update).await {
+                Ok(res) => res,
+                Err(err) => return Err(err),
+            };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to update repository",
+                    err,
+                ));
+            }
+        };
+        if let Err(err) =
+            prs::consume_repo_issues(&mut tx, &res.issues, &res.prs).await
+        {
+            return Err(err);
+        }
+        if res.truncated {
+            warn!(
+                "Search results truncated for repository '{}', keeping sync \
+                 cursor.",
+                repo.full_name()
+            );
+        }
+        let cursor = sync::cursor_after_refresh(
+            &last_update,
+            &res.cursor,
+            res.truncated,
+        );
+        if let Err(err) = repos::update_repo_refresh(
+            &mut tx,
+            &repo.id,
+            &chrono::Utc::now(),
+            &cursor,
+        )
+        .await
+        {
+            return Err(err);
+        }
+
+        match tx.commit().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(GHDError::db(
+                &format!(
+                    "committing update transaction for repository '{}'",
+                    repo.full_name()
+                ),
+                err,
+            )),
+        }
+    }
+
+    /// Obtain all open Pull Requests in the provided tracked repository, by
+    /// its full name; e.g., `owner/name`.
+    ///
+    pub async fn get_repo_pulls(
+        self: &Self,
+        db: &DB,
+        repo: &String,
+    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+        let (owner, name) = match repos::parse_repo(&repo) {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        let repo = match repos::get_repo_by_name(&db, &owner, &name).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        repos::get_open_prs(&db, &repo).await
+    }
+
+    /// Obtain all open Issues in the provided tracked repository, by its full
+    /// name; e.g., `owner/name`.
+    ///
+    pub async fn get_repo_issues(
+        self: &Self,
+        db: &DB,
+        repo: &String,
+    ) -> Result<Vec<IssueTableEntry>, GHDError> {
+        let (owner, name) = match repos::parse_repo(&repo) {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        let repo = match repos::get_repo_by_name(&db, &owner, &name).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        repos::get_open_issues(&db, &repo).await
+    }
+
+    /// Save a named Github search query; e.g.,
+    /// `label:needs-backport is:open org:ourorg`. If a query with the same
+    /// name and search query already exists, return it. The search query is
+    /// run before being saved, so that invalid queries are refused. If the
+    /// query is ultimately added to the database, will callback the provided
+    /// function `cb`.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `name` - String containing the saved query's name.
+    /// * `query` - String containing the Github search query.
+    /// * `cb` - Callback function that will be called if a new query is
+    ///   saved.
+    ///
+    pub async fn save_query<F>(
+        self: &Self,
+        db: &DB,
+        name: &String,
+        query: &String,
+        cb: F,
+    ) -> Result<SavedQuery, GHDError>
+    where
+        F: FnOnce(&SavedQuery),
+    {
+        let (name, query) = match saved_queries::check_query(&name, &query) {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        match saved_queries::get_query_by_name(&db, &name).await {
+            Ok(res) if res.query == query => {
+                debug!("query '{}' already saved!", name);
+                return Ok(res);
+            }
+            Ok(_) => {
+                return Err(GHDError::InvalidSavedQueryError {
+                    context: format!(
+                        "a different query named '{}' already exists",
+                        name
+                    ),
+                });
+            }
+            Err(GHDError::SavedQueryNotFoundError) => {}
+            Err(err) => return Err(err),
+        };
+
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
+        let res = match gql::get_query_updates(&endpoint, &query, &None).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to save query",
+                    err,
+                ));
+            }
+        };
+        let saved = match saved_queries::add_query_to_db(&mut tx, &name, &query)
+            .await
+        {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        if let Err(err) =
+            prs::consume_query_issues(&mut tx, &saved.id, &res.issues, &res.prs)
+                .await
+        {
+            return Err(err);
+        }
+        if let Err(err) = saved_queries::update_query_refresh(
+            &mut tx,
+            &saved.id,
+            &chrono::Utc::now(),
+            &Some(res.cursor),
+        )
+        .await
+        {
+            return Err(err);
+        }
+        if let Err(err) = tx.commit().await {
+            return Err(GHDError::db(
+                "committing transaction to save new query",
+                err,
+            ));
+        }
+
+        cb(&saved);
+        Ok(saved)
+    }
+
+    /// Refreshes the specified saved query's Issues and Pull Requests, looking
+    /// only for those updated since the last refresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `query` - The saved query to be refreshed.
+    ///
+    pub async fn refresh_query(
+        self: &Self,
+        db: &DB,
+        query: &SavedQuery,
+    ) -> Result<(), GHDError> {
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
+
+        let last_update =
+            match saved_queries::get_query_sync_cursor(&db, &query.id).await {
+                Ok(v) => v,
+                Err(err) => return Err(err),
+            };
+        let res =
+            match gql::get_query_updates(&endpoint, &query.query, &last_update)
+                .await
+            {
+                Ok(res) => res,
+                Err(err) => return Err(err),
+            };
+
+        let mut tx = match db.begin().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::db(
+                    "starting transaction to update saved query",
+                    err,
+                ));
+            }
+        };
+        if let Err(err) =
+            prs::consume_query_issues(&mut tx, &query.id, &res.issues, &res.prs)
+                .await
+        {
+            return Err(err);
+        }
+        if res.truncated {
+            warn!(
+                "Search results truncated for saved query '{}', keeping sync \
+                 cursor.",
+                query.name
+            );
+        }
+        let cursor = sync::cursor_after_refresh(
+            &last_update,
+            &res.cursor,
+            res.truncated,
+        );
+        if let Err(err) = saved_queries::update_query_refresh(
+            &mut tx,
+            &query.id,
+            &chrono::Utc::now(),
+            &cursor,
+        )
+        .await
+        {
+            return Err(err);
+        }
+
+        match tx.commit().await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(GHDError::db(
+                &format!(
+                    "committing update transaction for saved query '{}'",
+                    query.name
+                ),
+                err,
+            )),
+        }
+    }
+
+    /// Obtain all Pull Requests and Issues matching the saved query with the
+    /// provided `id`.
+    ///
+    pub async fn get_query_results(
+        self: &Self,
+        db: &DB,
+        id: &i64,
+    ) -> Result<SavedQueryResults, GHDError> {
+        let query = match saved_queries::get_query_by_id(&db, &id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        let prs = match saved_queries::get_query_prs(&db, &query).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        let issues = match saved_queries::get_query_issues(&db, &query).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        Ok(SavedQueryResults { query, prs, issues })
+    }
+
+    /// Obtain the Issues and Pull Requests needing the main user's attention,
+    /// ranked by why they need it.
+    ///
+    pub async fn get_inbox(
+        self: &Self,
+        db: &DB,
+    ) -> Result<Vec<InboxItem>, GHDError> {
+        let user = match users::get_main_user(&db).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        inbox::get_inbox(&db, &user).await
+    }
+
+    /// Obtain all Pull Requests from the provided author `login`.
+    ///
+    pub async fn get_pulls_by_author(
+        self: &Self,
+        db: &DB,
+        login: &String,
+    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+        prs::get_prs_by_author(&db, &login).await
+    }
+
+    /// Obtain all Pull Requests the provided `login` is involved with, except
+    /// those that have been authored by `login`.
+    ///
+    pub async fn get_involved_pulls(
+        self: &Self,
+        db: &DB,
+        login: &String,
+    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+        prs::get_involved_prs(&db, &login).await
+    }
+
+    /// Obtain all Pull Requests the provided `login` is involved with in the
+    /// specified way, including those authored by `login`.
+    ///
+    pub async fn get_pulls_by_involvement(
+        self: &Self,
+        db: &DB,
+        login: &String,
+        involvement: &Involvement,
+    ) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+        prs::get_prs_by_involvement(&db, &login, &involvement).await
+    }
+
+    /// Obtain all Issues from the provided author `login`.
+    ///
+    pub async fn get_issues_by_author(
+        self: &Self,
+        db: &DB,
+        login: &String,
+    ) -> Result<Vec<IssueTableEntry>, GHDError> {
+        issues::get_issues_by_author(&db, &login).await
+    }
+
+    /// Obtain all Issues the provided `login` is involved with, except those
+    /// that have been authored by `login`.
+    ///
+    pub async fn get_involved_issues(
+        self: &Self,
+        db: &DB,
+        login: &String,
+    ) -> Result<Vec<IssueTableEntry>, GHDError> {
+        issues::get_involved_issues(&db, &login).await
+    }
+
+    /// Obtain all Issues the provided `login` is involved with in the
+    /// specified way, including those authored by `login`.
+    ///
+    pub async fn get_issues_by_involvement(
+        self: &Self,
+        db: &DB,
+        login: &String,
+        involvement: &Involvement,
+    ) -> Result<Vec<IssueTableEntry>, GHDError> {
+        issues::get_issues_by_involvement(&db, &login, &involvement).await
+    }
+
+    /// Obtain all Issues assigned to the provided `login`.
+    ///
+    pub async fn get_assigned_issues(
+        self: &Self,
+        db: &DB,
+        login: &String,
+    ) -> Result<Vec<IssueTableEntry>, GHDError> {
+        issues::get_assigned_issues(&db, &login).await
+    }
+
+    /// Obtain a specific Pull Request's information.
+    ///
+    /// Information is served from the database if we have it cached. If the
+    /// cached copy is older than the Pull Request's last update, it will be
+    /// refreshed in the background, and `cb` called with the Pull Request's ID
+    /// once the refreshed information is available. If we don't have it
+    /// cached, it will be obtained from Github and cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `prid` - A Pull Request database ID.
+    /// * `cb` - Callback for when refreshed information is available.
+    ///
+    pub async fn get_pull_request_info<F>(
+        self: &Self,
+        db: &DB,
+        prid: &i64,
+        cb: F,
+    ) -> Result<PullRequestInfo, GHDError>
+    where
+        F: FnOnce(&i64) + Send + 'static,
+    {
+        let entry = match prs::get_issue_by_id(&db, &prid).await {
+            Err(GHDError::IssueNotFoundError) => {
+                return Err(GHDError::PullRequestNotFoundError);
+            }
+            Err(err) => return Err(err),
+            Ok(res) => res,
+        };
+
+        let cached = match prinfo::get_cached_info(&db, &prid).await {
+            Ok(res) => res,
+            Err(err) => {
+                warn!("Unable to obtain cached info for pr {}: {}", prid, err);
+                None
+            }
+        };
+
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => {
+                // we may still be able to serve from the cache, e.g. if the
+                // token has been invalidated meanwhile.
+                return match cached {
+                    Some((info, _)) => Ok(info),
+                    None => Err(err),
+                };
+            }
+        };
+
+        if let Some((info, fetched_at)) = cached {
+            if fetched_at < entry.updated_at {
+                debug!("cached info for pr {} is stale, refresh.", prid);
+                let db = db.clone();
+                let prid = prid.clone();
+                tokio::spawn(async move {
+                    match fetch_pull_request_info(&db, &endpoint, &prid).await {
+                        Ok(_) => cb(&prid),
+                        Err(err) => {
+                            warn!(
+                                "Unable to refresh info for pr {}: {}",
+                                prid, err
+                            );
+                        }
+                    };
+                });
+            }
+            return Ok(info);
+        }
+
+        fetch_pull_request_info(&db, &endpoint, &prid).await
+    }
+
+    /// Obtain a specific Issue's information. Pull Requests are not Issues,
+    /// and are refused; their information is obtained through
+    /// `get_pull_request_info()` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `issue_id` - An Issue database ID.
+    ///
+    pub async fn get_issue_info(
+        self: &Self,
+        db: &DB,
+        issue_id: &i64,
+    ) -> Result<IssueInfo, GHDError> {
+        let entry = match prs::get_issue_by_id(&db, &issue_id).await {
+            Err(err) => return Err(err),
+            Ok(res) => res,
+        };
+        if entry.is_pull_request {
+            return Err(GHDError::invalid_state(&format!(
+                "issue {} is a pull request",
+                issue_id
+            )));
+        }
+
+        let endpoint = match self.get_endpoint(&db).await {
+            Ok(ep) => ep,
+            Err(err) => return Err(err),
+        };
+
+        gql::get_issue_info(
+            &endpoint,
+            &entry.repo_owner,
+            &entry.repo_name,
+            &entry.number,
+        )
+        .await
+    }
+
+    /// Marks a specified Pull Request as having been viewed.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `prid` - The Pull Request's database ID.
+    ///
+    pub async fn mark_pull_request_viewed(
+        self: &Self,
+        db: &DB,
+        prid: &i64,
+    ) -> Result<(), GHDError> {
+        prs::mark_viewed(&db, &prid).await
+    }
+
+    /// Marks multiple Pull Requests as having been viewed.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `prs` - A Vector of Pull Request database IDs.
+    ///
+    pub async fn mark_pull_request_viewed_many(
+        self: &Self,
+        db: &DB,
+        prs: &Vec<i64>,
+    ) -> Result<(), GHDError> {
+        prs::mark_viewed_many(&db, &prs).await
+    }
+
+    /// Archive the provided issue with ID `issue_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - A GHD Database handle.
+    /// * `issue_id` - The Issue's database ID.
+    ///
+    pub async fn archive_issue(
+        self: &Self,
+        db: &DB,
+        issue_id: &i64,
+    ) -> Result<(), GHDError> {
+        prs::archive_issue(&db, &issue_id).await
+    }
+
+    /// Archive multiple issues.
+    ///
     /// # Arguments
     ///
     /// * `db` - A GHD Database handle.
@@ -567,3 +1604,41 @@ impl Github {
         prs::archive_issue_many(&db, &issues).await
     }
 }
+
+/// Obtain a specific Pull Request's information from Github, and cache it in
+/// the database.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `endpoint` - The Github API endpoint to use.
+/// * `prid` - A Pull Request database ID.
+///
+async fn fetch_pull_request_info(
+    db: &DB,
+    endpoint: &GithubEndpoint,
+    prid: &i64,
+) -> Result<PullRequestInfo, GHDError> {
+    let entry = match prs::get_issue_by_id(&db, &prid).await {
+        Err(err) => return Err(err),
+        Ok(res) => res,
+    };
+
+    let res = match gql::get_pull_request_info(
+        &endpoint,
+        &entry.repo_owner,
+        &entry.repo_name,
+        &entry.number,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(err) => return Err(err),
+    };
+
+    if let Err(err) = prinfo::store_info(&db, &prid, &res).await {
+        warn!("Unable to cache info for pr {}: {}", prid, err);
+    }
+
+    Ok(res)
+}
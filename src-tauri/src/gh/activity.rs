@@ -0,0 +1,231 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{db::DB, errors::GHDError};
+
+use super::sync;
+use super::types::{Activity, ActivityKind, Issue, UserUpdate};
+
+/// The state of an Issue or Pull Request as it was in the database before
+/// being updated. Pull Request specific fields are `None` for Issues.
+///
+#[derive(sqlx::FromRow)]
+struct PreviousState {
+    updated_at: i64,
+    total_comments: Option<i64>,
+    review_decision: Option<String>,
+    merged_at: Option<i64>,
+    review_requested: bool,
+}
+
+/// Obtain the state of the Issue or Pull Request with database ID `id`, as it
+/// currently is in the database, if it exists.
+///
+async fn get_previous_state(
+    db: &DB,
+    login: &String,
+    id: &i64,
+) -> Result<Option<PreviousState>, GHDError> {
+    match sqlx::query_as::<_, PreviousState>(
+        "
+        SELECT
+            issues.updated_at, issues.total_comments,
+            pull_requests.review_decision,
+            pull_requests.merged_at,
+            EXISTS (
+                SELECT 1 FROM pull_request_review_requests
+                WHERE pr_id = issues.id AND login = ?
+            ) AS review_requested
+        FROM
+            issues LEFT JOIN pull_requests
+        ON
+            issues.id = pull_requests.id
+        WHERE
+            issues.id = ?
+        ",
+    )
+    .bind(&login)
+    .bind(&id)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => Ok(Some(res)),
+        Err(sqlx::Error::RowNotFound) => Ok(None),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining previous state for issue {}", id),
+            err,
+        )),
+    }
+}
+
+/// Check whether `issue` is older than its previous state, if any.
+///
+fn is_stale(prev: &Option<PreviousState>, issue: &Issue) -> bool {
+    sync::is_stale(&prev.as_ref().map(|p| p.updated_at), &issue.updated_at)
+}
+
+/// Build an `Activity` of the provided `kind` for `issue`.
+///
+fn new_activity(
+    kind: ActivityKind,
+    issue: &Issue,
+    actor: Option<String>,
+) -> Activity {
+    Activity {
+        kind,
+        issue_id: issue.id,
+        number: issue.number,
+        title: issue.title.clone(),
+        repo_owner: issue.repo_owner.clone(),
+        repo_name: issue.repo_name.clone(),
+        url: issue.url.clone(),
+        is_pull_request: issue.is_pull_request,
+        actor,
+    }
+}
+
+/// Classify comment activity on `issue`, if any. A mention of `login` takes
+/// precedence over a new comment; comments by `login` are ignored.
+///
+fn get_comment_activity(
+    login: &String,
+    issue: &Issue,
+    prev: &Option<PreviousState>,
+) -> Option<Activity> {
+    let prev = match prev {
+        None => {
+            // new to us; we may have been mentioned when it was opened.
+            if &issue.author != login && issue.body_mentions.contains(login) {
+                return Some(new_activity(
+                    ActivityKind::Mention,
+                    issue,
+                    Some(issue.author.clone()),
+                ));
+            }
+            return None;
+        }
+        Some(v) => v,
+    };
+
+    // we don't know how many comments there were before, so we can't tell
+    // whether there are new ones.
+    let prev_comments = match prev.total_comments {
+        None => return None,
+        Some(v) => v,
+    };
+    if issue.total_comments <= prev_comments {
+        return None;
+    }
+    if issue.last_commenter.as_ref() == Some(login) {
+        return None;
+    }
+
+    let kind = if issue.last_comment_mentions.contains(login) {
+        ActivityKind::Mention
+    } else {
+        ActivityKind::NewComment
+    };
+    Some(new_activity(kind, issue, issue.last_commenter.clone()))
+}
+
+/// Classify the activity in the provided user update that may be relevant to
+/// `login`, by comparing it against what is currently in the database. Must be
+/// called before the update is consumed. Copies older than what is stored are
+/// ignored, as they will not be consumed.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - The login of the user the activity is relevant to.
+/// * `update` - The user update to classify.
+///
+pub async fn get_activity(
+    db: &DB,
+    login: &String,
+    update: &UserUpdate,
+) -> Result<Vec<Activity>, GHDError> {
+    let mut res: Vec<Activity> = vec![];
+
+    for issue in &update.issues {
+        let prev = match get_previous_state(&db, &login, &issue.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale(&prev, &issue) {
+            continue;
+        }
+        if let Some(a) = get_comment_activity(&login, &issue, &prev) {
+            res.push(a);
+        }
+    }
+
+    for pr in &update.prs {
+        let prev = match get_previous_state(&db, &login, &pr.issue.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale(&prev, &pr.issue) {
+            continue;
+        }
+
+        let was_requested = match &prev {
+            None => false,
+            Some(p) => p.review_requested,
+        };
+        if !was_requested && pr.review_requests.contains(login) {
+            res.push(new_activity(
+                ActivityKind::ReviewRequested,
+                &pr.issue,
+                Some(pr.issue.author.clone()),
+            ));
+        }
+
+        // reviews and merges are only relevant for our own pull requests.
+        if &pr.issue.author == login {
+            let prev_decision = match &prev {
+                None => None,
+                Some(p) => p.review_decision.clone(),
+            };
+            if prev_decision.as_ref() != Some(&pr.review_decision) {
+                if pr.review_decision == "approved" {
+                    res.push(new_activity(
+                        ActivityKind::Approved,
+                        &pr.issue,
+                        None,
+                    ));
+                } else if pr.review_decision == "changes_requested" {
+                    res.push(new_activity(
+                        ActivityKind::ChangesRequested,
+                        &pr.issue,
+                        None,
+                    ));
+                }
+            }
+
+            let was_merged = match &prev {
+                None => false,
+                Some(p) => p.merged_at.is_some(),
+            };
+            if !was_merged && pr.merged_at.is_some() {
+                res.push(new_activity(ActivityKind::Merged, &pr.issue, None));
+            }
+        }
+
+        if let Some(a) = get_comment_activity(&login, &pr.issue, &prev) {
+            res.push(a);
+        }
+    }
+
+    Ok(res)
+}
@@ -15,61 +15,199 @@
 mod custom_types;
 mod queries;
 
+use std::collections::HashMap;
+
 use graphql_client::GraphQLQuery;
+use log::warn;
 use queries::{user_info, UserInfo};
 
 use crate::{
-    errors::GHDError,
+    errors::{GHDError, GQLError},
     gh::types::{Label, UserReview},
 };
 
 use self::queries::{
+    batch_search_issues,
+    get_issue_info::{
+        self, GetIssueInfoRepositoryIssueAuthor,
+        GetIssueInfoRepositoryIssueTimelineItemsNodes,
+        LinkedPullRequestFragment,
+    },
     get_pull_request_info::{
         self, GetPullRequestInfoRepositoryPullRequestAuthor,
     },
     search_issues::{
-        self, IssueState, PullRequestReviewDecision, SearchIssuesSearchNodes,
-        SearchIssuesSearchNodesOnIssue, SearchIssuesSearchNodesOnIssueAuthor,
-        SearchIssuesSearchNodesOnPullRequest,
-        SearchIssuesSearchNodesOnPullRequestAuthor, UserFragment,
+        self, IssueState, PullRequestReviewDecision, SearchIssuesResultsNodes,
+        SearchIssuesResultsNodesOnIssue, SearchIssuesResultsNodesOnIssueAuthor,
+        SearchIssuesResultsNodesOnIssueCommentsNodesAuthor,
+        SearchIssuesResultsNodesOnPullRequest,
+        SearchIssuesResultsNodesOnPullRequestAuthor,
+        SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer,
+        UserFragment,
     },
-    GetPullRequestInfo, SearchIssues,
+    team_info, GetIssueInfo, GetPullRequestInfo, SearchIssues, TeamInfo,
 };
 
+use super::ratelimit::{RateLimitResource, RateLimitTracker};
+use super::sync;
 use super::types::{
-    GithubUser, Issue, Milestone, PullRequest, PullRequestInfo, UserUpdate,
+    CheckInfo, GithubEndpoint, GithubTeam, GithubTeamInfo, GithubUser, Issue,
+    IssueInfo, LinkedPullRequest, MergeQueueEntry, Milestone, PullRequest,
+    PullRequestInfo, Reaction, TrackedRepo, UserUpdate,
 };
 
+/// Github's "ghost" user, standing in for deleted accounts.
+///
+const GHOST_USER_LOGIN: &str = "ghost";
+const GHOST_USER_ID: i64 = 10137;
+
+/// Maximum number of searches we will batch into a single request. Each
+/// search may return up to 100 nodes, each with their own connections, and
+/// Github limits how many nodes a single request may return. Must match the
+/// number of searches in the `BatchSearchIssues` query.
+///
+pub const MAX_SEARCH_BATCH_SIZE: usize = 10;
+
+/// The GraphQL response envelope. The API may return `errors` alongside
+/// `data`, in which case `data` may be partial (e.g., with `null` in place of
+/// nodes we can no longer access).
+///
 #[derive(serde::Deserialize, Debug)]
 struct GQLResData<T> {
+    pub data: Option<T>,
+    pub errors: Option<Vec<GQLError>>,
+}
+
+/// A query's response data, along with any errors returned with it. If
+/// `errors` is not empty, `data` may be partial. `server_time` is the time
+/// reported by Github when the response was produced, if any.
+///
+struct GQLPartialData<T> {
     pub data: T,
+    pub errors: Vec<GQLError>,
+    pub server_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Search results, along with the time reported by Github when the search's
+/// first page was obtained, if any.
+///
+struct SearchResults {
+    pub data: search_issues::ResponseData,
+    pub server_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The `rateLimit` object returned by queries requesting it. Obtained
+/// separately from the query's own response data, so we don't need to handle
+/// it for every query.
+///
+#[derive(serde::Deserialize, Debug)]
+struct GQLRateLimit {
+    pub cost: i64,
+    pub limit: i64,
+    pub remaining: i64,
+    #[serde(rename = "resetAt")]
+    pub reset_at: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct GQLRateLimitData {
+    #[serde(rename = "rateLimit")]
+    pub rate_limit: Option<GQLRateLimit>,
+}
+
+/// Request body for a `BatchSearchIssues` query, running up to
+/// `MAX_SEARCH_BATCH_SIZE` searches at once. The search at index `N` is
+/// aliased as `uN`, with its query in variable `qN`, and is only run if
+/// variable `iN` is set.
+///
+#[derive(serde::Serialize)]
+struct GQLBatchQueryBody {
+    pub query: &'static str,
+    pub variables: HashMap<String, serde_json::Value>,
+    #[serde(rename = "operationName")]
+    pub operation_name: &'static str,
+}
+
+impl GQLBatchQueryBody {
+    /// Build a batched search request for the provided `queries`. Each search
+    /// selects the same fields as the `SearchIssues` query, so its results can
+    /// be decoded into the same types.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - Strings containing the search queries to batch.
+    ///
+    pub fn new(queries: &[String]) -> Result<Self, GHDError> {
+        if queries.len() > MAX_SEARCH_BATCH_SIZE {
+            return Err(GHDError::unexpected(&format!(
+                "unable to batch {} searches, at most {} allowed",
+                queries.len(),
+                MAX_SEARCH_BATCH_SIZE
+            )));
+        }
+
+        let mut variables = HashMap::new();
+        for (idx, query) in queries.iter().enumerate() {
+            variables.insert(format!("q{}", idx), serde_json::json!(query));
+            variables.insert(format!("i{}", idx), serde_json::json!(true));
+        }
+
+        Ok(GQLBatchQueryBody {
+            query: batch_search_issues::QUERY,
+            variables,
+            operation_name: batch_search_issues::OPERATION_NAME,
+        })
+    }
 }
 
 struct GithubGQLRequest {
     client: reqwest::Client,
+    url: String,
+    max_pages: usize,
+    ratelimit: RateLimitTracker,
 }
 
 impl GithubGQLRequest {
-    pub fn new(token: &String) -> Self {
-        GithubGQLRequest {
-            client: reqwest::Client::builder()
-                .user_agent("GHD")
-                .default_headers(
-                    std::iter::once((
-                        reqwest::header::AUTHORIZATION,
-                        reqwest::header::HeaderValue::from_str(&format!(
-                            "Bearer {}",
-                            token
-                        ))
-                        .unwrap(),
-                    ))
+    /// Create a new request handle for the provided `endpoint`. Fails if the
+    /// endpoint's token can't be used in a header, which means it is not a
+    /// valid token.
+    ///
+    pub fn new(endpoint: &GithubEndpoint) -> Result<Self, GHDError> {
+        let auth = match reqwest::header::HeaderValue::from_str(&format!(
+            "Bearer {}",
+            endpoint.token
+        )) {
+            Ok(v) => v,
+            Err(_) => {
+                warn!("Token contains invalid characters!");
+                return Err(GHDError::BadTokenError);
+            }
+        };
+        let client = match reqwest::Client::builder()
+            .user_agent("GHD")
+            .default_headers(
+                std::iter::once((reqwest::header::AUTHORIZATION, auth))
                     .collect(),
-                )
-                .build()
-                .unwrap(),
-        }
+            )
+            .build()
+        {
+            Ok(v) => v,
+            Err(err) => {
+                return Err(GHDError::network("building GraphQL client", err));
+            }
+        };
+
+        Ok(GithubGQLRequest {
+            client,
+            url: endpoint.graphql_url(),
+            max_pages: endpoint.max_pages,
+            ratelimit: endpoint.ratelimit.clone(),
+        })
     }
 
+    /// Execute a GraphQL query, failing if the API returns any errors, even if
+    /// alongside partial data.
+    ///
     async fn execute<'a, T, M>(
         self: &Self,
         variables: T::Variables,
@@ -78,59 +216,93 @@ impl GithubGQLRequest {
         T: GraphQLQuery,
         M: for<'de> serde::Deserialize<'de> + core::fmt::Debug,
     {
-        let debug = std::env::var("GHD_GQL_DEBUG").is_ok();
         let req_body = T::build_query(variables);
-        let res = match self
-            .client
-            .post("https://api.github.com/graphql")
-            .json(&req_body)
-            .send()
-            .await
+        let context = req_body.operation_name;
+        let res = match self.execute_partial::<_, M>(context, &req_body).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        if !res.errors.is_empty() {
+            return Err(map_gql_errors(context, res.errors));
+        }
+        Ok(res.data)
+    }
+
+    /// Execute a GraphQL query, accepting partial data. Errors returned along
+    /// with data are logged and handed to the caller, which should decide
+    /// whether the data is still usable. If no data is returned, the errors
+    /// are mapped into a `GHDError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation_name` - The query's operation name, used as context.
+    /// * `req_body` - The request body; either a `graphql_client` query body,
+    ///   or one we built ourselves.
+    ///
+    async fn execute_partial<B, M>(
+        self: &Self,
+        operation_name: &str,
+        req_body: &B,
+    ) -> Result<GQLPartialData<M>, GHDError>
+    where
+        B: serde::Serialize,
+        M: for<'de> serde::Deserialize<'de> + core::fmt::Debug,
+    {
+        let debug = std::env::var("GHD_GQL_DEBUG").is_ok();
+        let res = match self.client.post(&self.url).json(req_body).send().await
         {
             Ok(res) => res,
             Err(err) => {
-                println!("unknown error from send: {}", err);
-                return Err(GHDError::UnknownError);
+                return Err(GHDError::network("sending GraphQL request", err));
             }
         };
 
-        match res.status() {
-            reqwest::StatusCode::OK => {}
-            reqwest::StatusCode::FORBIDDEN => {
-                return Err(GHDError::BadTokenError);
-            }
-            reqwest::StatusCode::NOT_FOUND => {
-                return Err(GHDError::UserNotFoundError);
-            }
-            reqwest::StatusCode::BAD_REQUEST => {
-                return Err(GHDError::BadRequest);
-            }
-            reqwest::StatusCode::UNAUTHORIZED => {
-                return Err(GHDError::BadTokenError);
-            }
-            err => {
-                println!("unknown error: {}", err);
-                return Err(GHDError::UnknownError);
+        self.ratelimit
+            .update_from_headers(RateLimitResource::GraphQL, res.headers());
+        let server_time = get_server_time(res.headers());
+
+        let status = res.status();
+        if status != reqwest::StatusCode::OK {
+            let headers = res.headers().clone();
+            let body = res.text().await.unwrap_or_default();
+            if self.ratelimit.check_rate_limited(status, &headers, &body) {
+                return Err(GHDError::RateLimitedError);
             }
-        };
 
-        let res_body = res.text().await.unwrap_or_else(|err| {
-            panic!("Unable to unwrap graphql body result: {}", err);
-        });
-        if debug {
-            println!("res body:\n{}", res_body);
+            return match status {
+                reqwest::StatusCode::NOT_FOUND => {
+                    Err(GHDError::UserNotFoundError)
+                }
+                reqwest::StatusCode::BAD_REQUEST => Err(GHDError::BadRequest),
+                // only a 401 means the token is no longer valid; any other
+                // 403 is reported as an HTTP error.
+                reqwest::StatusCode::UNAUTHORIZED => {
+                    Err(GHDError::BadTokenError)
+                }
+                err => Err(GHDError::HTTPError {
+                    context: String::from("GraphQL request"),
+                    status: err.as_u16(),
+                }),
+            };
         }
 
-        let res_data: GQLResData<M> = serde_json::from_str(&res_body)
-            .unwrap_or_else(|err| {
-                panic!("Unable to decode graphql result: {}", err);
-            });
-
+        let res_body = match res.text().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::network("reading GraphQL response", err));
+            }
+        };
         if debug {
-            println!("res data: {:?}", res_data);
+            println!("res body:\n{}", res_body);
         }
 
-        Ok(res_data.data)
+        decode_partial(
+            operation_name,
+            &res_body,
+            &self.ratelimit,
+            server_time,
+            debug,
+        )
     }
 
     /// Obtain the result from the `UserInfo` GraphQL query. This will likely go
@@ -139,23 +311,12 @@ impl GithubGQLRequest {
     pub async fn get_user_info(
         self: &Self,
         login: &String,
-    ) -> user_info::ResponseData {
+    ) -> Result<user_info::ResponseData, GHDError> {
         let vars = user_info::Variables {
             login: login.clone(),
         };
-        let response_data: user_info::ResponseData = match self
-            .execute::<UserInfo, user_info::ResponseData>(vars)
+        self.execute::<UserInfo, user_info::ResponseData>(vars)
             .await
-        {
-            Ok(res) => res,
-            Err(err) => {
-                panic!("error: {:?}", err);
-            }
-        };
-
-        println!("data: {:?}", response_data);
-
-        response_data
     }
 
     /// Obtain all open issues involving the specified user `login`. This means
@@ -169,7 +330,7 @@ impl GithubGQLRequest {
     pub async fn get_user_open_issues(
         self: &Self,
         login: &String,
-    ) -> Result<search_issues::ResponseData, GHDError> {
+    ) -> Result<SearchResults, GHDError> {
         let q = format!("involves:{} is:open", login);
         self.get_search_issues_data(&q).await
     }
@@ -189,15 +350,179 @@ impl GithubGQLRequest {
         self: &Self,
         login: &String,
         since: &String,
-    ) -> Result<search_issues::ResponseData, GHDError> {
-        let q = format!("involves:{} updated:>{}", login, since);
+    ) -> Result<SearchResults, GHDError> {
+        self.get_search_issues_data(&user_update_query(&login, &since))
+            .await
+    }
+
+    /// Obtain all open issues and pull requests in the specified repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - String containing the repository's full name; e.g.,
+    ///   `owner/name`.
+    ///
+    pub async fn get_repo_open_issues(
+        self: &Self,
+        repo: &String,
+    ) -> Result<SearchResults, GHDError> {
+        let q = format!("repo:{} is:open", repo);
         self.get_search_issues_data(&q).await
     }
 
+    /// Obtain all issues and pull requests in the specified repository that
+    /// have been updated since the specified date.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - String containing the repository's full name; e.g.,
+    ///   `owner/name`.
+    /// * `since` - String containing the date and time since which we should
+    ///   look for updates. This String must comply with RFC 3339.
+    ///
+    pub async fn get_repo_update(
+        self: &Self,
+        repo: &String,
+        since: &String,
+    ) -> Result<SearchResults, GHDError> {
+        let q = format!("repo:{} updated:>{}", repo, since);
+        self.get_search_issues_data(&q).await
+    }
+
+    /// Obtain all issues and pull requests matching the specified search
+    /// query. If `since` is provided, only those updated since then.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - String containing a Github search query.
+    /// * `since` - String containing the date and time since which we should
+    ///   look for updates, if any. This String must comply with RFC 3339.
+    ///
+    pub async fn get_query_update(
+        self: &Self,
+        query: &String,
+        since: &Option<String>,
+    ) -> Result<SearchResults, GHDError> {
+        let q = match since {
+            None => query.clone(),
+            Some(v) => format!("{} updated:>{}", query, v),
+        };
+        self.get_search_issues_data(&q).await
+    }
+
+    /// Obtain all open pull requests for which a review has been requested
+    /// from the specified `team`.
+    ///
+    /// # Arguments
+    ///
+    /// * `team` - String containing the team's combined slug; e.g.,
+    ///   `org/team`.
+    ///
+    pub async fn get_team_review_requests(
+        self: &Self,
+        team: &String,
+    ) -> Result<SearchResults, GHDError> {
+        let q = format!("team-review-requested:{} is:pr is:open", team);
+        self.get_search_issues_data(&q).await
+    }
+
+    /// Obtain a team's information, including its members. Members are
+    /// paginated by the API, so we will follow the returned cursor until there
+    /// are no more pages, or until we hit `self.max_pages`.
+    ///
+    /// # Arguments
+    ///
+    /// * `org` - String containing the team's organization.
+    /// * `slug` - String containing the team's slug.
+    ///
+    async fn get_team_info(
+        self: &Self,
+        org: &String,
+        slug: &String,
+    ) -> Result<team_info::ResponseData, GHDError> {
+        let mut after: Option<String> = None;
+        let mut merged: Option<team_info::ResponseData> = None;
+        let mut page = 0;
+
+        loop {
+            let vars = team_info::Variables {
+                org: org.clone(),
+                slug: slug.clone(),
+                after: after.clone(),
+            };
+            let response_data = match self
+                .execute::<TeamInfo, team_info::ResponseData>(vars)
+                .await
+            {
+                Ok(res) => res,
+                Err(err) => {
+                    return Err(err);
+                }
+            };
+            page += 1;
+
+            let members = match response_data
+                .organization
+                .as_ref()
+                .and_then(|o| o.team.as_ref())
+            {
+                None => {
+                    // let the caller deal with the missing team.
+                    if merged.is_none() {
+                        merged = Some(response_data);
+                    }
+                    break;
+                }
+                Some(team) => &team.members,
+            };
+            let has_next_page = members.page_info.has_next_page;
+            after = members.page_info.end_cursor.clone();
+
+            merged = match merged {
+                None => Some(response_data),
+                Some(mut res) => {
+                    let dst =
+                        res.organization.as_mut().and_then(|o| o.team.as_mut());
+                    let src = response_data
+                        .organization
+                        .and_then(|o| o.team)
+                        .and_then(|t| t.members.nodes);
+                    if let (Some(dst), Some(nodes)) = (dst, src) {
+                        dst.members
+                            .nodes
+                            .get_or_insert_with(Vec::new)
+                            .extend(nodes);
+                    }
+                    Some(res)
+                }
+            };
+
+            if !has_next_page || after.is_none() {
+                break;
+            }
+            if page >= self.max_pages {
+                warn!(
+                    "Team members truncated at {} pages for team '{}/{}'",
+                    page, org, slug
+                );
+                break;
+            }
+        }
+
+        match merged {
+            Some(res) => Ok(res),
+            None => Err(GHDError::unexpected("no team information obtained")),
+        }
+    }
+
     /// Obtain issues matching the specified query. This function is auxiliary
     /// and implements the common functionality for the `get_user_open_issues()`
     /// and `get_user_update()` functions.
     ///
+    /// Results are paginated by the API, so we will follow the returned cursor
+    /// until there are no more pages, or until we hit `self.max_pages`. All
+    /// pages are merged into the first page's result.
+    ///
     /// # Arguments
     ///
     /// * `query` - String containing the query to be used for searching issues.
@@ -205,26 +530,177 @@ impl GithubGQLRequest {
     async fn get_search_issues_data(
         self: &Self,
         query: &String,
-    ) -> Result<search_issues::ResponseData, GHDError> {
-        let vars = search_issues::Variables { q: query.clone() };
-        let response_data: search_issues::ResponseData = match self
-            .execute::<SearchIssues, search_issues::ResponseData>(vars)
+    ) -> Result<SearchResults, GHDError> {
+        self.get_search_issues_pages(query, None).await
+    }
+
+    /// Obtain issues matching the specified query, following the returned
+    /// cursor from the provided `first` page, if any. All pages are merged
+    /// into the first page's result.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - String containing the query to be used for searching issues.
+    /// * `first` - The query's first page of results, if already obtained.
+    ///
+    async fn get_search_issues_pages(
+        self: &Self,
+        query: &String,
+        first: Option<SearchResults>,
+    ) -> Result<SearchResults, GHDError> {
+        let mut after: Option<String> = None;
+        let mut page = 0;
+        if let Some(res) = &first {
+            if !res.data.search.page_info.has_next_page {
+                return Ok(first.unwrap());
+            }
+            after = res.data.search.page_info.end_cursor.clone();
+            page = 1;
+        }
+        let mut merged = first;
+
+        loop {
+            let vars = search_issues::Variables {
+                q: query.clone(),
+                after: after.clone(),
+            };
+            // inaccessible search results are returned as null nodes, along
+            // with an error; we can live with those.
+            let req_body = SearchIssues::build_query(vars);
+            let res = match self
+                .execute_partial::<_, search_issues::ResponseData>(
+                    req_body.operation_name,
+                    &req_body,
+                )
+                .await
+            {
+                Ok(res) => res,
+                Err(err) => {
+                    return Err(err);
+                }
+            };
+            let response_data = res.data;
+            page += 1;
+
+            let has_next_page = response_data.search.page_info.has_next_page;
+            after = response_data.search.page_info.end_cursor.clone();
+
+            merged = match merged {
+                None => Some(SearchResults {
+                    data: response_data,
+                    server_time: res.server_time,
+                }),
+                Some(mut res) => {
+                    if let Some(nodes) = response_data.search.nodes {
+                        res.data
+                            .search
+                            .nodes
+                            .get_or_insert_with(Vec::new)
+                            .extend(nodes);
+                    }
+                    res.data.search.page_info = response_data.search.page_info;
+                    Some(res)
+                }
+            };
+
+            if !has_next_page || after.is_none() {
+                break;
+            }
+            if page >= self.max_pages {
+                warn!(
+                    "Search results truncated at {} pages for query '{}'",
+                    page, query
+                );
+                break;
+            }
+        }
+
+        match merged {
+            Some(res) => Ok(res),
+            None => Err(GHDError::unexpected("no search results obtained")),
+        }
+    }
+
+    /// Obtain issues matching each of the specified queries, running them as
+    /// aliased searches in a single request. Results are returned in the same
+    /// order as `queries`; a query whose results can't be obtained results in
+    /// an error for that query alone. Queries with more results than fit in
+    /// the first page are followed individually.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - Strings containing the queries to be used for searching
+    ///   issues.
+    ///
+    async fn get_batch_search_issues_data(
+        self: &Self,
+        queries: &[String],
+    ) -> Result<Vec<Result<SearchResults, GHDError>>, GHDError> {
+        let req_body = match GQLBatchQueryBody::new(queries) {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+        // inaccessible search results are returned as null nodes, along with
+        // an error; we can live with those.
+        let res = match self
+            .execute_partial::<_, HashMap<String, serde_json::Value>>(
+                req_body.operation_name,
+                &req_body,
+            )
             .await
         {
             Ok(res) => res,
-            Err(GHDError::UnknownError) => {
-                panic!("error: unknown error");
-            }
-            Err(err) => {
-                return Err(err);
-            }
+            Err(err) => return Err(err),
         };
+        let mut response_data = res.data;
 
-        Ok(response_data)
+        let mut results = vec![];
+        for (idx, query) in queries.iter().enumerate() {
+            let search = match response_data.remove(&batch_alias(idx)) {
+                None | Some(serde_json::Value::Null) => {
+                    results.push(Err(GHDError::unexpected(&format!(
+                        "no search results obtained for query '{}'",
+                        query
+                    ))));
+                    continue;
+                }
+                Some(v) => {
+                    match serde_json::from_value::<
+                        search_issues::SearchIssuesSearch,
+                    >(v)
+                    {
+                        Ok(res) => res,
+                        Err(err) => {
+                            results.push(Err(GHDError::decode(
+                                "decoding batched search results",
+                                err,
+                            )));
+                            continue;
+                        }
+                    }
+                }
+            };
+            let first = SearchResults {
+                data: search_issues::ResponseData {
+                    rate_limit: None,
+                    search,
+                },
+                server_time: res.server_time,
+            };
+            results
+                .push(self.get_search_issues_pages(&query, Some(first)).await);
+        }
+
+        Ok(results)
     }
 
     /// Obtain a given Pull Request's information.
     ///
+    /// The Pull Request's comments, reviews and participants are paginated by
+    /// the API. We will follow their cursors until all have been exhausted, or
+    /// until we hit `self.max_pages`. Nodes from subsequent pages are merged
+    /// into the first page's result.
+    ///
     /// # Arguments
     ///
     /// * `repo_owner` - String containing the Pull Request's repository owner.
@@ -237,27 +713,301 @@ impl GithubGQLRequest {
         repo_name: &String,
         pr_number: &i64,
     ) -> Result<get_pull_request_info::ResponseData, GHDError> {
-        let vars = get_pull_request_info::Variables {
+        let mut comments_after: Option<String> = None;
+        let mut reviews_after: Option<String> = None;
+        let mut participants_after: Option<String> = None;
+        let mut merged: Option<get_pull_request_info::ResponseData> = None;
+        let mut page = 0;
+
+        // whether we are still following each connection's cursor.
+        let mut want_comments = true;
+        let mut want_reviews = true;
+        let mut want_participants = true;
+
+        loop {
+            let vars = get_pull_request_info::Variables {
+                owner: repo_owner.clone(),
+                repo: repo_name.clone(),
+                prid: pr_number.clone(),
+                comments_after: comments_after.clone(),
+                reviews_after: reviews_after.clone(),
+                participants_after: participants_after.clone(),
+            };
+            let req_body = GetPullRequestInfo::build_query(vars);
+            let res = match self
+                .execute_partial::<_, get_pull_request_info::ResponseData>(
+                    req_body.operation_name,
+                    &req_body,
+                )
+                .await
+            {
+                Ok(res) => res,
+                Err(err) => {
+                    return Err(err);
+                }
+            };
+            let response_data = res.data;
+            page += 1;
+
+            let pr = match response_data
+                .repository
+                .as_ref()
+                .and_then(|r| r.pull_request.as_ref())
+            {
+                None => {
+                    // the API tells us why the repository or pull request is
+                    // missing, e.g. if we no longer have access to it.
+                    if !res.errors.is_empty() {
+                        return Err(map_gql_errors(
+                            "GetPullRequestInfo",
+                            res.errors,
+                        ));
+                    }
+                    // otherwise let the caller deal with the missing
+                    // repository or pull request; nothing more to paginate.
+                    if merged.is_none() {
+                        merged = Some(response_data);
+                    }
+                    break;
+                }
+                Some(v) => v,
+            };
+
+            // figure out which connections have further pages.
+            if want_comments {
+                let info = &pr.comments.page_info;
+                want_comments = info.has_next_page && info.end_cursor.is_some();
+                comments_after = info.end_cursor.clone();
+            }
+            if want_reviews {
+                want_reviews = false;
+                if let Some(r) = &pr.reviews {
+                    let info = &r.page_info;
+                    want_reviews =
+                        info.has_next_page && info.end_cursor.is_some();
+                    reviews_after = info.end_cursor.clone();
+                }
+            }
+            if want_participants {
+                let info = &pr.participants.page_info;
+                want_participants =
+                    info.has_next_page && info.end_cursor.is_some();
+                participants_after = info.end_cursor.clone();
+            }
+
+            merged = match merged {
+                None => Some(response_data),
+                Some(mut res) => {
+                    merge_pull_request_info_page(&mut res, response_data);
+                    Some(res)
+                }
+            };
+
+            if !want_comments && !want_reviews && !want_participants {
+                break;
+            }
+            if page >= self.max_pages {
+                warn!(
+                    "Pull request info truncated at {} pages for {}/{}#{}",
+                    page, repo_owner, repo_name, pr_number
+                );
+                break;
+            }
+        }
+
+        match merged {
+            Some(res) => Ok(res),
+            None => Err(GHDError::unexpected("no pull request info obtained")),
+        }
+    }
+
+    /// Obtain a given Issue's information.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_owner` - String containing the Issue's repository owner.
+    /// * `repo_name` - String containing the Issue's repository name.
+    /// * `issue_number` - The Issue's number.
+    ///
+    async fn get_issue_info(
+        self: &Self,
+        repo_owner: &String,
+        repo_name: &String,
+        issue_number: &i64,
+    ) -> Result<get_issue_info::ResponseData, GHDError> {
+        let vars = get_issue_info::Variables {
             owner: repo_owner.clone(),
             repo: repo_name.clone(),
-            prid: pr_number.clone(),
+            number: issue_number.clone(),
         };
-        let response_data: get_pull_request_info::ResponseData = match self
-            .execute::<GetPullRequestInfo, get_pull_request_info::ResponseData>(
-                vars,
-            )
+        self.execute::<GetIssueInfo, get_issue_info::ResponseData>(vars)
             .await
-        {
-            Ok(res) => res,
-            Err(GHDError::UnknownError) => {
-                panic!("error: unknown error");
+    }
+}
+
+/// Decode a GraphQL response body, accepting partial data. The GraphQL
+/// budget is updated from the response's `rateLimit` object, if any, and
+/// requests are paused if the API reports us as being rate limited. Errors
+/// returned along with data are logged and handed to the caller; if no data
+/// is returned, the errors are mapped into a `GHDError`.
+///
+/// # Arguments
+///
+/// * `operation_name` - The query's operation name, used as context.
+/// * `res_body` - The response's body.
+/// * `ratelimit` - The rate limit tracker to update.
+/// * `server_time` - The time reported by Github in the response, if any.
+/// * `debug` - Whether to print the decoded response.
+///
+fn decode_partial<M>(
+    operation_name: &str,
+    res_body: &str,
+    ratelimit: &RateLimitTracker,
+    server_time: Option<chrono::DateTime<chrono::Utc>>,
+    debug: bool,
+) -> Result<GQLPartialData<M>, GHDError>
+where
+    M: for<'de> serde::Deserialize<'de> + core::fmt::Debug,
+{
+    if let Ok(res_limit) =
+        serde_json::from_str::<GQLResData<GQLRateLimitData>>(&res_body)
+    {
+        if let Some(l) = res_limit.data.and_then(|d| d.rate_limit) {
+            ratelimit.update_graphql(l.cost, l.limit, l.remaining, &l.reset_at);
+        }
+    }
+
+    let res_data: GQLResData<M> = match serde_json::from_str(&res_body) {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::decode("decoding GraphQL response", err));
+        }
+    };
+
+    if debug {
+        println!("res data: {:?}", res_data);
+    }
+
+    let errors = res_data.errors.unwrap_or_default();
+    if errors
+        .iter()
+        .any(|e| e.error_type.as_deref() == Some("RATE_LIMITED"))
+    {
+        ratelimit.pause_graphql();
+        return Err(GHDError::RateLimitedError);
+    }
+
+    match res_data.data {
+        Some(data) => {
+            for e in &errors {
+                warn!(
+                    "{}: GraphQL error with partial data: {}",
+                    operation_name, e.message
+                );
             }
-            Err(err) => {
-                return Err(err);
+            Ok(GQLPartialData {
+                data,
+                errors,
+                server_time,
+            })
+        }
+        None => Err(map_gql_errors(operation_name, errors)),
+    }
+}
+
+/// Map the `errors` returned by the GraphQL API into a `GHDError`. Known error
+/// types are mapped into their own variants, taking into account the path of
+/// the failing field; everything else becomes a `GHDError::GraphQLError`.
+///
+/// # Arguments
+///
+/// * `context` - The operation that failed.
+/// * `errors` - The errors returned by the API.
+///
+fn map_gql_errors(context: &str, errors: Vec<GQLError>) -> GHDError {
+    let path_has = |e: &GQLError, field: &str| match &e.path {
+        None => false,
+        Some(path) => path.iter().any(|p| p.as_str() == Some(field)),
+    };
+
+    for e in &errors {
+        match e.error_type.as_deref() {
+            Some("RATE_LIMITED") => return GHDError::RateLimitedError,
+            Some("NOT_FOUND") => {
+                if path_has(e, "pullRequest") {
+                    return GHDError::PullRequestNotFoundError;
+                } else if path_has(e, "issue") {
+                    return GHDError::IssueNotFoundError;
+                } else if path_has(e, "repository") {
+                    return GHDError::RepositoryNotFoundError;
+                } else if path_has(e, "organization") {
+                    return GHDError::TeamNotFoundError;
+                }
+                return GHDError::NotFoundError;
             }
-        };
+            Some("FORBIDDEN") => {
+                return GHDError::ForbiddenError {
+                    context: format!("{}: {}", context, e.message),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    GHDError::GraphQLError {
+        context: String::from(context),
+        errors,
+    }
+}
+
+/// Merge a subsequent page of a `GetPullRequestInfo` query result into the
+/// first page's result. We only take nodes from connections that had further
+/// pages as of the previously merged page; other connections are ignored.
+///
+fn merge_pull_request_info_page(
+    into: &mut get_pull_request_info::ResponseData,
+    from: get_pull_request_info::ResponseData,
+) {
+    let dst = match into
+        .repository
+        .as_mut()
+        .and_then(|r| r.pull_request.as_mut())
+    {
+        None => return,
+        Some(v) => v,
+    };
+    let src = match from.repository.and_then(|r| r.pull_request) {
+        None => return,
+        Some(v) => v,
+    };
+
+    if dst.comments.page_info.has_next_page {
+        if let Some(nodes) = src.comments.nodes {
+            dst.comments
+                .nodes
+                .get_or_insert_with(Vec::new)
+                .extend(nodes);
+        }
+        dst.comments.page_info = src.comments.page_info;
+    }
 
-        Ok(response_data)
+    if let (Some(d), Some(s)) = (dst.reviews.as_mut(), src.reviews) {
+        if d.page_info.has_next_page {
+            if let Some(nodes) = s.nodes {
+                d.nodes.get_or_insert_with(Vec::new).extend(nodes);
+            }
+            d.page_info = s.page_info;
+        }
+    }
+
+    if dst.participants.page_info.has_next_page {
+        if let Some(nodes) = src.participants.nodes {
+            dst.participants
+                .nodes
+                .get_or_insert_with(Vec::new)
+                .extend(nodes);
+        }
+        dst.participants.page_info = src.participants.page_info;
     }
 }
 
@@ -266,13 +1016,164 @@ impl GithubGQLRequest {
 /// all issues with which the user may be involved (authored, mentioned, etc.).
 ///
 pub async fn get_user_open_issues(
-    token: &String,
+    endpoint: &GithubEndpoint,
     login: &String,
 ) -> Result<UserUpdate, GHDError> {
-    let res = match GithubGQLRequest::new(&token)
-        .get_user_open_issues(&login)
-        .await
-    {
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req.get_user_open_issues(&login).await {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+
+    process_user_search_results(&res)
+}
+
+/// Obtain Pull Request and Issue updates for provided `login` since the
+/// provided date `since`.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `login` - String containing the user to obtain an update for.
+/// * `since` - Date since which updates should be looked for.
+///
+pub async fn get_user_updates(
+    endpoint: &GithubEndpoint,
+    login: &String,
+    since: &chrono::DateTime<chrono::Utc>,
+) -> Result<UserUpdate, GHDError> {
+    let since_str = sync::search_since(&since).to_rfc3339();
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req.get_user_update(&login, &since_str).await {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+
+    process_user_search_results(&res)
+}
+
+/// Obtain Pull Request and Issue updates for several users at once, running
+/// their searches in a single request. At most `MAX_SEARCH_BATCH_SIZE` users
+/// may be provided. Results are returned in the same order as `users`; an
+/// error affecting the whole request is returned for each of the users.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `users` - Users to obtain updates for, and the dates since which updates
+///   should be looked for.
+///
+pub async fn get_users_updates(
+    endpoint: &GithubEndpoint,
+    users: &[(String, chrono::DateTime<chrono::Utc>)],
+) -> Vec<Result<UserUpdate, GHDError>> {
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return users.iter().map(|_| Err(err.clone())).collect(),
+    };
+
+    let queries: Vec<String> = users
+        .iter()
+        .map(|(login, since)| {
+            user_update_query(&login, &sync::search_since(&since).to_rfc3339())
+        })
+        .collect();
+    match req.get_batch_search_issues_data(&queries).await {
+        Ok(res) => res
+            .into_iter()
+            .map(|r| match r {
+                Ok(v) => process_user_search_results(&v),
+                Err(err) => Err(err),
+            })
+            .collect(),
+        Err(err) => users.iter().map(|_| Err(err.clone())).collect(),
+    }
+}
+
+/// Obtain Pull Request and Issue updates for the provided repository. If
+/// `since` is not provided, obtain all open Pull Requests and Issues instead.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `repo` - The repository to obtain updates for.
+/// * `since` - Date since which updates should be looked for, if any.
+///
+pub async fn get_repo_updates(
+    endpoint: &GithubEndpoint,
+    repo: &TrackedRepo,
+    since: &Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<UserUpdate, GHDError> {
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match since {
+        None => req.get_repo_open_issues(&repo.full_name()).await,
+        Some(dt) => {
+            req.get_repo_update(
+                &repo.full_name(),
+                &sync::search_since(&dt).to_rfc3339(),
+            )
+            .await
+        }
+    };
+    match res {
+        Ok(v) => process_user_search_results(&v),
+        Err(err) => Err(err),
+    }
+}
+
+/// Obtain Pull Request and Issue updates for the provided search query. If
+/// `since` is not provided, obtain all matching Pull Requests and Issues.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `query` - String containing a Github search query.
+/// * `since` - Date since which updates should be looked for, if any.
+///
+pub async fn get_query_updates(
+    endpoint: &GithubEndpoint,
+    query: &String,
+    since: &Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<UserUpdate, GHDError> {
+    let since_str = since.map(|dt| sync::search_since(&dt).to_rfc3339());
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req.get_query_update(&query, &since_str).await {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+
+    process_user_search_results(&res)
+}
+
+/// Obtain all open Pull Requests for which a review has been requested from
+/// the provided `team`.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `team` - The team to obtain review requests for.
+///
+pub async fn get_team_review_requests(
+    endpoint: &GithubEndpoint,
+    team: &GithubTeam,
+) -> Result<UserUpdate, GHDError> {
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req.get_team_review_requests(&team.combined_slug()).await {
         Ok(v) => v,
         Err(err) => return Err(err),
     };
@@ -280,30 +1181,65 @@ pub async fn get_user_open_issues(
     process_user_search_results(&res)
 }
 
-/// Obtain Pull Request and Issue updates for provided `login` since the
-/// provided date `since`.
+/// Obtain a team's information, and its members, from Github. Returns
+/// `GHDError::TeamNotFoundError` if the team does not exist or we can't see
+/// it.
 ///
 /// # Arguments
 ///
-/// * `token` - String containing the Github API Token.
-/// * `login` - String containing the user to obtain an update for.
-/// * `since` - Date since which updates should be looked for.
+/// * `endpoint` - The Github API endpoint to use.
+/// * `org` - String containing the team's organization.
+/// * `slug` - String containing the team's slug.
 ///
-pub async fn get_user_updates(
-    token: &String,
-    login: &String,
-    since: &chrono::DateTime<chrono::Utc>,
-) -> Result<UserUpdate, GHDError> {
-    let since_str = since.to_rfc3339();
-    let res = match GithubGQLRequest::new(&token)
-        .get_user_update(&login, &since_str)
-        .await
-    {
+pub async fn get_team_info(
+    endpoint: &GithubEndpoint,
+    org: &String,
+    slug: &String,
+) -> Result<GithubTeamInfo, GHDError> {
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req.get_team_info(&org, &slug).await {
         Ok(v) => v,
         Err(err) => return Err(err),
     };
 
-    process_user_search_results(&res)
+    let team = match res.organization.and_then(|o| o.team) {
+        None => return Err(GHDError::TeamNotFoundError),
+        Some(v) => v,
+    };
+    let id = match get_id(team.database_id) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+
+    let members = team
+        .members
+        .nodes
+        .unwrap_or_default()
+        .into_iter()
+        .flatten()
+        .filter_map(|m| match m.database_id {
+            None => None,
+            Some(id) => Some(GithubUser {
+                id,
+                login: m.login,
+                name: m.name.unwrap_or(String::from("unknown")),
+                avatar_url: m.avatar_url,
+            }),
+        })
+        .collect();
+
+    Ok(GithubTeamInfo {
+        team: GithubTeam {
+            id,
+            org: org.clone(),
+            slug: team.slug,
+            name: team.name,
+        },
+        members,
+    })
 }
 
 /// Process the resulting data from the GraphQL call into something that the
@@ -311,11 +1247,13 @@ pub async fn get_user_updates(
 /// both issues and pull requests resulting from the original query.
 ///
 fn process_user_search_results(
-    res: &search_issues::ResponseData,
+    res: &SearchResults,
 ) -> Result<UserUpdate, GHDError> {
-    let nodes = match &res.search.nodes {
+    let empty = vec![];
+    let nodes = match &res.data.search.nodes {
         None => {
-            panic!("Unexpected null nodes for user update!");
+            warn!("Unexpected null nodes for user update!");
+            &empty
         }
         Some(v) => v,
     };
@@ -328,18 +1266,26 @@ fn process_user_search_results(
             None => {
                 continue;
             }
-            Some(SearchIssuesSearchNodes::PullRequest(entry)) => {
+            Some(SearchIssuesResultsNodes::PullRequest(entry)) => {
                 get_issue_from_pull_request(&entry)
             }
-            Some(SearchIssuesSearchNodes::Issue(entry)) => {
+            Some(SearchIssuesResultsNodes::Issue(entry)) => {
                 get_issue_from_issue(&entry)
             }
             Some(_) => {
-                panic!("unexpected node type!");
+                warn!("unexpected search node type, skipping!");
+                continue;
+            }
+        };
+        let issue = match issue {
+            Ok(v) => v,
+            Err(err) => {
+                warn!("unable to process search node, skipping: {}", err);
+                continue;
             }
         };
 
-        if let Some(SearchIssuesSearchNodes::PullRequest(entry)) = &n {
+        if let Some(SearchIssuesResultsNodes::PullRequest(entry)) = &n {
             pr_lst.push(PullRequest {
                 issue,
                 is_draft: entry.is_draft,
@@ -357,38 +1303,86 @@ fn process_user_search_results(
                     Some(PullRequestReviewDecision::Other(v)) => v.clone(),
                 },
                 merged_at: entry.merged_at,
+                checks_state: entry
+                    .commits
+                    .nodes
+                    .iter()
+                    .flatten()
+                    .flatten()
+                    .last()
+                    .and_then(|c| c.commit.status_check_rollup.as_ref())
+                    .map(|r| gql_enum_to_string(&r.state)),
+                mergeable: gql_enum_to_string(&entry.mergeable),
+                merge_state_status: gql_enum_to_string(
+                    &entry.merge_state_status,
+                ),
+                base_ref_name: entry.base_ref_name.clone(),
+                head_ref_name: entry.head_ref_name.clone(),
+                merge_queue: entry.merge_queue_entry.as_ref().map(|q| {
+                    MergeQueueEntry {
+                        position: q.position,
+                        state: gql_enum_to_string(&q.state),
+                        enqueued_at: q.enqueued_at,
+                        enqueued_at_ts: q.enqueued_at.timestamp(),
+                        estimated_time_to_merge: q.estimated_time_to_merge,
+                    }
+                }),
+                review_requests: get_requested_reviewers(&entry),
+                team_review_requests: get_requested_teams(&entry),
+                reviewers: get_reviewers(&entry),
             });
-        } else if let Some(SearchIssuesSearchNodes::Issue(_)) = &n {
-            issue_lst.push(issue);
         } else {
-            panic!("should not have gotten here!");
+            issue_lst.push(issue);
         }
     }
 
+    let max_updated_at = pr_lst
+        .iter()
+        .map(|pr| pr.issue.updated_at)
+        .chain(issue_lst.iter().map(|issue| issue.updated_at))
+        .max();
+    // inaccessible results are null nodes, but still count as results.
+    let obtained = res.data.search.nodes.as_ref().map_or(0, |n| n.len());
+
     Ok(UserUpdate {
-        when: chrono::Utc::now(),
+        cursor: sync::next_cursor(
+            &res.server_time,
+            &max_updated_at,
+            &chrono::Utc::now(),
+        ),
         prs: pr_lst,
         issues: issue_lst,
+        truncated: sync::is_truncated(
+            res.data.search.page_info.has_next_page,
+            res.data.search.issue_count,
+            obtained,
+        ),
     })
 }
 
 /// Obtain an `Issue` from the provided GraphQL issue node.
 ///
-fn get_issue_from_issue(node: &SearchIssuesSearchNodesOnIssue) -> Issue {
+fn get_issue_from_issue(
+    node: &SearchIssuesResultsNodesOnIssue,
+) -> Result<Issue, GHDError> {
+    type Author = SearchIssuesResultsNodesOnIssueAuthor;
+
     let (username, userid) = match &node.author {
-        None => {
-            panic!("author not defined for pull request!");
-        }
-        Some(SearchIssuesSearchNodesOnIssueAuthor::User(user)) => {
-            get_username_and_id(user)
+        None => get_ghost_username_and_id(),
+        Some(Author::User(user)) => get_username_and_id(user),
+        Some(Author::Bot(bot)) => {
+            (bot.login.clone(), bot.database_id.unwrap_or(-1))
         }
-        Some(_) => {
-            panic!("unexpected author user type!");
+        Some(Author::Mannequin(m)) => {
+            (m.login.clone(), m.database_id.unwrap_or(-1))
         }
+        Some(_) => get_ghost_username_and_id(),
     };
+    let last_comment =
+        node.last_comment.nodes.iter().flatten().flatten().last();
 
-    Issue {
-        id: get_id(node.database_id),
+    Ok(Issue {
+        id: get_id(node.database_id)?,
         title: node.title.clone(),
         number: node.number,
         author: username.clone(),
@@ -406,28 +1400,50 @@ fn get_issue_from_issue(node: &SearchIssuesSearchNodesOnIssue) -> Issue {
         closed_at: node.closed_at,
         is_pull_request: false,
         last_viewed: None,
-    }
+        assignees: node
+            .assignees
+            .nodes
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|a| a.login.clone())
+            .collect(),
+        total_comments: node.last_comment.total_count,
+        last_commenter: last_comment
+            .and_then(|c| c.author.as_ref())
+            .map(|a| a.login.clone()),
+        body_mentions: extract_mentions(&node.body_text),
+        last_comment_mentions: match last_comment {
+            None => vec![],
+            Some(c) => extract_mentions(&c.body_text),
+        },
+        commenters: get_issue_commenters(&node),
+    })
 }
 
 /// Obtain the `Issue` associated with the provided GraphQL pull request node.
 ///
 fn get_issue_from_pull_request(
-    node: &SearchIssuesSearchNodesOnPullRequest,
-) -> Issue {
+    node: &SearchIssuesResultsNodesOnPullRequest,
+) -> Result<Issue, GHDError> {
+    type Author = SearchIssuesResultsNodesOnPullRequestAuthor;
+
     let (username, userid) = match &node.author {
-        None => {
-            panic!("author not defined for pull request!");
-        }
-        Some(SearchIssuesSearchNodesOnPullRequestAuthor::User(user)) => {
-            get_username_and_id(user)
+        None => get_ghost_username_and_id(),
+        Some(Author::User(user)) => get_username_and_id(user),
+        Some(Author::Bot(bot)) => {
+            (bot.login.clone(), bot.database_id.unwrap_or(-1))
         }
-        Some(_) => {
-            panic!("unexpected author user type!");
+        Some(Author::Mannequin(m)) => {
+            (m.login.clone(), m.database_id.unwrap_or(-1))
         }
+        Some(_) => get_ghost_username_and_id(),
     };
+    let last_comment =
+        node.last_comment.nodes.iter().flatten().flatten().last();
 
-    Issue {
-        id: get_id(node.database_id),
+    Ok(Issue {
+        id: get_id(node.database_id)?,
         title: node.title.clone(),
         number: node.number,
         author: username.clone(),
@@ -446,13 +1462,31 @@ fn get_issue_from_pull_request(
         closed_at: node.closed_at,
         is_pull_request: true,
         last_viewed: None,
-    }
+        assignees: node
+            .assignees
+            .nodes
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|a| a.login.clone())
+            .collect(),
+        total_comments: node.last_comment.total_count,
+        last_commenter: last_comment
+            .and_then(|c| c.author.as_ref())
+            .map(|a| a.login.clone()),
+        body_mentions: extract_mentions(&node.body_text),
+        last_comment_mentions: match last_comment {
+            None => vec![],
+            Some(c) => extract_mentions(&c.body_text),
+        },
+        commenters: get_pull_request_commenters(&node),
+    })
 }
 
 /// Obtain a given Pull Request's information.
 ///
 pub async fn get_pull_request_info(
-    token: &String,
+    endpoint: &GithubEndpoint,
     repo_owner: &String,
     repo_name: &String,
     pr_number: &i64,
@@ -463,8 +1497,13 @@ pub async fn get_pull_request_info(
     type PRMilestoneState = get_pull_request_info::MilestoneState;
     type ReviewAuthor = get_pull_request_info::GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthor;
     type ReviewState = get_pull_request_info::PullRequestReviewState;
+    type CheckContext = get_pull_request_info::GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodes;
 
-    let res = match GithubGQLRequest::new(&token)
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req
         .get_pull_request_info(&repo_owner, &repo_name, &pr_number)
         .await
     {
@@ -535,6 +1574,40 @@ pub async fn get_pull_request_info(
         }
     }
 
+    // CI checks for the Pull Request's head commit.
+    let rollup = pr
+        .commits
+        .nodes
+        .iter()
+        .flatten()
+        .flatten()
+        .last()
+        .and_then(|c| c.commit.status_check_rollup.as_ref());
+    let checks_state = rollup.map(|r| gql_enum_to_string(&r.state));
+    let mut checks: Vec<CheckInfo> = vec![];
+    if let Some(lst) = rollup.and_then(|r| r.contexts.nodes.as_ref()) {
+        for entry in lst {
+            checks.push(match &entry {
+                None => continue,
+                Some(CheckContext::CheckRun(run)) => CheckInfo {
+                    name: run.name.clone(),
+                    state: match &run.conclusion {
+                        None => gql_enum_to_string(&run.status),
+                        Some(v) => gql_enum_to_string(v),
+                    },
+                    description: None,
+                    url: run.details_url.clone(),
+                },
+                Some(CheckContext::StatusContext(status)) => CheckInfo {
+                    name: status.context.clone(),
+                    state: gql_enum_to_string(&status.state),
+                    description: status.description.clone(),
+                    url: status.target_url.clone(),
+                },
+            });
+        }
+    }
+
     let mut reviews: Vec<UserReview> = vec![];
     if let Some(r) = &pr.reviews {
         if let Some(lst) = &r.nodes {
@@ -611,15 +1684,429 @@ pub async fn get_pull_request_info(
         },
         participants,
         reviews,
+        checks_state,
+        checks,
+        mergeable: gql_enum_to_string(&pr.mergeable),
+        merge_state_status: gql_enum_to_string(&pr.merge_state_status),
+        base_ref_name: pr.base_ref_name,
+        head_ref_name: pr.head_ref_name,
+        merge_queue: pr.merge_queue_entry.map(|q| MergeQueueEntry {
+            position: q.position,
+            state: gql_enum_to_string(&q.state),
+            enqueued_at: q.enqueued_at,
+            enqueued_at_ts: q.enqueued_at.timestamp(),
+            estimated_time_to_merge: q.estimated_time_to_merge,
+        }),
+    })
+}
+
+/// Obtain a given Issue's information.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `repo_owner` - String containing the Issue's repository owner.
+/// * `repo_name` - String containing the Issue's repository name.
+/// * `issue_number` - The Issue's number.
+///
+pub async fn get_issue_info(
+    endpoint: &GithubEndpoint,
+    repo_owner: &String,
+    repo_name: &String,
+    issue_number: &i64,
+) -> Result<IssueInfo, GHDError> {
+    // helper types
+    type InfoAuthor = GetIssueInfoRepositoryIssueAuthor;
+    type IssueInfoState = get_issue_info::IssueState;
+    type IssueMilestoneState = get_issue_info::MilestoneState;
+    type ReactionKind = get_issue_info::ReactionContent;
+    type TimelineItem = GetIssueInfoRepositoryIssueTimelineItemsNodes;
+    type ConnectedSubject = get_issue_info::GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEventSubject;
+    type CrossRefSource = get_issue_info::GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEventSource;
+
+    let req = match GithubGQLRequest::new(&endpoint) {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+    let res = match req
+        .get_issue_info(&repo_owner, &repo_name, &issue_number)
+        .await
+    {
+        Ok(v) => v,
+        Err(err) => return Err(err),
+    };
+
+    let repo = match res.repository {
+        None => return Err(GHDError::RepositoryNotFoundError),
+        Some(v) => v,
+    };
+    let issue = match repo.issue {
+        None => return Err(GHDError::IssueNotFoundError),
+        Some(v) => v,
+    };
+
+    let unknown_user = GithubUser {
+        id: -1,
+        login: String::from("unknown"),
+        name: String::from("unknown"),
+        avatar_url: String::new(),
+    };
+
+    let author: GithubUser = match issue.author {
+        None => unknown_user.clone(),
+        Some(v) => match v {
+            InfoAuthor::User(user) => GithubUser {
+                id: user.database_id.unwrap_or(-1),
+                login: user.login,
+                name: user.name.unwrap_or(String::from("unknown")),
+                avatar_url: user.avatar_url,
+            },
+            _ => unknown_user.clone(),
+        },
+    };
+
+    let mut labels: Vec<Label> = vec![];
+    if let Some(l) = &issue.labels {
+        if let Some(lst) = &l.nodes {
+            for entry in lst {
+                if let Some(label) = &entry {
+                    labels.push(Label {
+                        name: label.name.clone(),
+                        color: label.color.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut assignees: Vec<GithubUser> = vec![];
+    if let Some(lst) = &issue.assignees.nodes {
+        for entry in lst {
+            if let Some(user) = &entry {
+                assignees.push(GithubUser {
+                    id: user.database_id.unwrap_or(-1),
+                    login: user.login.clone(),
+                    name: match &user.name {
+                        None => String::from("unknown"),
+                        Some(v) => v.clone(),
+                    },
+                    avatar_url: user.avatar_url.clone(),
+                });
+            }
+        }
+    }
+
+    let mut participants: Vec<GithubUser> = vec![];
+    if let Some(lst) = &issue.participants.nodes {
+        for entry in lst {
+            if let Some(user) = &entry {
+                participants.push(GithubUser {
+                    id: user.database_id.unwrap_or(-1),
+                    login: user.login.clone(),
+                    name: match &user.name {
+                        None => String::from("unknown"),
+                        Some(v) => v.clone(),
+                    },
+                    avatar_url: user.avatar_url.clone(),
+                });
+            }
+        }
+    }
+
+    // a Pull Request may show up more than once in the timeline; e.g., if it
+    // both references the issue and has been connected to it.
+    let mut linked_prs: Vec<LinkedPullRequest> = vec![];
+    if let Some(lst) = &issue.timeline_items.nodes {
+        for entry in lst {
+            let (pr, will_close) = match &entry {
+                Some(TimelineItem::ConnectedEvent(ev)) => match &ev.subject {
+                    ConnectedSubject::PullRequest(pr) => (pr, true),
+                    _ => continue,
+                },
+                Some(TimelineItem::CrossReferencedEvent(ev)) => {
+                    match &ev.source {
+                        CrossRefSource::PullRequest(pr) => {
+                            (pr, ev.will_close_target)
+                        }
+                        _ => continue,
+                    }
+                }
+                _ => continue,
+            };
+            match linked_prs.iter_mut().find(|e| e.url == pr.url) {
+                Some(existing) => {
+                    existing.will_close = existing.will_close || will_close;
+                }
+                None => {
+                    linked_prs.push(get_linked_pull_request(pr, will_close))
+                }
+            };
+        }
+    }
+
+    let mut reactions: Vec<Reaction> = vec![];
+    if let Some(lst) = &issue.reaction_groups {
+        for group in lst {
+            if group.reactors.total_count == 0 {
+                continue;
+            }
+            reactions.push(Reaction {
+                content: match &group.content {
+                    ReactionKind::THUMBS_UP => String::from("thumbs_up"),
+                    ReactionKind::THUMBS_DOWN => String::from("thumbs_down"),
+                    ReactionKind::LAUGH => String::from("laugh"),
+                    ReactionKind::HOORAY => String::from("hooray"),
+                    ReactionKind::CONFUSED => String::from("confused"),
+                    ReactionKind::HEART => String::from("heart"),
+                    ReactionKind::ROCKET => String::from("rocket"),
+                    ReactionKind::EYES => String::from("eyes"),
+                    ReactionKind::Other(v) => v.clone(),
+                },
+                count: group.reactors.total_count,
+            });
+        }
+    }
+
+    Ok(IssueInfo {
+        number: issue.number,
+        title: issue.title,
+        body_html: issue.body_html,
+        author,
+        repo_owner: issue.repository.owner.login,
+        repo_name: issue.repository.name,
+        url: issue.url,
+        state: match &issue.state {
+            IssueInfoState::OPEN => String::from("open"),
+            IssueInfoState::CLOSED => String::from("closed"),
+            IssueInfoState::Other(v) => v.clone(),
+        },
+        milestone: match &issue.milestone {
+            None => None,
+            Some(m) => Some(Milestone {
+                title: m.title.clone(),
+                state: match &m.state {
+                    IssueMilestoneState::OPEN => String::from("open"),
+                    IssueMilestoneState::CLOSED => String::from("closed"),
+                    IssueMilestoneState::Other(v) => v.clone(),
+                },
+                due_on: m.due_on,
+                due_on_ts: m.due_on.map(|d| d.timestamp()),
+            }),
+        },
+        labels,
+        total_comments: issue.comments.total_count,
+        assignees,
+        participants,
+        linked_prs,
+        reactions,
     })
 }
 
+/// Obtain a `LinkedPullRequest` from its GraphQL fragment.
+///
+fn get_linked_pull_request(
+    pr: &LinkedPullRequestFragment,
+    will_close: bool,
+) -> LinkedPullRequest {
+    type LinkedPRState = get_issue_info::PullRequestState;
+
+    LinkedPullRequest {
+        number: pr.number,
+        title: pr.title.clone(),
+        url: pr.url.clone(),
+        state: match &pr.state {
+            LinkedPRState::OPEN => String::from("open"),
+            LinkedPRState::CLOSED => String::from("closed"),
+            LinkedPRState::MERGED => String::from("merged"),
+            LinkedPRState::Other(v) => v.clone(),
+        },
+        repo_owner: pr.repository.owner.login.clone(),
+        repo_name: pr.repository.name.clone(),
+        will_close,
+    }
+}
+
+/// Obtain the logins of the users whose review has been requested for the
+/// provided GraphQL pull request node. Team review requests are obtained by
+/// `get_requested_teams()` instead.
+///
+fn get_requested_reviewers(
+    node: &SearchIssuesResultsNodesOnPullRequest,
+) -> Vec<String> {
+    type Reviewer = SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer;
+
+    node.review_requests
+        .iter()
+        .flat_map(|r| r.nodes.iter().flatten().flatten())
+        .filter_map(|n| match &n.requested_reviewer {
+            Some(Reviewer::User(user)) => Some(user.login.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Obtain the combined slugs (e.g., `org/team`) of the teams whose review has
+/// been requested for the provided GraphQL pull request node.
+///
+fn get_requested_teams(
+    node: &SearchIssuesResultsNodesOnPullRequest,
+) -> Vec<String> {
+    type Reviewer = SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer;
+
+    node.review_requests
+        .iter()
+        .flat_map(|r| r.nodes.iter().flatten().flatten())
+        .filter_map(|n| match &n.requested_reviewer {
+            Some(Reviewer::Team(team)) => Some(team.combined_slug.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Obtain the logins of the users who have reviewed the provided GraphQL pull
+/// request node.
+///
+fn get_reviewers(node: &SearchIssuesResultsNodesOnPullRequest) -> Vec<String> {
+    let mut res: Vec<String> = node
+        .latest_reviews
+        .iter()
+        .flat_map(|r| r.nodes.iter().flatten().flatten())
+        .filter_map(|n| n.author.as_ref().map(|a| a.login.clone()))
+        .collect();
+    res.sort();
+    res.dedup();
+    res
+}
+
+/// Obtain the logins of the users who have commented on the provided GraphQL
+/// issue node. Only the first page of comments is considered.
+///
+fn get_issue_commenters(node: &SearchIssuesResultsNodesOnIssue) -> Vec<String> {
+    type Author = SearchIssuesResultsNodesOnIssueCommentsNodesAuthor;
+
+    let mut res: Vec<String> = node
+        .comments
+        .nodes
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|n| match &n.author {
+            Some(Author::User(user)) => Some(user.login.clone()),
+            _ => None,
+        })
+        .collect();
+    res.sort();
+    res.dedup();
+    res
+}
+
+/// Obtain the logins of the users who have commented on the provided GraphQL
+/// pull request node. Only the first page of comments is considered.
+///
+fn get_pull_request_commenters(
+    node: &SearchIssuesResultsNodesOnPullRequest,
+) -> Vec<String> {
+    let mut res: Vec<String> = node
+        .commenters
+        .nodes
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|n| n.author.as_ref().map(|a| a.login.clone()))
+        .collect();
+    res.sort();
+    res.dedup();
+    res
+}
+
+/// Obtain the logins mentioned in the provided text; e.g., `@foo` yields
+/// `foo`. Mentions are only considered at the start of the text or when
+/// following a character that can't be part of a login or an email address.
+///
+fn extract_mentions(text: &str) -> Vec<String> {
+    let mut res: Vec<String> = vec![];
+    let mut prev: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        let at_boundary = match prev {
+            None => true,
+            Some(p) => !(p.is_ascii_alphanumeric() || p == '_' || p == '.'),
+        };
+        prev = Some(c);
+        if c != '@' || !at_boundary {
+            continue;
+        }
+
+        let start = idx + 1;
+        let mut end = start;
+        while let Some((i, n)) = chars.peek() {
+            if !(n.is_ascii_alphanumeric() || *n == '-') {
+                break;
+            }
+            end = i + n.len_utf8();
+            prev = Some(*n);
+            chars.next();
+        }
+
+        let login = text[start..end].trim_end_matches('-');
+        if !login.is_empty() && !res.iter().any(|l| l == login) {
+            res.push(login.to_string());
+        }
+    }
+    res
+}
+
+/// Obtain a lowercase string for a GraphQL enum value; e.g., `SUCCESS` becomes
+/// `success`.
+///
+fn gql_enum_to_string<E: serde::Serialize>(value: &E) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(v)) => v.to_lowercase(),
+        _ => String::from("unknown"),
+    }
+}
+
+/// Obtain the time reported by Github in the response's `Date` header, if
+/// any.
+///
+fn get_server_time(
+    headers: &reqwest::header::HeaderMap,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    let value = headers.get(reqwest::header::DATE)?.to_str().ok()?;
+    match chrono::DateTime::parse_from_rfc2822(value) {
+        Ok(dt) => Some(dt.with_timezone(&chrono::Utc)),
+        Err(err) => {
+            warn!("Unable to parse server date '{}': {}", value, err);
+            None
+        }
+    }
+}
+
+/// Obtain the search query used to look for updates for user `login` since
+/// `since`, a String complying with RFC 3339.
+///
+fn user_update_query(login: &String, since: &String) -> String {
+    format!("involves:{} updated:>{}", login, since)
+}
+
+/// Obtain the alias for the batched search at index `idx`.
+///
+fn batch_alias(idx: usize) -> String {
+    format!("u{}", idx)
+}
+
 /// Obtain a user `login` and `id` from a given GraphQL `User Fragment`.
 ///
 fn get_username_and_id(user: &UserFragment) -> (String, i64) {
-    let id = get_id(user.database_id);
+    (user.login.clone(), user.database_id.unwrap_or(-1))
+}
 
-    (user.login.clone(), id)
+/// Obtain the `login` and `id` of Github's "ghost" user, which stands in for
+/// users that have since deleted their accounts.
+///
+fn get_ghost_username_and_id() -> (String, i64) {
+    (String::from(GHOST_USER_LOGIN), GHOST_USER_ID)
 }
 
 /// Obtain an `id` from a provided optional ID. Typically this will be a helper
@@ -627,11 +2114,157 @@ fn get_username_and_id(user: &UserFragment) -> (String, i64) {
 /// else, and used solely when it's expected that the provided `Option<i64>` is
 /// not `None`.
 ///
-fn get_id(v: Option<i64>) -> i64 {
+fn get_id(v: Option<i64>) -> Result<i64, GHDError> {
     match v {
-        None => {
-            panic!("id not defined!");
+        None => Err(GHDError::unexpected("id not defined")),
+        Some(id) => Ok(id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_query_has_max_batch_size_searches() {
+        let query = batch_search_issues::QUERY;
+        for idx in 0..MAX_SEARCH_BATCH_SIZE {
+            let alias = format!("{}: search(", batch_alias(idx));
+            assert_eq!(query.matches(&alias).count(), 1);
+        }
+        let extra = format!("{}: search(", batch_alias(MAX_SEARCH_BATCH_SIZE));
+        assert!(!query.contains(&extra));
+    }
+
+    #[test]
+    fn batch_body_only_enables_provided_searches() {
+        let queries = vec![String::from("first"), String::from("second")];
+        let body = GQLBatchQueryBody::new(&queries).unwrap();
+        assert_eq!(body.operation_name, "BatchSearchIssues");
+        assert_eq!(body.variables["q1"], "second");
+        assert_eq!(body.variables["i1"], true);
+        assert!(!body.variables.contains_key("q2"));
+        assert!(!body.variables.contains_key("i2"));
+
+        let too_many = vec![String::from("q"); MAX_SEARCH_BATCH_SIZE + 1];
+        assert!(GQLBatchQueryBody::new(&too_many).is_err());
+    }
+
+    type Data = HashMap<String, serde_json::Value>;
+
+    fn decode(body: &str) -> (Result<GQLPartialData<Data>, GHDError>, bool) {
+        let tracker = RateLimitTracker::new();
+        let res = decode_partial::<Data>("Test", body, &tracker, None, false);
+        (res, tracker.paused_until().is_some())
+    }
+
+    fn not_found(path: &str) -> GHDError {
+        let body = format!(
+            r#"{{
+                "data": null,
+                "errors": [{{
+                    "type": "NOT_FOUND",
+                    "path": {},
+                    "message": "Could not resolve to a node."
+                }}]
+            }}"#,
+            path
+        );
+        match decode(&body).0 {
+            Err(err) => err,
+            Ok(_) => panic!("expected an error for path {}", path),
+        }
+    }
+
+    #[test]
+    fn not_found_is_mapped_by_path() {
+        assert!(matches!(
+            not_found(r#"["repository", "pullRequest"]"#),
+            GHDError::PullRequestNotFoundError
+        ));
+        assert!(matches!(
+            not_found(r#"["repository", "issue"]"#),
+            GHDError::IssueNotFoundError
+        ));
+        assert!(matches!(
+            not_found(r#"["repository"]"#),
+            GHDError::RepositoryNotFoundError
+        ));
+        assert!(matches!(
+            not_found(r#"["organization", "team"]"#),
+            GHDError::TeamNotFoundError
+        ));
+        assert!(matches!(not_found("null"), GHDError::NotFoundError));
+    }
+
+    #[test]
+    fn rate_limited_pauses_despite_data() {
+        let body = r#"{
+            "data": {"search": null},
+            "errors": [{
+                "type": "RATE_LIMITED",
+                "message": "API rate limit exceeded"
+            }]
+        }"#;
+        let (res, paused) = decode(body);
+        assert!(matches!(res, Err(GHDError::RateLimitedError)));
+        assert!(paused);
+    }
+
+    #[test]
+    fn partial_data_is_kept_with_errors() {
+        let body = r#"{
+            "data": {
+                "search": {"nodes": [{"id": 1}, null]},
+                "rateLimit": {
+                    "cost": 1,
+                    "limit": 5000,
+                    "remaining": 4999,
+                    "resetAt": "2100-01-01T00:00:00Z"
+                }
+            },
+            "errors": [{
+                "type": "FORBIDDEN",
+                "path": ["search", "nodes", 1],
+                "message": "Resource not accessible by integration"
+            }]
+        }"#;
+        let tracker = RateLimitTracker::new();
+        let res = decode_partial::<Data>("Test", body, &tracker, None, false)
+            .unwrap();
+        assert_eq!(res.errors.len(), 1);
+        assert_eq!(res.errors[0].error_type.as_deref(), Some("FORBIDDEN"));
+        assert_eq!(res.data["search"]["nodes"][0]["id"], 1);
+        assert!(res.data["search"]["nodes"][1].is_null());
+        assert_eq!(tracker.get().graphql.remaining, Some(4999));
+        assert!(tracker.paused_until().is_none());
+    }
+
+    #[test]
+    fn errors_without_data_are_mapped() {
+        let body = r#"{
+            "errors": [{
+                "type": "FORBIDDEN",
+                "message": "Resource not accessible by integration"
+            }]
+        }"#;
+        assert!(matches!(
+            decode(body).0,
+            Err(GHDError::ForbiddenError { .. })
+        ));
+
+        let body = r#"{"errors": [{"message": "Something went wrong"}]}"#;
+        match decode(body).0 {
+            Err(GHDError::GraphQLError { context, errors }) => {
+                assert_eq!(context, "Test");
+                assert_eq!(errors.len(), 1);
+            }
+            _ => panic!("expected a GraphQL error"),
         }
-        Some(id) => id,
+
+        assert!(matches!(
+            decode("not json").0,
+            Err(GHDError::DecodeError { .. })
+        ));
     }
 }
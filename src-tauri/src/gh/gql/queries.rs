@@ -4,7 +4,7 @@ pub mod user_info {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "UserInfo";
-    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nquery SearchIssues($q: String!) {\n  search(first: 100, query: $q, type: ISSUE) {\n    nodes {\n      __typename\n      ... on PullRequest {\n        title\n        number\n        databaseId\n        author {\n          __typename\n          ...UserFragment\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        url\n        state\n        isDraft\n        totalCommentsCount\n        reviewRequests(first: 30) {\n          nodes {\n            requestedReviewer {\n              __typename\n              ...UserFragment\n            }\n          }\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n        createdAt\n        updatedAt\n        closedAt\n        mergedAt\n      }\n      ... on Issue {\n        title\n        number\n        databaseId\n        url\n        author {\n          __typename\n          ...UserFragment\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        state\n        createdAt\n        updatedAt\n        closedAt\n        comments(first: 100) {\n          nodes {\n            author {\n              __typename\n              ...UserFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo($owner: String!, $repo: String!, $prid: Int!) {\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n    }\n  }\n}\n" ;
+    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nfragment SearchIssuesResults on SearchResultItemConnection {\n  issueCount\n  pageInfo {\n    hasNextPage\n    endCursor\n  }\n  nodes {\n    __typename\n    ... on PullRequest {\n      title\n      number\n      databaseId\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      totalCommentsCount\n      reviewRequests(first: 30) {\n        nodes {\n          requestedReviewer {\n            __typename\n            ...UserFragment\n            ... on Team {\n              combinedSlug\n            }\n          }\n        }\n      }\n      reviewDecision\n      reviews(first: 1) {\n        totalCount\n      }\n      latestReviews(first: 30) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n      createdAt\n      updatedAt\n      closedAt\n      mergedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n            }\n          }\n        }\n      }\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n      commenters: comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n    }\n    ... on Issue {\n      title\n      number\n      databaseId\n      url\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      state\n      createdAt\n      updatedAt\n      closedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n    }\n  }\n}\n\nquery SearchIssues($q: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  search(first: 100, query: $q, type: ISSUE, after: $after) {\n    ...SearchIssuesResults\n  }\n}\n\n# Runs up to 10 searches at once, each aliased as `uN` and enabled by setting\n# `$iN`. Must match `MAX_SEARCH_BATCH_SIZE` in gql.rs.\nquery BatchSearchIssues(\n  $q0: String! = \"\"\n  $i0: Boolean! = false\n  $q1: String! = \"\"\n  $i1: Boolean! = false\n  $q2: String! = \"\"\n  $i2: Boolean! = false\n  $q3: String! = \"\"\n  $i3: Boolean! = false\n  $q4: String! = \"\"\n  $i4: Boolean! = false\n  $q5: String! = \"\"\n  $i5: Boolean! = false\n  $q6: String! = \"\"\n  $i6: Boolean! = false\n  $q7: String! = \"\"\n  $i7: Boolean! = false\n  $q8: String! = \"\"\n  $i8: Boolean! = false\n  $q9: String! = \"\"\n  $i9: Boolean! = false\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  u0: search(first: 100, query: $q0, type: ISSUE) @include(if: $i0) {\n    ...SearchIssuesResults\n  }\n  u1: search(first: 100, query: $q1, type: ISSUE) @include(if: $i1) {\n    ...SearchIssuesResults\n  }\n  u2: search(first: 100, query: $q2, type: ISSUE) @include(if: $i2) {\n    ...SearchIssuesResults\n  }\n  u3: search(first: 100, query: $q3, type: ISSUE) @include(if: $i3) {\n    ...SearchIssuesResults\n  }\n  u4: search(first: 100, query: $q4, type: ISSUE) @include(if: $i4) {\n    ...SearchIssuesResults\n  }\n  u5: search(first: 100, query: $q5, type: ISSUE) @include(if: $i5) {\n    ...SearchIssuesResults\n  }\n  u6: search(first: 100, query: $q6, type: ISSUE) @include(if: $i6) {\n    ...SearchIssuesResults\n  }\n  u7: search(first: 100, query: $q7, type: ISSUE) @include(if: $i7) {\n    ...SearchIssuesResults\n  }\n  u8: search(first: 100, query: $q8, type: ISSUE) @include(if: $i8) {\n    ...SearchIssuesResults\n  }\n  u9: search(first: 100, query: $q9, type: ISSUE) @include(if: $i9) {\n    ...SearchIssuesResults\n  }\n}\n\nquery TeamInfo($org: String!, $slug: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  organization(login: $org) {\n    team(slug: $slug) {\n      databaseId\n      name\n      slug\n      members(first: 100, after: $after) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          ...UserFragment\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo(\n  $owner: String!\n  $repo: String!\n  $prid: Int!\n  $commentsAfter: String\n  $reviewsAfter: String\n  $participantsAfter: String\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100, after: $commentsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100, after: $reviewsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100, after: $participantsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n              contexts(first: 100) {\n                nodes {\n                  __typename\n                  ... on CheckRun {\n                    name\n                    status\n                    conclusion\n                    detailsUrl\n                  }\n                  ... on StatusContext {\n                    context\n                    state\n                    description\n                    targetUrl\n                  }\n                }\n              }\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment LinkedPullRequestFragment on PullRequest {\n  number\n  title\n  url\n  state\n  repository {\n    owner {\n      __typename\n      login\n    }\n    name\n  }\n}\n\nquery GetIssueInfo($owner: String!, $repo: String!, $number: Int!) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    issue(number: $number) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      comments {\n        totalCount\n      }\n      assignees(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      reactionGroups {\n        content\n        reactors {\n          totalCount\n        }\n      }\n      timelineItems(\n        first: 100\n        itemTypes: [CONNECTED_EVENT, CROSS_REFERENCED_EVENT]\n      ) {\n        nodes {\n          __typename\n          ... on ConnectedEvent {\n            subject {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n          ... on CrossReferencedEvent {\n            willCloseTarget\n            source {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -190,7 +190,7 @@ pub mod search_issues {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "SearchIssues";
-    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nquery SearchIssues($q: String!) {\n  search(first: 100, query: $q, type: ISSUE) {\n    nodes {\n      __typename\n      ... on PullRequest {\n        title\n        number\n        databaseId\n        author {\n          __typename\n          ...UserFragment\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        url\n        state\n        isDraft\n        totalCommentsCount\n        reviewRequests(first: 30) {\n          nodes {\n            requestedReviewer {\n              __typename\n              ...UserFragment\n            }\n          }\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n        createdAt\n        updatedAt\n        closedAt\n        mergedAt\n      }\n      ... on Issue {\n        title\n        number\n        databaseId\n        url\n        author {\n          __typename\n          ...UserFragment\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        state\n        createdAt\n        updatedAt\n        closedAt\n        comments(first: 100) {\n          nodes {\n            author {\n              __typename\n              ...UserFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo($owner: String!, $repo: String!, $prid: Int!) {\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n    }\n  }\n}\n" ;
+    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nfragment SearchIssuesResults on SearchResultItemConnection {\n  issueCount\n  pageInfo {\n    hasNextPage\n    endCursor\n  }\n  nodes {\n    __typename\n    ... on PullRequest {\n      title\n      number\n      databaseId\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      totalCommentsCount\n      reviewRequests(first: 30) {\n        nodes {\n          requestedReviewer {\n            __typename\n            ...UserFragment\n            ... on Team {\n              combinedSlug\n            }\n          }\n        }\n      }\n      reviewDecision\n      reviews(first: 1) {\n        totalCount\n      }\n      latestReviews(first: 30) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n      createdAt\n      updatedAt\n      closedAt\n      mergedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n            }\n          }\n        }\n      }\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n      commenters: comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n    }\n    ... on Issue {\n      title\n      number\n      databaseId\n      url\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      state\n      createdAt\n      updatedAt\n      closedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n    }\n  }\n}\n\nquery SearchIssues($q: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  search(first: 100, query: $q, type: ISSUE, after: $after) {\n    ...SearchIssuesResults\n  }\n}\n\n# Runs up to 10 searches at once, each aliased as `uN` and enabled by setting\n# `$iN`. Must match `MAX_SEARCH_BATCH_SIZE` in gql.rs.\nquery BatchSearchIssues(\n  $q0: String! = \"\"\n  $i0: Boolean! = false\n  $q1: String! = \"\"\n  $i1: Boolean! = false\n  $q2: String! = \"\"\n  $i2: Boolean! = false\n  $q3: String! = \"\"\n  $i3: Boolean! = false\n  $q4: String! = \"\"\n  $i4: Boolean! = false\n  $q5: String! = \"\"\n  $i5: Boolean! = false\n  $q6: String! = \"\"\n  $i6: Boolean! = false\n  $q7: String! = \"\"\n  $i7: Boolean! = false\n  $q8: String! = \"\"\n  $i8: Boolean! = false\n  $q9: String! = \"\"\n  $i9: Boolean! = false\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  u0: search(first: 100, query: $q0, type: ISSUE) @include(if: $i0) {\n    ...SearchIssuesResults\n  }\n  u1: search(first: 100, query: $q1, type: ISSUE) @include(if: $i1) {\n    ...SearchIssuesResults\n  }\n  u2: search(first: 100, query: $q2, type: ISSUE) @include(if: $i2) {\n    ...SearchIssuesResults\n  }\n  u3: search(first: 100, query: $q3, type: ISSUE) @include(if: $i3) {\n    ...SearchIssuesResults\n  }\n  u4: search(first: 100, query: $q4, type: ISSUE) @include(if: $i4) {\n    ...SearchIssuesResults\n  }\n  u5: search(first: 100, query: $q5, type: ISSUE) @include(if: $i5) {\n    ...SearchIssuesResults\n  }\n  u6: search(first: 100, query: $q6, type: ISSUE) @include(if: $i6) {\n    ...SearchIssuesResults\n  }\n  u7: search(first: 100, query: $q7, type: ISSUE) @include(if: $i7) {\n    ...SearchIssuesResults\n  }\n  u8: search(first: 100, query: $q8, type: ISSUE) @include(if: $i8) {\n    ...SearchIssuesResults\n  }\n  u9: search(first: 100, query: $q9, type: ISSUE) @include(if: $i9) {\n    ...SearchIssuesResults\n  }\n}\n\nquery TeamInfo($org: String!, $slug: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  organization(login: $org) {\n    team(slug: $slug) {\n      databaseId\n      name\n      slug\n      members(first: 100, after: $after) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          ...UserFragment\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo(\n  $owner: String!\n  $repo: String!\n  $prid: Int!\n  $commentsAfter: String\n  $reviewsAfter: String\n  $participantsAfter: String\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100, after: $commentsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100, after: $reviewsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100, after: $participantsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n              contexts(first: 100) {\n                nodes {\n                  __typename\n                  ... on CheckRun {\n                    name\n                    status\n                    conclusion\n                    detailsUrl\n                  }\n                  ... on StatusContext {\n                    context\n                    state\n                    description\n                    targetUrl\n                  }\n                }\n              }\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment LinkedPullRequestFragment on PullRequest {\n  number\n  title\n  url\n  state\n  repository {\n    owner {\n      __typename\n      login\n    }\n    name\n  }\n}\n\nquery GetIssueInfo($owner: String!, $repo: String!, $number: Int!) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    issue(number: $number) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      comments {\n        totalCount\n      }\n      assignees(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      reactionGroups {\n        content\n        reactors {\n          totalCount\n        }\n      }\n      timelineItems(\n        first: 100\n        itemTypes: [CONNECTED_EVENT, CROSS_REFERENCED_EVENT]\n      ) {\n        nodes {\n          __typename\n          ... on ConnectedEvent {\n            subject {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n          ... on CrossReferencedEvent {\n            willCloseTarget\n            source {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -234,6 +234,126 @@ pub mod search_issues {
         }
     }
     #[derive(Debug)]
+    pub enum MergeQueueEntryState {
+        AWAITING_CHECKS,
+        LOCKED,
+        MERGEABLE,
+        QUEUED,
+        UNMERGEABLE,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeQueueEntryState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeQueueEntryState::AWAITING_CHECKS => "AWAITING_CHECKS",
+                MergeQueueEntryState::LOCKED => "LOCKED",
+                MergeQueueEntryState::MERGEABLE => "MERGEABLE",
+                MergeQueueEntryState::QUEUED => "QUEUED",
+                MergeQueueEntryState::UNMERGEABLE => "UNMERGEABLE",
+                MergeQueueEntryState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeQueueEntryState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "AWAITING_CHECKS" => Ok(MergeQueueEntryState::AWAITING_CHECKS),
+                "LOCKED" => Ok(MergeQueueEntryState::LOCKED),
+                "MERGEABLE" => Ok(MergeQueueEntryState::MERGEABLE),
+                "QUEUED" => Ok(MergeQueueEntryState::QUEUED),
+                "UNMERGEABLE" => Ok(MergeQueueEntryState::UNMERGEABLE),
+                _ => Ok(MergeQueueEntryState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeStateStatus {
+        BEHIND,
+        BLOCKED,
+        CLEAN,
+        DIRTY,
+        DRAFT,
+        HAS_HOOKS,
+        UNKNOWN,
+        UNSTABLE,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeStateStatus {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeStateStatus::BEHIND => "BEHIND",
+                MergeStateStatus::BLOCKED => "BLOCKED",
+                MergeStateStatus::CLEAN => "CLEAN",
+                MergeStateStatus::DIRTY => "DIRTY",
+                MergeStateStatus::DRAFT => "DRAFT",
+                MergeStateStatus::HAS_HOOKS => "HAS_HOOKS",
+                MergeStateStatus::UNKNOWN => "UNKNOWN",
+                MergeStateStatus::UNSTABLE => "UNSTABLE",
+                MergeStateStatus::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeStateStatus {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "BEHIND" => Ok(MergeStateStatus::BEHIND),
+                "BLOCKED" => Ok(MergeStateStatus::BLOCKED),
+                "CLEAN" => Ok(MergeStateStatus::CLEAN),
+                "DIRTY" => Ok(MergeStateStatus::DIRTY),
+                "DRAFT" => Ok(MergeStateStatus::DRAFT),
+                "HAS_HOOKS" => Ok(MergeStateStatus::HAS_HOOKS),
+                "UNKNOWN" => Ok(MergeStateStatus::UNKNOWN),
+                "UNSTABLE" => Ok(MergeStateStatus::UNSTABLE),
+                _ => Ok(MergeStateStatus::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeableState {
+        CONFLICTING,
+        MERGEABLE,
+        UNKNOWN,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeableState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeableState::CONFLICTING => "CONFLICTING",
+                MergeableState::MERGEABLE => "MERGEABLE",
+                MergeableState::UNKNOWN => "UNKNOWN",
+                MergeableState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeableState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CONFLICTING" => Ok(MergeableState::CONFLICTING),
+                "MERGEABLE" => Ok(MergeableState::MERGEABLE),
+                "UNKNOWN" => Ok(MergeableState::UNKNOWN),
+                _ => Ok(MergeableState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
     pub enum PullRequestReviewDecision {
         APPROVED,
         CHANGES_REQUESTED,
@@ -305,9 +425,49 @@ pub mod search_issues {
             }
         }
     }
+    #[derive(Debug)]
+    pub enum StatusState {
+        ERROR,
+        EXPECTED,
+        FAILURE,
+        PENDING,
+        SUCCESS,
+        Other(String),
+    }
+    impl ::serde::Serialize for StatusState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                StatusState::ERROR => "ERROR",
+                StatusState::EXPECTED => "EXPECTED",
+                StatusState::FAILURE => "FAILURE",
+                StatusState::PENDING => "PENDING",
+                StatusState::SUCCESS => "SUCCESS",
+                StatusState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for StatusState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "ERROR" => Ok(StatusState::ERROR),
+                "EXPECTED" => Ok(StatusState::EXPECTED),
+                "FAILURE" => Ok(StatusState::FAILURE),
+                "PENDING" => Ok(StatusState::PENDING),
+                "SUCCESS" => Ok(StatusState::SUCCESS),
+                _ => Ok(StatusState::Other(s)),
+            }
+        }
+    }
     #[derive(Serialize)]
     pub struct Variables {
         pub q: String,
+        pub after: Option<String>,
     }
     impl Variables {}
     #[derive(Deserialize, Debug)]
@@ -320,34 +480,41 @@ pub mod search_issues {
         pub avatar_url: URI,
     }
     #[derive(Deserialize, Debug)]
-    pub struct ResponseData {
-        pub search: SearchIssuesSearch,
+    pub struct SearchIssuesResults {
+        #[serde(rename = "issueCount")]
+        pub issue_count: Int,
+        #[serde(rename = "pageInfo")]
+        pub page_info: SearchIssuesResultsPageInfo,
+        pub nodes: Option<Vec<Option<SearchIssuesResultsNodes>>>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearch {
-        pub nodes: Option<Vec<Option<SearchIssuesSearchNodes>>>,
+    pub struct SearchIssuesResultsPageInfo {
+        #[serde(rename = "hasNextPage")]
+        pub has_next_page: Boolean,
+        #[serde(rename = "endCursor")]
+        pub end_cursor: Option<String>,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodes {
+    pub enum SearchIssuesResultsNodes {
         App,
         Discussion,
-        Issue(SearchIssuesSearchNodesOnIssue),
+        Issue(SearchIssuesResultsNodesOnIssue),
         MarketplaceListing,
         Organization,
-        PullRequest(SearchIssuesSearchNodesOnPullRequest),
+        PullRequest(SearchIssuesResultsNodesOnPullRequest),
         Repository,
         User,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnIssue {
+    pub struct SearchIssuesResultsNodesOnIssue {
         pub title: String,
         pub number: Int,
         #[serde(rename = "databaseId")]
         pub database_id: Option<Int>,
         pub url: URI,
-        pub author: Option<SearchIssuesSearchNodesOnIssueAuthor>,
-        pub repository: SearchIssuesSearchNodesOnIssueRepository,
+        pub author: Option<SearchIssuesResultsNodesOnIssueAuthor>,
+        pub repository: SearchIssuesResultsNodesOnIssueRepository,
         pub state: IssueState,
         #[serde(rename = "createdAt")]
         pub created_at: DateTime,
@@ -355,63 +522,119 @@ pub mod search_issues {
         pub updated_at: DateTime,
         #[serde(rename = "closedAt")]
         pub closed_at: Option<DateTime>,
-        pub comments: SearchIssuesSearchNodesOnIssueComments,
+        pub assignees: SearchIssuesResultsNodesOnIssueAssignees,
+        pub comments: SearchIssuesResultsNodesOnIssueComments,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+        #[serde(rename = "lastComment")]
+        pub last_comment: SearchIssuesResultsNodesOnIssueLastComment,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodesOnIssueAuthor {
-        Bot,
+    pub enum SearchIssuesResultsNodesOnIssueAuthor {
+        Bot(SearchIssuesResultsNodesOnIssueAuthorOnBot),
         EnterpriseUserAccount,
-        Mannequin,
+        Mannequin(SearchIssuesResultsNodesOnIssueAuthorOnMannequin),
         Organization,
-        User(SearchIssuesSearchNodesOnIssueAuthorOnUser),
+        User(SearchIssuesResultsNodesOnIssueAuthorOnUser),
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAuthorOnBot {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAuthorOnMannequin {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
     }
-    pub type SearchIssuesSearchNodesOnIssueAuthorOnUser = UserFragment;
+    pub type SearchIssuesResultsNodesOnIssueAuthorOnUser = UserFragment;
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnIssueRepository {
-        pub owner: SearchIssuesSearchNodesOnIssueRepositoryOwner,
+    pub struct SearchIssuesResultsNodesOnIssueRepository {
+        pub owner: SearchIssuesResultsNodesOnIssueRepositoryOwner,
         pub name: String,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnIssueRepositoryOwner {
+    pub struct SearchIssuesResultsNodesOnIssueRepositoryOwner {
         pub login: String,
         #[serde(flatten)]
-        pub on: SearchIssuesSearchNodesOnIssueRepositoryOwnerOn,
+        pub on: SearchIssuesResultsNodesOnIssueRepositoryOwnerOn,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodesOnIssueRepositoryOwnerOn {
+    pub enum SearchIssuesResultsNodesOnIssueRepositoryOwnerOn {
         Organization,
         User,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnIssueComments {
+    pub struct SearchIssuesResultsNodesOnIssueAssignees {
+        pub nodes:
+            Option<Vec<Option<SearchIssuesResultsNodesOnIssueAssigneesNodes>>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAssigneesNodes {
+        pub login: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueComments {
         pub nodes:
-            Option<Vec<Option<SearchIssuesSearchNodesOnIssueCommentsNodes>>>,
+            Option<Vec<Option<SearchIssuesResultsNodesOnIssueCommentsNodes>>>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnIssueCommentsNodes {
-        pub author: Option<SearchIssuesSearchNodesOnIssueCommentsNodesAuthor>,
+    pub struct SearchIssuesResultsNodesOnIssueCommentsNodes {
+        pub author: Option<SearchIssuesResultsNodesOnIssueCommentsNodesAuthor>,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodesOnIssueCommentsNodesAuthor {
+    pub enum SearchIssuesResultsNodesOnIssueCommentsNodesAuthor {
         Bot,
         EnterpriseUserAccount,
         Mannequin,
         Organization,
-        User(SearchIssuesSearchNodesOnIssueCommentsNodesAuthorOnUser),
+        User(SearchIssuesResultsNodesOnIssueCommentsNodesAuthorOnUser),
     }
-    pub type SearchIssuesSearchNodesOnIssueCommentsNodesAuthorOnUser =
+    pub type SearchIssuesResultsNodesOnIssueCommentsNodesAuthorOnUser =
         UserFragment;
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnPullRequest {
+    pub struct SearchIssuesResultsNodesOnIssueLastComment {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnIssueLastCommentNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueLastCommentNodes {
+        pub author:
+            Option<SearchIssuesResultsNodesOnIssueLastCommentNodesAuthor>,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueLastCommentNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnIssueLastCommentNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnIssueLastCommentNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequest {
         pub title: String,
         pub number: Int,
         #[serde(rename = "databaseId")]
         pub database_id: Option<Int>,
-        pub author: Option<SearchIssuesSearchNodesOnPullRequestAuthor>,
-        pub repository: SearchIssuesSearchNodesOnPullRequestRepository,
+        pub author: Option<SearchIssuesResultsNodesOnPullRequestAuthor>,
+        pub repository: SearchIssuesResultsNodesOnPullRequestRepository,
         pub url: URI,
         pub state: PullRequestState,
         #[serde(rename = "isDraft")]
@@ -420,10 +643,13 @@ pub mod search_issues {
         pub total_comments_count: Option<Int>,
         #[serde(rename = "reviewRequests")]
         pub review_requests:
-            Option<SearchIssuesSearchNodesOnPullRequestReviewRequests>,
+            Option<SearchIssuesResultsNodesOnPullRequestReviewRequests>,
         #[serde(rename = "reviewDecision")]
         pub review_decision: Option<PullRequestReviewDecision>,
-        pub reviews: Option<SearchIssuesSearchNodesOnPullRequestReviews>,
+        pub reviews: Option<SearchIssuesResultsNodesOnPullRequestReviews>,
+        #[serde(rename = "latestReviews")]
+        pub latest_reviews:
+            Option<SearchIssuesResultsNodesOnPullRequestLatestReviews>,
         #[serde(rename = "createdAt")]
         pub created_at: DateTime,
         #[serde(rename = "updatedAt")]
@@ -432,56 +658,229 @@ pub mod search_issues {
         pub closed_at: Option<DateTime>,
         #[serde(rename = "mergedAt")]
         pub merged_at: Option<DateTime>,
+        pub assignees: SearchIssuesResultsNodesOnPullRequestAssignees,
+        pub commits: SearchIssuesResultsNodesOnPullRequestCommits,
+        pub mergeable: MergeableState,
+        #[serde(rename = "mergeStateStatus")]
+        pub merge_state_status: MergeStateStatus,
+        #[serde(rename = "baseRefName")]
+        pub base_ref_name: String,
+        #[serde(rename = "headRefName")]
+        pub head_ref_name: String,
+        #[serde(rename = "mergeQueueEntry")]
+        pub merge_queue_entry:
+            Option<SearchIssuesResultsNodesOnPullRequestMergeQueueEntry>,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+        #[serde(rename = "lastComment")]
+        pub last_comment: SearchIssuesResultsNodesOnPullRequestLastComment,
+        pub commenters: SearchIssuesResultsNodesOnPullRequestCommenters,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodesOnPullRequestAuthor {
-        Bot,
+    pub enum SearchIssuesResultsNodesOnPullRequestAuthor {
+        Bot(SearchIssuesResultsNodesOnPullRequestAuthorOnBot),
         EnterpriseUserAccount,
-        Mannequin,
+        Mannequin(SearchIssuesResultsNodesOnPullRequestAuthorOnMannequin),
         Organization,
-        User(SearchIssuesSearchNodesOnPullRequestAuthorOnUser),
+        User(SearchIssuesResultsNodesOnPullRequestAuthorOnUser),
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAuthorOnBot {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAuthorOnMannequin {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
     }
-    pub type SearchIssuesSearchNodesOnPullRequestAuthorOnUser = UserFragment;
+    pub type SearchIssuesResultsNodesOnPullRequestAuthorOnUser = UserFragment;
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnPullRequestRepository {
-        pub owner: SearchIssuesSearchNodesOnPullRequestRepositoryOwner,
+    pub struct SearchIssuesResultsNodesOnPullRequestRepository {
+        pub owner: SearchIssuesResultsNodesOnPullRequestRepositoryOwner,
         pub name: String,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnPullRequestRepositoryOwner {
+    pub struct SearchIssuesResultsNodesOnPullRequestRepositoryOwner {
         pub login: String,
         #[serde(flatten)]
-        pub on: SearchIssuesSearchNodesOnPullRequestRepositoryOwnerOn,
+        pub on: SearchIssuesResultsNodesOnPullRequestRepositoryOwnerOn,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodesOnPullRequestRepositoryOwnerOn {
+    pub enum SearchIssuesResultsNodesOnPullRequestRepositoryOwnerOn {
         Organization,
         User,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnPullRequestReviewRequests {
+    pub struct SearchIssuesResultsNodesOnPullRequestReviewRequests {
         pub nodes: Option<
             Vec<
-                Option<SearchIssuesSearchNodesOnPullRequestReviewRequestsNodes>,
+                Option<
+                    SearchIssuesResultsNodesOnPullRequestReviewRequestsNodes,
+                >,
             >,
         >,
     }
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnPullRequestReviewRequestsNodes { # [serde (rename = "requestedReviewer")] pub requested_reviewer : Option < SearchIssuesSearchNodesOnPullRequestReviewRequestsNodesRequestedReviewer > , }
+    pub struct SearchIssuesResultsNodesOnPullRequestReviewRequestsNodes { # [serde (rename = "requestedReviewer")] pub requested_reviewer : Option < SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer > , }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum SearchIssuesSearchNodesOnPullRequestReviewRequestsNodesRequestedReviewer
+    pub enum SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer
     {
-        Mannequin , Team , User (SearchIssuesSearchNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnUser) }
-    pub type SearchIssuesSearchNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnUser =
+        Mannequin , Team (SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnTeam) , User (SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnUser) }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnTeam
+    {
+        #[serde(rename = "combinedSlug")]
+        pub combined_slug: String,
+    }
+    pub type SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnUser =
         UserFragment;
     #[derive(Deserialize, Debug)]
-    pub struct SearchIssuesSearchNodesOnPullRequestReviews {
+    pub struct SearchIssuesResultsNodesOnPullRequestReviews {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLatestReviews {
+        pub nodes: Option<
+            Vec<
+                Option<SearchIssuesResultsNodesOnPullRequestLatestReviewsNodes>,
+            >,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLatestReviewsNodes {
+        pub author: Option<
+            SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthor,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAssignees {
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestAssigneesNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAssigneesNodes {
+        pub login: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommits {
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestCommitsNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommitsNodes {
+        pub commit: SearchIssuesResultsNodesOnPullRequestCommitsNodesCommit,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommitsNodesCommit { # [serde (rename = "statusCheckRollup")] pub status_check_rollup : Option < SearchIssuesResultsNodesOnPullRequestCommitsNodesCommitStatusCheckRollup > , }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommitsNodesCommitStatusCheckRollup
+    {
+        pub state: StatusState,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestMergeQueueEntry {
+        pub position: Int,
+        pub state: MergeQueueEntryState,
+        #[serde(rename = "enqueuedAt")]
+        pub enqueued_at: DateTime,
+        #[serde(rename = "estimatedTimeToMerge")]
+        pub estimated_time_to_merge: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLastComment {
         #[serde(rename = "totalCount")]
         pub total_count: Int,
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestLastCommentNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLastCommentNodes {
+        pub author:
+            Option<SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthor>,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommenters {
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestCommentersNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommentersNodes {
+        pub author:
+            Option<SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthor>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "rateLimit")]
+        pub rate_limit: Option<SearchIssuesRateLimit>,
+        pub search: SearchIssuesSearch,
     }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesRateLimit {
+        pub cost: Int,
+        pub limit: Int,
+        pub remaining: Int,
+        #[serde(rename = "resetAt")]
+        pub reset_at: DateTime,
+    }
+    pub type SearchIssuesSearch = SearchIssuesResults;
 }
 impl graphql_client::GraphQLQuery for SearchIssues {
     type Variables = search_issues::Variables;
@@ -496,12 +895,12 @@ impl graphql_client::GraphQLQuery for SearchIssues {
         }
     }
 }
-pub struct GetPullRequestInfo;
-pub mod get_pull_request_info {
+pub struct BatchSearchIssues;
+pub mod batch_search_issues {
     #![allow(dead_code)]
     use std::result::Result;
-    pub const OPERATION_NAME: &str = "GetPullRequestInfo";
-    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nquery SearchIssues($q: String!) {\n  search(first: 100, query: $q, type: ISSUE) {\n    nodes {\n      __typename\n      ... on PullRequest {\n        title\n        number\n        databaseId\n        author {\n          __typename\n          ...UserFragment\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        url\n        state\n        isDraft\n        totalCommentsCount\n        reviewRequests(first: 30) {\n          nodes {\n            requestedReviewer {\n              __typename\n              ...UserFragment\n            }\n          }\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n        createdAt\n        updatedAt\n        closedAt\n        mergedAt\n      }\n      ... on Issue {\n        title\n        number\n        databaseId\n        url\n        author {\n          __typename\n          ...UserFragment\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        state\n        createdAt\n        updatedAt\n        closedAt\n        comments(first: 100) {\n          nodes {\n            author {\n              __typename\n              ...UserFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo($owner: String!, $repo: String!, $prid: Int!) {\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n    }\n  }\n}\n" ;
+    pub const OPERATION_NAME: &str = "BatchSearchIssues";
+    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nfragment SearchIssuesResults on SearchResultItemConnection {\n  issueCount\n  pageInfo {\n    hasNextPage\n    endCursor\n  }\n  nodes {\n    __typename\n    ... on PullRequest {\n      title\n      number\n      databaseId\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      totalCommentsCount\n      reviewRequests(first: 30) {\n        nodes {\n          requestedReviewer {\n            __typename\n            ...UserFragment\n            ... on Team {\n              combinedSlug\n            }\n          }\n        }\n      }\n      reviewDecision\n      reviews(first: 1) {\n        totalCount\n      }\n      latestReviews(first: 30) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n      createdAt\n      updatedAt\n      closedAt\n      mergedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n            }\n          }\n        }\n      }\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n      commenters: comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n    }\n    ... on Issue {\n      title\n      number\n      databaseId\n      url\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      state\n      createdAt\n      updatedAt\n      closedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n    }\n  }\n}\n\nquery SearchIssues($q: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  search(first: 100, query: $q, type: ISSUE, after: $after) {\n    ...SearchIssuesResults\n  }\n}\n\n# Runs up to 10 searches at once, each aliased as `uN` and enabled by setting\n# `$iN`. Must match `MAX_SEARCH_BATCH_SIZE` in gql.rs.\nquery BatchSearchIssues(\n  $q0: String! = \"\"\n  $i0: Boolean! = false\n  $q1: String! = \"\"\n  $i1: Boolean! = false\n  $q2: String! = \"\"\n  $i2: Boolean! = false\n  $q3: String! = \"\"\n  $i3: Boolean! = false\n  $q4: String! = \"\"\n  $i4: Boolean! = false\n  $q5: String! = \"\"\n  $i5: Boolean! = false\n  $q6: String! = \"\"\n  $i6: Boolean! = false\n  $q7: String! = \"\"\n  $i7: Boolean! = false\n  $q8: String! = \"\"\n  $i8: Boolean! = false\n  $q9: String! = \"\"\n  $i9: Boolean! = false\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  u0: search(first: 100, query: $q0, type: ISSUE) @include(if: $i0) {\n    ...SearchIssuesResults\n  }\n  u1: search(first: 100, query: $q1, type: ISSUE) @include(if: $i1) {\n    ...SearchIssuesResults\n  }\n  u2: search(first: 100, query: $q2, type: ISSUE) @include(if: $i2) {\n    ...SearchIssuesResults\n  }\n  u3: search(first: 100, query: $q3, type: ISSUE) @include(if: $i3) {\n    ...SearchIssuesResults\n  }\n  u4: search(first: 100, query: $q4, type: ISSUE) @include(if: $i4) {\n    ...SearchIssuesResults\n  }\n  u5: search(first: 100, query: $q5, type: ISSUE) @include(if: $i5) {\n    ...SearchIssuesResults\n  }\n  u6: search(first: 100, query: $q6, type: ISSUE) @include(if: $i6) {\n    ...SearchIssuesResults\n  }\n  u7: search(first: 100, query: $q7, type: ISSUE) @include(if: $i7) {\n    ...SearchIssuesResults\n  }\n  u8: search(first: 100, query: $q8, type: ISSUE) @include(if: $i8) {\n    ...SearchIssuesResults\n  }\n  u9: search(first: 100, query: $q9, type: ISSUE) @include(if: $i9) {\n    ...SearchIssuesResults\n  }\n}\n\nquery TeamInfo($org: String!, $slug: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  organization(login: $org) {\n    team(slug: $slug) {\n      databaseId\n      name\n      slug\n      members(first: 100, after: $after) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          ...UserFragment\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo(\n  $owner: String!\n  $repo: String!\n  $prid: Int!\n  $commentsAfter: String\n  $reviewsAfter: String\n  $participantsAfter: String\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100, after: $commentsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100, after: $reviewsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100, after: $participantsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n              contexts(first: 100) {\n                nodes {\n                  __typename\n                  ... on CheckRun {\n                    name\n                    status\n                    conclusion\n                    detailsUrl\n                  }\n                  ... on StatusContext {\n                    context\n                    state\n                    description\n                    targetUrl\n                  }\n                }\n              }\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment LinkedPullRequestFragment on PullRequest {\n  number\n  title\n  url\n  state\n  repository {\n    owner {\n      __typename\n      login\n    }\n    name\n  }\n}\n\nquery GetIssueInfo($owner: String!, $repo: String!, $number: Int!) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    issue(number: $number) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      comments {\n        totalCount\n      }\n      assignees(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      reactionGroups {\n        content\n        reactors {\n          totalCount\n        }\n      }\n      timelineItems(\n        first: 100\n        itemTypes: [CONNECTED_EVENT, CROSS_REFERENCED_EVENT]\n      ) {\n        nodes {\n          __typename\n          ... on ConnectedEvent {\n            subject {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n          ... on CrossReferencedEvent {\n            willCloseTarget\n            source {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -513,78 +912,1608 @@ pub mod get_pull_request_info {
     #[allow(dead_code)]
     type ID = String;
     type DateTime = crate::gh::gql::custom_types::DateTime;
-    type HTML = crate::gh::gql::custom_types::HTML;
     type URI = crate::gh::gql::custom_types::URI;
     #[derive(Debug)]
-    pub enum MilestoneState {
+    pub enum IssueState {
         CLOSED,
         OPEN,
         Other(String),
     }
-    impl ::serde::Serialize for MilestoneState {
+    impl ::serde::Serialize for IssueState {
         fn serialize<S: serde::Serializer>(
             &self,
             ser: S,
         ) -> Result<S::Ok, S::Error> {
             ser.serialize_str(match *self {
-                MilestoneState::CLOSED => "CLOSED",
-                MilestoneState::OPEN => "OPEN",
-                MilestoneState::Other(ref s) => &s,
+                IssueState::CLOSED => "CLOSED",
+                IssueState::OPEN => "OPEN",
+                IssueState::Other(ref s) => &s,
             })
         }
     }
-    impl<'de> ::serde::Deserialize<'de> for MilestoneState {
+    impl<'de> ::serde::Deserialize<'de> for IssueState {
         fn deserialize<D: ::serde::Deserializer<'de>>(
             deserializer: D,
         ) -> Result<Self, D::Error> {
             let s: String = ::serde::Deserialize::deserialize(deserializer)?;
             match s.as_str() {
-                "CLOSED" => Ok(MilestoneState::CLOSED),
-                "OPEN" => Ok(MilestoneState::OPEN),
-                _ => Ok(MilestoneState::Other(s)),
+                "CLOSED" => Ok(IssueState::CLOSED),
+                "OPEN" => Ok(IssueState::OPEN),
+                _ => Ok(IssueState::Other(s)),
             }
         }
     }
     #[derive(Debug)]
-    pub enum PullRequestReviewState {
-        APPROVED,
-        CHANGES_REQUESTED,
-        COMMENTED,
-        DISMISSED,
-        PENDING,
+    pub enum MergeQueueEntryState {
+        AWAITING_CHECKS,
+        LOCKED,
+        MERGEABLE,
+        QUEUED,
+        UNMERGEABLE,
         Other(String),
     }
-    impl ::serde::Serialize for PullRequestReviewState {
+    impl ::serde::Serialize for MergeQueueEntryState {
         fn serialize<S: serde::Serializer>(
             &self,
             ser: S,
         ) -> Result<S::Ok, S::Error> {
             ser.serialize_str(match *self {
-                PullRequestReviewState::APPROVED => "APPROVED",
-                PullRequestReviewState::CHANGES_REQUESTED => {
-                    "CHANGES_REQUESTED"
-                }
-                PullRequestReviewState::COMMENTED => "COMMENTED",
-                PullRequestReviewState::DISMISSED => "DISMISSED",
-                PullRequestReviewState::PENDING => "PENDING",
+                MergeQueueEntryState::AWAITING_CHECKS => "AWAITING_CHECKS",
+                MergeQueueEntryState::LOCKED => "LOCKED",
+                MergeQueueEntryState::MERGEABLE => "MERGEABLE",
+                MergeQueueEntryState::QUEUED => "QUEUED",
+                MergeQueueEntryState::UNMERGEABLE => "UNMERGEABLE",
+                MergeQueueEntryState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeQueueEntryState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "AWAITING_CHECKS" => Ok(MergeQueueEntryState::AWAITING_CHECKS),
+                "LOCKED" => Ok(MergeQueueEntryState::LOCKED),
+                "MERGEABLE" => Ok(MergeQueueEntryState::MERGEABLE),
+                "QUEUED" => Ok(MergeQueueEntryState::QUEUED),
+                "UNMERGEABLE" => Ok(MergeQueueEntryState::UNMERGEABLE),
+                _ => Ok(MergeQueueEntryState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeStateStatus {
+        BEHIND,
+        BLOCKED,
+        CLEAN,
+        DIRTY,
+        DRAFT,
+        HAS_HOOKS,
+        UNKNOWN,
+        UNSTABLE,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeStateStatus {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeStateStatus::BEHIND => "BEHIND",
+                MergeStateStatus::BLOCKED => "BLOCKED",
+                MergeStateStatus::CLEAN => "CLEAN",
+                MergeStateStatus::DIRTY => "DIRTY",
+                MergeStateStatus::DRAFT => "DRAFT",
+                MergeStateStatus::HAS_HOOKS => "HAS_HOOKS",
+                MergeStateStatus::UNKNOWN => "UNKNOWN",
+                MergeStateStatus::UNSTABLE => "UNSTABLE",
+                MergeStateStatus::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeStateStatus {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "BEHIND" => Ok(MergeStateStatus::BEHIND),
+                "BLOCKED" => Ok(MergeStateStatus::BLOCKED),
+                "CLEAN" => Ok(MergeStateStatus::CLEAN),
+                "DIRTY" => Ok(MergeStateStatus::DIRTY),
+                "DRAFT" => Ok(MergeStateStatus::DRAFT),
+                "HAS_HOOKS" => Ok(MergeStateStatus::HAS_HOOKS),
+                "UNKNOWN" => Ok(MergeStateStatus::UNKNOWN),
+                "UNSTABLE" => Ok(MergeStateStatus::UNSTABLE),
+                _ => Ok(MergeStateStatus::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeableState {
+        CONFLICTING,
+        MERGEABLE,
+        UNKNOWN,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeableState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeableState::CONFLICTING => "CONFLICTING",
+                MergeableState::MERGEABLE => "MERGEABLE",
+                MergeableState::UNKNOWN => "UNKNOWN",
+                MergeableState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeableState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CONFLICTING" => Ok(MergeableState::CONFLICTING),
+                "MERGEABLE" => Ok(MergeableState::MERGEABLE),
+                "UNKNOWN" => Ok(MergeableState::UNKNOWN),
+                _ => Ok(MergeableState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum PullRequestReviewDecision {
+        APPROVED,
+        CHANGES_REQUESTED,
+        REVIEW_REQUIRED,
+        Other(String),
+    }
+    impl ::serde::Serialize for PullRequestReviewDecision {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                PullRequestReviewDecision::APPROVED => "APPROVED",
+                PullRequestReviewDecision::CHANGES_REQUESTED => {
+                    "CHANGES_REQUESTED"
+                }
+                PullRequestReviewDecision::REVIEW_REQUIRED => "REVIEW_REQUIRED",
+                PullRequestReviewDecision::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for PullRequestReviewDecision {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "APPROVED" => Ok(PullRequestReviewDecision::APPROVED),
+                "CHANGES_REQUESTED" => {
+                    Ok(PullRequestReviewDecision::CHANGES_REQUESTED)
+                }
+                "REVIEW_REQUIRED" => {
+                    Ok(PullRequestReviewDecision::REVIEW_REQUIRED)
+                }
+                _ => Ok(PullRequestReviewDecision::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum PullRequestState {
+        CLOSED,
+        MERGED,
+        OPEN,
+        Other(String),
+    }
+    impl ::serde::Serialize for PullRequestState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                PullRequestState::CLOSED => "CLOSED",
+                PullRequestState::MERGED => "MERGED",
+                PullRequestState::OPEN => "OPEN",
+                PullRequestState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for PullRequestState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CLOSED" => Ok(PullRequestState::CLOSED),
+                "MERGED" => Ok(PullRequestState::MERGED),
+                "OPEN" => Ok(PullRequestState::OPEN),
+                _ => Ok(PullRequestState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum StatusState {
+        ERROR,
+        EXPECTED,
+        FAILURE,
+        PENDING,
+        SUCCESS,
+        Other(String),
+    }
+    impl ::serde::Serialize for StatusState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                StatusState::ERROR => "ERROR",
+                StatusState::EXPECTED => "EXPECTED",
+                StatusState::FAILURE => "FAILURE",
+                StatusState::PENDING => "PENDING",
+                StatusState::SUCCESS => "SUCCESS",
+                StatusState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for StatusState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "ERROR" => Ok(StatusState::ERROR),
+                "EXPECTED" => Ok(StatusState::EXPECTED),
+                "FAILURE" => Ok(StatusState::FAILURE),
+                "PENDING" => Ok(StatusState::PENDING),
+                "SUCCESS" => Ok(StatusState::SUCCESS),
+                _ => Ok(StatusState::Other(s)),
+            }
+        }
+    }
+    #[derive(Serialize)]
+    pub struct Variables {
+        pub q0: String,
+        pub i0: Boolean,
+        pub q1: String,
+        pub i1: Boolean,
+        pub q2: String,
+        pub i2: Boolean,
+        pub q3: String,
+        pub i3: Boolean,
+        pub q4: String,
+        pub i4: Boolean,
+        pub q5: String,
+        pub i5: Boolean,
+        pub q6: String,
+        pub i6: Boolean,
+        pub q7: String,
+        pub i7: Boolean,
+        pub q8: String,
+        pub i8: Boolean,
+        pub q9: String,
+        pub i9: Boolean,
+    }
+    impl Variables {
+        pub fn default_q0() -> String {
+            "".to_string()
+        }
+        pub fn default_i0() -> Boolean {
+            false
+        }
+        pub fn default_q1() -> String {
+            "".to_string()
+        }
+        pub fn default_i1() -> Boolean {
+            false
+        }
+        pub fn default_q2() -> String {
+            "".to_string()
+        }
+        pub fn default_i2() -> Boolean {
+            false
+        }
+        pub fn default_q3() -> String {
+            "".to_string()
+        }
+        pub fn default_i3() -> Boolean {
+            false
+        }
+        pub fn default_q4() -> String {
+            "".to_string()
+        }
+        pub fn default_i4() -> Boolean {
+            false
+        }
+        pub fn default_q5() -> String {
+            "".to_string()
+        }
+        pub fn default_i5() -> Boolean {
+            false
+        }
+        pub fn default_q6() -> String {
+            "".to_string()
+        }
+        pub fn default_i6() -> Boolean {
+            false
+        }
+        pub fn default_q7() -> String {
+            "".to_string()
+        }
+        pub fn default_i7() -> Boolean {
+            false
+        }
+        pub fn default_q8() -> String {
+            "".to_string()
+        }
+        pub fn default_i8() -> Boolean {
+            false
+        }
+        pub fn default_q9() -> String {
+            "".to_string()
+        }
+        pub fn default_i9() -> Boolean {
+            false
+        }
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct UserFragment {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+        pub name: Option<String>,
+        #[serde(rename = "avatarUrl")]
+        pub avatar_url: URI,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResults {
+        #[serde(rename = "issueCount")]
+        pub issue_count: Int,
+        #[serde(rename = "pageInfo")]
+        pub page_info: SearchIssuesResultsPageInfo,
+        pub nodes: Option<Vec<Option<SearchIssuesResultsNodes>>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsPageInfo {
+        #[serde(rename = "hasNextPage")]
+        pub has_next_page: Boolean,
+        #[serde(rename = "endCursor")]
+        pub end_cursor: Option<String>,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodes {
+        App,
+        Discussion,
+        Issue(SearchIssuesResultsNodesOnIssue),
+        MarketplaceListing,
+        Organization,
+        PullRequest(SearchIssuesResultsNodesOnPullRequest),
+        Repository,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssue {
+        pub title: String,
+        pub number: Int,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+        pub url: URI,
+        pub author: Option<SearchIssuesResultsNodesOnIssueAuthor>,
+        pub repository: SearchIssuesResultsNodesOnIssueRepository,
+        pub state: IssueState,
+        #[serde(rename = "createdAt")]
+        pub created_at: DateTime,
+        #[serde(rename = "updatedAt")]
+        pub updated_at: DateTime,
+        #[serde(rename = "closedAt")]
+        pub closed_at: Option<DateTime>,
+        pub assignees: SearchIssuesResultsNodesOnIssueAssignees,
+        pub comments: SearchIssuesResultsNodesOnIssueComments,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+        #[serde(rename = "lastComment")]
+        pub last_comment: SearchIssuesResultsNodesOnIssueLastComment,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnIssueAuthor {
+        Bot(SearchIssuesResultsNodesOnIssueAuthorOnBot),
+        EnterpriseUserAccount,
+        Mannequin(SearchIssuesResultsNodesOnIssueAuthorOnMannequin),
+        Organization,
+        User(SearchIssuesResultsNodesOnIssueAuthorOnUser),
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAuthorOnBot {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAuthorOnMannequin {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    pub type SearchIssuesResultsNodesOnIssueAuthorOnUser = UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueRepository {
+        pub owner: SearchIssuesResultsNodesOnIssueRepositoryOwner,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueRepositoryOwner {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnIssueRepositoryOwnerOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnIssueRepositoryOwnerOn {
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAssignees {
+        pub nodes:
+            Option<Vec<Option<SearchIssuesResultsNodesOnIssueAssigneesNodes>>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueAssigneesNodes {
+        pub login: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueComments {
+        pub nodes:
+            Option<Vec<Option<SearchIssuesResultsNodesOnIssueCommentsNodes>>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueCommentsNodes {
+        pub author: Option<SearchIssuesResultsNodesOnIssueCommentsNodesAuthor>,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnIssueCommentsNodesAuthor {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User(SearchIssuesResultsNodesOnIssueCommentsNodesAuthorOnUser),
+    }
+    pub type SearchIssuesResultsNodesOnIssueCommentsNodesAuthorOnUser =
+        UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueLastComment {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnIssueLastCommentNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueLastCommentNodes {
+        pub author:
+            Option<SearchIssuesResultsNodesOnIssueLastCommentNodesAuthor>,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnIssueLastCommentNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnIssueLastCommentNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnIssueLastCommentNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequest {
+        pub title: String,
+        pub number: Int,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+        pub author: Option<SearchIssuesResultsNodesOnPullRequestAuthor>,
+        pub repository: SearchIssuesResultsNodesOnPullRequestRepository,
+        pub url: URI,
+        pub state: PullRequestState,
+        #[serde(rename = "isDraft")]
+        pub is_draft: Boolean,
+        #[serde(rename = "totalCommentsCount")]
+        pub total_comments_count: Option<Int>,
+        #[serde(rename = "reviewRequests")]
+        pub review_requests:
+            Option<SearchIssuesResultsNodesOnPullRequestReviewRequests>,
+        #[serde(rename = "reviewDecision")]
+        pub review_decision: Option<PullRequestReviewDecision>,
+        pub reviews: Option<SearchIssuesResultsNodesOnPullRequestReviews>,
+        #[serde(rename = "latestReviews")]
+        pub latest_reviews:
+            Option<SearchIssuesResultsNodesOnPullRequestLatestReviews>,
+        #[serde(rename = "createdAt")]
+        pub created_at: DateTime,
+        #[serde(rename = "updatedAt")]
+        pub updated_at: DateTime,
+        #[serde(rename = "closedAt")]
+        pub closed_at: Option<DateTime>,
+        #[serde(rename = "mergedAt")]
+        pub merged_at: Option<DateTime>,
+        pub assignees: SearchIssuesResultsNodesOnPullRequestAssignees,
+        pub commits: SearchIssuesResultsNodesOnPullRequestCommits,
+        pub mergeable: MergeableState,
+        #[serde(rename = "mergeStateStatus")]
+        pub merge_state_status: MergeStateStatus,
+        #[serde(rename = "baseRefName")]
+        pub base_ref_name: String,
+        #[serde(rename = "headRefName")]
+        pub head_ref_name: String,
+        #[serde(rename = "mergeQueueEntry")]
+        pub merge_queue_entry:
+            Option<SearchIssuesResultsNodesOnPullRequestMergeQueueEntry>,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+        #[serde(rename = "lastComment")]
+        pub last_comment: SearchIssuesResultsNodesOnPullRequestLastComment,
+        pub commenters: SearchIssuesResultsNodesOnPullRequestCommenters,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestAuthor {
+        Bot(SearchIssuesResultsNodesOnPullRequestAuthorOnBot),
+        EnterpriseUserAccount,
+        Mannequin(SearchIssuesResultsNodesOnPullRequestAuthorOnMannequin),
+        Organization,
+        User(SearchIssuesResultsNodesOnPullRequestAuthorOnUser),
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAuthorOnBot {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAuthorOnMannequin {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    pub type SearchIssuesResultsNodesOnPullRequestAuthorOnUser = UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestRepository {
+        pub owner: SearchIssuesResultsNodesOnPullRequestRepositoryOwner,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestRepositoryOwner {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestRepositoryOwnerOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestRepositoryOwnerOn {
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestReviewRequests {
+        pub nodes: Option<
+            Vec<
+                Option<
+                    SearchIssuesResultsNodesOnPullRequestReviewRequestsNodes,
+                >,
+            >,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestReviewRequestsNodes { # [serde (rename = "requestedReviewer")] pub requested_reviewer : Option < SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer > , }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewer
+    {
+        Mannequin , Team (SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnTeam) , User (SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnUser) }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnTeam
+    {
+        #[serde(rename = "combinedSlug")]
+        pub combined_slug: String,
+    }
+    pub type SearchIssuesResultsNodesOnPullRequestReviewRequestsNodesRequestedReviewerOnUser =
+        UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestReviews {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLatestReviews {
+        pub nodes: Option<
+            Vec<
+                Option<SearchIssuesResultsNodesOnPullRequestLatestReviewsNodes>,
+            >,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLatestReviewsNodes {
+        pub author: Option<
+            SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthor,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestLatestReviewsNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAssignees {
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestAssigneesNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestAssigneesNodes {
+        pub login: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommits {
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestCommitsNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommitsNodes {
+        pub commit: SearchIssuesResultsNodesOnPullRequestCommitsNodesCommit,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommitsNodesCommit { # [serde (rename = "statusCheckRollup")] pub status_check_rollup : Option < SearchIssuesResultsNodesOnPullRequestCommitsNodesCommitStatusCheckRollup > , }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommitsNodesCommitStatusCheckRollup
+    {
+        pub state: StatusState,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestMergeQueueEntry {
+        pub position: Int,
+        pub state: MergeQueueEntryState,
+        #[serde(rename = "enqueuedAt")]
+        pub enqueued_at: DateTime,
+        #[serde(rename = "estimatedTimeToMerge")]
+        pub estimated_time_to_merge: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLastComment {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestLastCommentNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLastCommentNodes {
+        pub author:
+            Option<SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthor>,
+        #[serde(rename = "bodyText")]
+        pub body_text: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestLastCommentNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommenters {
+        pub nodes: Option<
+            Vec<Option<SearchIssuesResultsNodesOnPullRequestCommentersNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommentersNodes {
+        pub author:
+            Option<SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthor>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthor {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthorOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum SearchIssuesResultsNodesOnPullRequestCommentersNodesAuthorOn {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "rateLimit")]
+        pub rate_limit: Option<BatchSearchIssuesRateLimit>,
+        pub u0: BatchSearchIssuesU0,
+        pub u1: BatchSearchIssuesU1,
+        pub u2: BatchSearchIssuesU2,
+        pub u3: BatchSearchIssuesU3,
+        pub u4: BatchSearchIssuesU4,
+        pub u5: BatchSearchIssuesU5,
+        pub u6: BatchSearchIssuesU6,
+        pub u7: BatchSearchIssuesU7,
+        pub u8: BatchSearchIssuesU8,
+        pub u9: BatchSearchIssuesU9,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct BatchSearchIssuesRateLimit {
+        pub cost: Int,
+        pub limit: Int,
+        pub remaining: Int,
+        #[serde(rename = "resetAt")]
+        pub reset_at: DateTime,
+    }
+    pub type BatchSearchIssuesU0 = SearchIssuesResults;
+    pub type BatchSearchIssuesU1 = SearchIssuesResults;
+    pub type BatchSearchIssuesU2 = SearchIssuesResults;
+    pub type BatchSearchIssuesU3 = SearchIssuesResults;
+    pub type BatchSearchIssuesU4 = SearchIssuesResults;
+    pub type BatchSearchIssuesU5 = SearchIssuesResults;
+    pub type BatchSearchIssuesU6 = SearchIssuesResults;
+    pub type BatchSearchIssuesU7 = SearchIssuesResults;
+    pub type BatchSearchIssuesU8 = SearchIssuesResults;
+    pub type BatchSearchIssuesU9 = SearchIssuesResults;
+}
+impl graphql_client::GraphQLQuery for BatchSearchIssues {
+    type Variables = batch_search_issues::Variables;
+    type ResponseData = batch_search_issues::ResponseData;
+    fn build_query(
+        variables: Self::Variables,
+    ) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: batch_search_issues::QUERY,
+            operation_name: batch_search_issues::OPERATION_NAME,
+        }
+    }
+}
+pub struct TeamInfo;
+pub mod team_info {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "TeamInfo";
+    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nfragment SearchIssuesResults on SearchResultItemConnection {\n  issueCount\n  pageInfo {\n    hasNextPage\n    endCursor\n  }\n  nodes {\n    __typename\n    ... on PullRequest {\n      title\n      number\n      databaseId\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      totalCommentsCount\n      reviewRequests(first: 30) {\n        nodes {\n          requestedReviewer {\n            __typename\n            ...UserFragment\n            ... on Team {\n              combinedSlug\n            }\n          }\n        }\n      }\n      reviewDecision\n      reviews(first: 1) {\n        totalCount\n      }\n      latestReviews(first: 30) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n      createdAt\n      updatedAt\n      closedAt\n      mergedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n            }\n          }\n        }\n      }\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n      commenters: comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n    }\n    ... on Issue {\n      title\n      number\n      databaseId\n      url\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      state\n      createdAt\n      updatedAt\n      closedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n    }\n  }\n}\n\nquery SearchIssues($q: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  search(first: 100, query: $q, type: ISSUE, after: $after) {\n    ...SearchIssuesResults\n  }\n}\n\n# Runs up to 10 searches at once, each aliased as `uN` and enabled by setting\n# `$iN`. Must match `MAX_SEARCH_BATCH_SIZE` in gql.rs.\nquery BatchSearchIssues(\n  $q0: String! = \"\"\n  $i0: Boolean! = false\n  $q1: String! = \"\"\n  $i1: Boolean! = false\n  $q2: String! = \"\"\n  $i2: Boolean! = false\n  $q3: String! = \"\"\n  $i3: Boolean! = false\n  $q4: String! = \"\"\n  $i4: Boolean! = false\n  $q5: String! = \"\"\n  $i5: Boolean! = false\n  $q6: String! = \"\"\n  $i6: Boolean! = false\n  $q7: String! = \"\"\n  $i7: Boolean! = false\n  $q8: String! = \"\"\n  $i8: Boolean! = false\n  $q9: String! = \"\"\n  $i9: Boolean! = false\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  u0: search(first: 100, query: $q0, type: ISSUE) @include(if: $i0) {\n    ...SearchIssuesResults\n  }\n  u1: search(first: 100, query: $q1, type: ISSUE) @include(if: $i1) {\n    ...SearchIssuesResults\n  }\n  u2: search(first: 100, query: $q2, type: ISSUE) @include(if: $i2) {\n    ...SearchIssuesResults\n  }\n  u3: search(first: 100, query: $q3, type: ISSUE) @include(if: $i3) {\n    ...SearchIssuesResults\n  }\n  u4: search(first: 100, query: $q4, type: ISSUE) @include(if: $i4) {\n    ...SearchIssuesResults\n  }\n  u5: search(first: 100, query: $q5, type: ISSUE) @include(if: $i5) {\n    ...SearchIssuesResults\n  }\n  u6: search(first: 100, query: $q6, type: ISSUE) @include(if: $i6) {\n    ...SearchIssuesResults\n  }\n  u7: search(first: 100, query: $q7, type: ISSUE) @include(if: $i7) {\n    ...SearchIssuesResults\n  }\n  u8: search(first: 100, query: $q8, type: ISSUE) @include(if: $i8) {\n    ...SearchIssuesResults\n  }\n  u9: search(first: 100, query: $q9, type: ISSUE) @include(if: $i9) {\n    ...SearchIssuesResults\n  }\n}\n\nquery TeamInfo($org: String!, $slug: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  organization(login: $org) {\n    team(slug: $slug) {\n      databaseId\n      name\n      slug\n      members(first: 100, after: $after) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          ...UserFragment\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo(\n  $owner: String!\n  $repo: String!\n  $prid: Int!\n  $commentsAfter: String\n  $reviewsAfter: String\n  $participantsAfter: String\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100, after: $commentsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100, after: $reviewsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100, after: $participantsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n              contexts(first: 100) {\n                nodes {\n                  __typename\n                  ... on CheckRun {\n                    name\n                    status\n                    conclusion\n                    detailsUrl\n                  }\n                  ... on StatusContext {\n                    context\n                    state\n                    description\n                    targetUrl\n                  }\n                }\n              }\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment LinkedPullRequestFragment on PullRequest {\n  number\n  title\n  url\n  state\n  repository {\n    owner {\n      __typename\n      login\n    }\n    name\n  }\n}\n\nquery GetIssueInfo($owner: String!, $repo: String!, $number: Int!) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    issue(number: $number) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      comments {\n        totalCount\n      }\n      assignees(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      reactionGroups {\n        content\n        reactors {\n          totalCount\n        }\n      }\n      timelineItems(\n        first: 100\n        itemTypes: [CONNECTED_EVENT, CROSS_REFERENCED_EVENT]\n      ) {\n        nodes {\n          __typename\n          ... on ConnectedEvent {\n            subject {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n          ... on CrossReferencedEvent {\n            willCloseTarget\n            source {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type DateTime = crate::gh::gql::custom_types::DateTime;
+    type URI = crate::gh::gql::custom_types::URI;
+    #[derive(Serialize)]
+    pub struct Variables {
+        pub org: String,
+        pub slug: String,
+        pub after: Option<String>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct UserFragment {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+        pub name: Option<String>,
+        #[serde(rename = "avatarUrl")]
+        pub avatar_url: URI,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "rateLimit")]
+        pub rate_limit: Option<TeamInfoRateLimit>,
+        pub organization: Option<TeamInfoOrganization>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct TeamInfoRateLimit {
+        pub cost: Int,
+        pub limit: Int,
+        pub remaining: Int,
+        #[serde(rename = "resetAt")]
+        pub reset_at: DateTime,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct TeamInfoOrganization {
+        pub team: Option<TeamInfoOrganizationTeam>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct TeamInfoOrganizationTeam {
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+        pub name: String,
+        pub slug: String,
+        pub members: TeamInfoOrganizationTeamMembers,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct TeamInfoOrganizationTeamMembers {
+        #[serde(rename = "pageInfo")]
+        pub page_info: TeamInfoOrganizationTeamMembersPageInfo,
+        pub nodes: Option<Vec<Option<TeamInfoOrganizationTeamMembersNodes>>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct TeamInfoOrganizationTeamMembersPageInfo {
+        #[serde(rename = "hasNextPage")]
+        pub has_next_page: Boolean,
+        #[serde(rename = "endCursor")]
+        pub end_cursor: Option<String>,
+    }
+    pub type TeamInfoOrganizationTeamMembersNodes = UserFragment;
+}
+impl graphql_client::GraphQLQuery for TeamInfo {
+    type Variables = team_info::Variables;
+    type ResponseData = team_info::ResponseData;
+    fn build_query(
+        variables: Self::Variables,
+    ) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: team_info::QUERY,
+            operation_name: team_info::OPERATION_NAME,
+        }
+    }
+}
+pub struct GetPullRequestInfo;
+pub mod get_pull_request_info {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "GetPullRequestInfo";
+    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nfragment SearchIssuesResults on SearchResultItemConnection {\n  issueCount\n  pageInfo {\n    hasNextPage\n    endCursor\n  }\n  nodes {\n    __typename\n    ... on PullRequest {\n      title\n      number\n      databaseId\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      totalCommentsCount\n      reviewRequests(first: 30) {\n        nodes {\n          requestedReviewer {\n            __typename\n            ...UserFragment\n            ... on Team {\n              combinedSlug\n            }\n          }\n        }\n      }\n      reviewDecision\n      reviews(first: 1) {\n        totalCount\n      }\n      latestReviews(first: 30) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n      createdAt\n      updatedAt\n      closedAt\n      mergedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n            }\n          }\n        }\n      }\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n      commenters: comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n    }\n    ... on Issue {\n      title\n      number\n      databaseId\n      url\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      state\n      createdAt\n      updatedAt\n      closedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n    }\n  }\n}\n\nquery SearchIssues($q: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  search(first: 100, query: $q, type: ISSUE, after: $after) {\n    ...SearchIssuesResults\n  }\n}\n\n# Runs up to 10 searches at once, each aliased as `uN` and enabled by setting\n# `$iN`. Must match `MAX_SEARCH_BATCH_SIZE` in gql.rs.\nquery BatchSearchIssues(\n  $q0: String! = \"\"\n  $i0: Boolean! = false\n  $q1: String! = \"\"\n  $i1: Boolean! = false\n  $q2: String! = \"\"\n  $i2: Boolean! = false\n  $q3: String! = \"\"\n  $i3: Boolean! = false\n  $q4: String! = \"\"\n  $i4: Boolean! = false\n  $q5: String! = \"\"\n  $i5: Boolean! = false\n  $q6: String! = \"\"\n  $i6: Boolean! = false\n  $q7: String! = \"\"\n  $i7: Boolean! = false\n  $q8: String! = \"\"\n  $i8: Boolean! = false\n  $q9: String! = \"\"\n  $i9: Boolean! = false\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  u0: search(first: 100, query: $q0, type: ISSUE) @include(if: $i0) {\n    ...SearchIssuesResults\n  }\n  u1: search(first: 100, query: $q1, type: ISSUE) @include(if: $i1) {\n    ...SearchIssuesResults\n  }\n  u2: search(first: 100, query: $q2, type: ISSUE) @include(if: $i2) {\n    ...SearchIssuesResults\n  }\n  u3: search(first: 100, query: $q3, type: ISSUE) @include(if: $i3) {\n    ...SearchIssuesResults\n  }\n  u4: search(first: 100, query: $q4, type: ISSUE) @include(if: $i4) {\n    ...SearchIssuesResults\n  }\n  u5: search(first: 100, query: $q5, type: ISSUE) @include(if: $i5) {\n    ...SearchIssuesResults\n  }\n  u6: search(first: 100, query: $q6, type: ISSUE) @include(if: $i6) {\n    ...SearchIssuesResults\n  }\n  u7: search(first: 100, query: $q7, type: ISSUE) @include(if: $i7) {\n    ...SearchIssuesResults\n  }\n  u8: search(first: 100, query: $q8, type: ISSUE) @include(if: $i8) {\n    ...SearchIssuesResults\n  }\n  u9: search(first: 100, query: $q9, type: ISSUE) @include(if: $i9) {\n    ...SearchIssuesResults\n  }\n}\n\nquery TeamInfo($org: String!, $slug: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  organization(login: $org) {\n    team(slug: $slug) {\n      databaseId\n      name\n      slug\n      members(first: 100, after: $after) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          ...UserFragment\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo(\n  $owner: String!\n  $repo: String!\n  $prid: Int!\n  $commentsAfter: String\n  $reviewsAfter: String\n  $participantsAfter: String\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100, after: $commentsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100, after: $reviewsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100, after: $participantsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n              contexts(first: 100) {\n                nodes {\n                  __typename\n                  ... on CheckRun {\n                    name\n                    status\n                    conclusion\n                    detailsUrl\n                  }\n                  ... on StatusContext {\n                    context\n                    state\n                    description\n                    targetUrl\n                  }\n                }\n              }\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment LinkedPullRequestFragment on PullRequest {\n  number\n  title\n  url\n  state\n  repository {\n    owner {\n      __typename\n      login\n    }\n    name\n  }\n}\n\nquery GetIssueInfo($owner: String!, $repo: String!, $number: Int!) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    issue(number: $number) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      comments {\n        totalCount\n      }\n      assignees(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      reactionGroups {\n        content\n        reactors {\n          totalCount\n        }\n      }\n      timelineItems(\n        first: 100\n        itemTypes: [CONNECTED_EVENT, CROSS_REFERENCED_EVENT]\n      ) {\n        nodes {\n          __typename\n          ... on ConnectedEvent {\n            subject {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n          ... on CrossReferencedEvent {\n            willCloseTarget\n            source {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type DateTime = crate::gh::gql::custom_types::DateTime;
+    type HTML = crate::gh::gql::custom_types::HTML;
+    type URI = crate::gh::gql::custom_types::URI;
+    #[derive(Debug)]
+    pub enum CheckConclusionState {
+        ACTION_REQUIRED,
+        CANCELLED,
+        FAILURE,
+        NEUTRAL,
+        SKIPPED,
+        STALE,
+        STARTUP_FAILURE,
+        SUCCESS,
+        TIMED_OUT,
+        Other(String),
+    }
+    impl ::serde::Serialize for CheckConclusionState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                CheckConclusionState::ACTION_REQUIRED => "ACTION_REQUIRED",
+                CheckConclusionState::CANCELLED => "CANCELLED",
+                CheckConclusionState::FAILURE => "FAILURE",
+                CheckConclusionState::NEUTRAL => "NEUTRAL",
+                CheckConclusionState::SKIPPED => "SKIPPED",
+                CheckConclusionState::STALE => "STALE",
+                CheckConclusionState::STARTUP_FAILURE => "STARTUP_FAILURE",
+                CheckConclusionState::SUCCESS => "SUCCESS",
+                CheckConclusionState::TIMED_OUT => "TIMED_OUT",
+                CheckConclusionState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for CheckConclusionState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "ACTION_REQUIRED" => Ok(CheckConclusionState::ACTION_REQUIRED),
+                "CANCELLED" => Ok(CheckConclusionState::CANCELLED),
+                "FAILURE" => Ok(CheckConclusionState::FAILURE),
+                "NEUTRAL" => Ok(CheckConclusionState::NEUTRAL),
+                "SKIPPED" => Ok(CheckConclusionState::SKIPPED),
+                "STALE" => Ok(CheckConclusionState::STALE),
+                "STARTUP_FAILURE" => Ok(CheckConclusionState::STARTUP_FAILURE),
+                "SUCCESS" => Ok(CheckConclusionState::SUCCESS),
+                "TIMED_OUT" => Ok(CheckConclusionState::TIMED_OUT),
+                _ => Ok(CheckConclusionState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum CheckStatusState {
+        COMPLETED,
+        IN_PROGRESS,
+        PENDING,
+        QUEUED,
+        REQUESTED,
+        WAITING,
+        Other(String),
+    }
+    impl ::serde::Serialize for CheckStatusState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                CheckStatusState::COMPLETED => "COMPLETED",
+                CheckStatusState::IN_PROGRESS => "IN_PROGRESS",
+                CheckStatusState::PENDING => "PENDING",
+                CheckStatusState::QUEUED => "QUEUED",
+                CheckStatusState::REQUESTED => "REQUESTED",
+                CheckStatusState::WAITING => "WAITING",
+                CheckStatusState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for CheckStatusState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "COMPLETED" => Ok(CheckStatusState::COMPLETED),
+                "IN_PROGRESS" => Ok(CheckStatusState::IN_PROGRESS),
+                "PENDING" => Ok(CheckStatusState::PENDING),
+                "QUEUED" => Ok(CheckStatusState::QUEUED),
+                "REQUESTED" => Ok(CheckStatusState::REQUESTED),
+                "WAITING" => Ok(CheckStatusState::WAITING),
+                _ => Ok(CheckStatusState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeQueueEntryState {
+        AWAITING_CHECKS,
+        LOCKED,
+        MERGEABLE,
+        QUEUED,
+        UNMERGEABLE,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeQueueEntryState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeQueueEntryState::AWAITING_CHECKS => "AWAITING_CHECKS",
+                MergeQueueEntryState::LOCKED => "LOCKED",
+                MergeQueueEntryState::MERGEABLE => "MERGEABLE",
+                MergeQueueEntryState::QUEUED => "QUEUED",
+                MergeQueueEntryState::UNMERGEABLE => "UNMERGEABLE",
+                MergeQueueEntryState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeQueueEntryState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "AWAITING_CHECKS" => Ok(MergeQueueEntryState::AWAITING_CHECKS),
+                "LOCKED" => Ok(MergeQueueEntryState::LOCKED),
+                "MERGEABLE" => Ok(MergeQueueEntryState::MERGEABLE),
+                "QUEUED" => Ok(MergeQueueEntryState::QUEUED),
+                "UNMERGEABLE" => Ok(MergeQueueEntryState::UNMERGEABLE),
+                _ => Ok(MergeQueueEntryState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeStateStatus {
+        BEHIND,
+        BLOCKED,
+        CLEAN,
+        DIRTY,
+        DRAFT,
+        HAS_HOOKS,
+        UNKNOWN,
+        UNSTABLE,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeStateStatus {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeStateStatus::BEHIND => "BEHIND",
+                MergeStateStatus::BLOCKED => "BLOCKED",
+                MergeStateStatus::CLEAN => "CLEAN",
+                MergeStateStatus::DIRTY => "DIRTY",
+                MergeStateStatus::DRAFT => "DRAFT",
+                MergeStateStatus::HAS_HOOKS => "HAS_HOOKS",
+                MergeStateStatus::UNKNOWN => "UNKNOWN",
+                MergeStateStatus::UNSTABLE => "UNSTABLE",
+                MergeStateStatus::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeStateStatus {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "BEHIND" => Ok(MergeStateStatus::BEHIND),
+                "BLOCKED" => Ok(MergeStateStatus::BLOCKED),
+                "CLEAN" => Ok(MergeStateStatus::CLEAN),
+                "DIRTY" => Ok(MergeStateStatus::DIRTY),
+                "DRAFT" => Ok(MergeStateStatus::DRAFT),
+                "HAS_HOOKS" => Ok(MergeStateStatus::HAS_HOOKS),
+                "UNKNOWN" => Ok(MergeStateStatus::UNKNOWN),
+                "UNSTABLE" => Ok(MergeStateStatus::UNSTABLE),
+                _ => Ok(MergeStateStatus::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MergeableState {
+        CONFLICTING,
+        MERGEABLE,
+        UNKNOWN,
+        Other(String),
+    }
+    impl ::serde::Serialize for MergeableState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MergeableState::CONFLICTING => "CONFLICTING",
+                MergeableState::MERGEABLE => "MERGEABLE",
+                MergeableState::UNKNOWN => "UNKNOWN",
+                MergeableState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MergeableState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CONFLICTING" => Ok(MergeableState::CONFLICTING),
+                "MERGEABLE" => Ok(MergeableState::MERGEABLE),
+                "UNKNOWN" => Ok(MergeableState::UNKNOWN),
+                _ => Ok(MergeableState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MilestoneState {
+        CLOSED,
+        OPEN,
+        Other(String),
+    }
+    impl ::serde::Serialize for MilestoneState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MilestoneState::CLOSED => "CLOSED",
+                MilestoneState::OPEN => "OPEN",
+                MilestoneState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MilestoneState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CLOSED" => Ok(MilestoneState::CLOSED),
+                "OPEN" => Ok(MilestoneState::OPEN),
+                _ => Ok(MilestoneState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum PullRequestReviewState {
+        APPROVED,
+        CHANGES_REQUESTED,
+        COMMENTED,
+        DISMISSED,
+        PENDING,
+        Other(String),
+    }
+    impl ::serde::Serialize for PullRequestReviewState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                PullRequestReviewState::APPROVED => "APPROVED",
+                PullRequestReviewState::CHANGES_REQUESTED => {
+                    "CHANGES_REQUESTED"
+                }
+                PullRequestReviewState::COMMENTED => "COMMENTED",
+                PullRequestReviewState::DISMISSED => "DISMISSED",
+                PullRequestReviewState::PENDING => "PENDING",
                 PullRequestReviewState::Other(ref s) => &s,
             })
         }
     }
-    impl<'de> ::serde::Deserialize<'de> for PullRequestReviewState {
+    impl<'de> ::serde::Deserialize<'de> for PullRequestReviewState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "APPROVED" => Ok(PullRequestReviewState::APPROVED),
+                "CHANGES_REQUESTED" => {
+                    Ok(PullRequestReviewState::CHANGES_REQUESTED)
+                }
+                "COMMENTED" => Ok(PullRequestReviewState::COMMENTED),
+                "DISMISSED" => Ok(PullRequestReviewState::DISMISSED),
+                "PENDING" => Ok(PullRequestReviewState::PENDING),
+                _ => Ok(PullRequestReviewState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum PullRequestState {
+        CLOSED,
+        MERGED,
+        OPEN,
+        Other(String),
+    }
+    impl ::serde::Serialize for PullRequestState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                PullRequestState::CLOSED => "CLOSED",
+                PullRequestState::MERGED => "MERGED",
+                PullRequestState::OPEN => "OPEN",
+                PullRequestState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for PullRequestState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CLOSED" => Ok(PullRequestState::CLOSED),
+                "MERGED" => Ok(PullRequestState::MERGED),
+                "OPEN" => Ok(PullRequestState::OPEN),
+                _ => Ok(PullRequestState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum StatusState {
+        ERROR,
+        EXPECTED,
+        FAILURE,
+        PENDING,
+        SUCCESS,
+        Other(String),
+    }
+    impl ::serde::Serialize for StatusState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                StatusState::ERROR => "ERROR",
+                StatusState::EXPECTED => "EXPECTED",
+                StatusState::FAILURE => "FAILURE",
+                StatusState::PENDING => "PENDING",
+                StatusState::SUCCESS => "SUCCESS",
+                StatusState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for StatusState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "ERROR" => Ok(StatusState::ERROR),
+                "EXPECTED" => Ok(StatusState::EXPECTED),
+                "FAILURE" => Ok(StatusState::FAILURE),
+                "PENDING" => Ok(StatusState::PENDING),
+                "SUCCESS" => Ok(StatusState::SUCCESS),
+                _ => Ok(StatusState::Other(s)),
+            }
+        }
+    }
+    #[derive(Serialize)]
+    pub struct Variables {
+        pub owner: String,
+        pub repo: String,
+        pub prid: Int,
+        #[serde(rename = "commentsAfter")]
+        pub comments_after: Option<String>,
+        #[serde(rename = "reviewsAfter")]
+        pub reviews_after: Option<String>,
+        #[serde(rename = "participantsAfter")]
+        pub participants_after: Option<String>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct UserFragment {
+        pub login: String,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+        pub name: Option<String>,
+        #[serde(rename = "avatarUrl")]
+        pub avatar_url: URI,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "rateLimit")]
+        pub rate_limit: Option<GetPullRequestInfoRateLimit>,
+        pub repository: Option<GetPullRequestInfoRepository>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRateLimit {
+        pub cost: Int,
+        pub limit: Int,
+        pub remaining: Int,
+        #[serde(rename = "resetAt")]
+        pub reset_at: DateTime,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepository {
+        #[serde(rename = "pullRequest")]
+        pub pull_request: Option<GetPullRequestInfoRepositoryPullRequest>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequest {
+        pub number: Int,
+        pub title: String,
+        #[serde(rename = "bodyHTML")]
+        pub body_html: HTML,
+        pub author: Option<GetPullRequestInfoRepositoryPullRequestAuthor>,
+        pub repository: GetPullRequestInfoRepositoryPullRequestRepository,
+        pub url: URI,
+        pub state: PullRequestState,
+        #[serde(rename = "isDraft")]
+        pub is_draft: Boolean,
+        pub mergeable: MergeableState,
+        #[serde(rename = "mergeStateStatus")]
+        pub merge_state_status: MergeStateStatus,
+        #[serde(rename = "baseRefName")]
+        pub base_ref_name: String,
+        #[serde(rename = "headRefName")]
+        pub head_ref_name: String,
+        #[serde(rename = "mergeQueueEntry")]
+        pub merge_queue_entry:
+            Option<GetPullRequestInfoRepositoryPullRequestMergeQueueEntry>,
+        pub milestone: Option<GetPullRequestInfoRepositoryPullRequestMilestone>,
+        pub labels: Option<GetPullRequestInfoRepositoryPullRequestLabels>,
+        #[serde(rename = "totalCommentsCount")]
+        pub total_comments_count: Option<Int>,
+        pub comments: GetPullRequestInfoRepositoryPullRequestComments,
+        pub reviews: Option<GetPullRequestInfoRepositoryPullRequestReviews>,
+        pub participants: GetPullRequestInfoRepositoryPullRequestParticipants,
+        pub commits: GetPullRequestInfoRepositoryPullRequestCommits,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetPullRequestInfoRepositoryPullRequestAuthor {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User(GetPullRequestInfoRepositoryPullRequestAuthorOnUser),
+    }
+    pub type GetPullRequestInfoRepositoryPullRequestAuthorOnUser = UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestRepository {
+        pub owner: GetPullRequestInfoRepositoryPullRequestRepositoryOwner,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestRepositoryOwner {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: GetPullRequestInfoRepositoryPullRequestRepositoryOwnerOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetPullRequestInfoRepositoryPullRequestRepositoryOwnerOn {
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestMergeQueueEntry {
+        pub position: Int,
+        pub state: MergeQueueEntryState,
+        #[serde(rename = "enqueuedAt")]
+        pub enqueued_at: DateTime,
+        #[serde(rename = "estimatedTimeToMerge")]
+        pub estimated_time_to_merge: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestMilestone {
+        pub title: String,
+        pub state: MilestoneState,
+        #[serde(rename = "dueOn")]
+        pub due_on: Option<DateTime>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestLabels {
+        pub nodes: Option<
+            Vec<Option<GetPullRequestInfoRepositoryPullRequestLabelsNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestLabelsNodes {
+        pub color: String,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestComments {
+        #[serde(rename = "pageInfo")]
+        pub page_info: GetPullRequestInfoRepositoryPullRequestCommentsPageInfo,
+        pub nodes: Option<
+            Vec<Option<GetPullRequestInfoRepositoryPullRequestCommentsNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommentsPageInfo {
+        #[serde(rename = "hasNextPage")]
+        pub has_next_page: Boolean,
+        #[serde(rename = "endCursor")]
+        pub end_cursor: Option<String>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommentsNodes {
+        pub author:
+            Option<GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthor>,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthor {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User(GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthorOnUser),
+    }
+    pub type GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthorOnUser =
+        UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestReviews {
+        #[serde(rename = "pageInfo")]
+        pub page_info: GetPullRequestInfoRepositoryPullRequestReviewsPageInfo,
+        pub nodes: Option<
+            Vec<Option<GetPullRequestInfoRepositoryPullRequestReviewsNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestReviewsPageInfo {
+        #[serde(rename = "hasNextPage")]
+        pub has_next_page: Boolean,
+        #[serde(rename = "endCursor")]
+        pub end_cursor: Option<String>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestReviewsNodes {
+        pub author:
+            Option<GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthor>,
+        pub state: PullRequestReviewState,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthor {
+        Bot,
+        EnterpriseUserAccount,
+        Mannequin,
+        Organization,
+        User(GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthorOnUser),
+    }
+    pub type GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthorOnUser =
+        UserFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestParticipants {
+        #[serde(rename = "pageInfo")]
+        pub page_info:
+            GetPullRequestInfoRepositoryPullRequestParticipantsPageInfo,
+        pub nodes: Option<
+            Vec<
+                Option<
+                    GetPullRequestInfoRepositoryPullRequestParticipantsNodes,
+                >,
+            >,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestParticipantsPageInfo {
+        #[serde(rename = "hasNextPage")]
+        pub has_next_page: Boolean,
+        #[serde(rename = "endCursor")]
+        pub end_cursor: Option<String>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestParticipantsNodes {
+        pub login: String,
+        pub name: Option<String>,
+        #[serde(rename = "avatarUrl")]
+        pub avatar_url: URI,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommits {
+        pub nodes: Option<
+            Vec<Option<GetPullRequestInfoRepositoryPullRequestCommitsNodes>>,
+        >,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommitsNodes {
+        pub commit: GetPullRequestInfoRepositoryPullRequestCommitsNodesCommit,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommitsNodesCommit { # [serde (rename = "statusCheckRollup")] pub status_check_rollup : Option < GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollup > , }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollup { pub state : StatusState , pub contexts : GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContexts , }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContexts { pub nodes : Option < Vec < Option < GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodes >> > , }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodes
+    {
+        CheckRun (GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodesOnCheckRun) , StatusContext (GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodesOnStatusContext) }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodesOnCheckRun
+    {
+        pub name: String,
+        pub status: CheckStatusState,
+        pub conclusion: Option<CheckConclusionState>,
+        #[serde(rename = "detailsUrl")]
+        pub details_url: Option<URI>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetPullRequestInfoRepositoryPullRequestCommitsNodesCommitStatusCheckRollupContextsNodesOnStatusContext
+    {
+        pub context: String,
+        pub state: StatusState,
+        pub description: Option<String>,
+        #[serde(rename = "targetUrl")]
+        pub target_url: Option<URI>,
+    }
+}
+impl graphql_client::GraphQLQuery for GetPullRequestInfo {
+    type Variables = get_pull_request_info::Variables;
+    type ResponseData = get_pull_request_info::ResponseData;
+    fn build_query(
+        variables: Self::Variables,
+    ) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: get_pull_request_info::QUERY,
+            operation_name: get_pull_request_info::OPERATION_NAME,
+        }
+    }
+}
+pub struct GetIssueInfo;
+pub mod get_issue_info {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "GetIssueInfo";
+    pub const QUERY : & str = "query UserInfo($login: String!) {\n  user(login: $login) {\n    name\n    login\n    avatarUrl\n    databaseId\n    pullRequests(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        totalCommentsCount\n        createdAt\n        updatedAt\n        isDraft\n        milestone {\n          id\n        }\n        repository {\n          owner {\n            __typename\n            login\n          }\n          name\n        }\n        reviewDecision\n        reviews(first: 1) {\n          totalCount\n        }\n      }\n    }\n    issues(first: 30, states: OPEN) {\n      nodes {\n        title\n        number\n        databaseId\n        updatedAt\n        author {\n          __typename\n          login\n        }\n        participants(first: 1) {\n          totalCount\n        }\n        assignees(first: 10) {\n          nodes {\n            login\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment UserFragment on User {\n  login\n  databaseId\n  name\n  avatarUrl\n}\n\nfragment SearchIssuesResults on SearchResultItemConnection {\n  issueCount\n  pageInfo {\n    hasNextPage\n    endCursor\n  }\n  nodes {\n    __typename\n    ... on PullRequest {\n      title\n      number\n      databaseId\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      totalCommentsCount\n      reviewRequests(first: 30) {\n        nodes {\n          requestedReviewer {\n            __typename\n            ...UserFragment\n            ... on Team {\n              combinedSlug\n            }\n          }\n        }\n      }\n      reviewDecision\n      reviews(first: 1) {\n        totalCount\n      }\n      latestReviews(first: 30) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n      createdAt\n      updatedAt\n      closedAt\n      mergedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n            }\n          }\n        }\n      }\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n      commenters: comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            login\n          }\n        }\n      }\n    }\n    ... on Issue {\n      title\n      number\n      databaseId\n      url\n      author {\n        __typename\n        ...UserFragment\n        ... on Bot {\n          login\n          databaseId\n        }\n        ... on Mannequin {\n          login\n          databaseId\n        }\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      state\n      createdAt\n      updatedAt\n      closedAt\n      assignees(first: 10) {\n        nodes {\n          login\n        }\n      }\n      comments(first: 100) {\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      bodyText\n      lastComment: comments(last: 1) {\n        totalCount\n        nodes {\n          author {\n            __typename\n            login\n          }\n          bodyText\n        }\n      }\n    }\n  }\n}\n\nquery SearchIssues($q: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  search(first: 100, query: $q, type: ISSUE, after: $after) {\n    ...SearchIssuesResults\n  }\n}\n\n# Runs up to 10 searches at once, each aliased as `uN` and enabled by setting\n# `$iN`. Must match `MAX_SEARCH_BATCH_SIZE` in gql.rs.\nquery BatchSearchIssues(\n  $q0: String! = \"\"\n  $i0: Boolean! = false\n  $q1: String! = \"\"\n  $i1: Boolean! = false\n  $q2: String! = \"\"\n  $i2: Boolean! = false\n  $q3: String! = \"\"\n  $i3: Boolean! = false\n  $q4: String! = \"\"\n  $i4: Boolean! = false\n  $q5: String! = \"\"\n  $i5: Boolean! = false\n  $q6: String! = \"\"\n  $i6: Boolean! = false\n  $q7: String! = \"\"\n  $i7: Boolean! = false\n  $q8: String! = \"\"\n  $i8: Boolean! = false\n  $q9: String! = \"\"\n  $i9: Boolean! = false\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  u0: search(first: 100, query: $q0, type: ISSUE) @include(if: $i0) {\n    ...SearchIssuesResults\n  }\n  u1: search(first: 100, query: $q1, type: ISSUE) @include(if: $i1) {\n    ...SearchIssuesResults\n  }\n  u2: search(first: 100, query: $q2, type: ISSUE) @include(if: $i2) {\n    ...SearchIssuesResults\n  }\n  u3: search(first: 100, query: $q3, type: ISSUE) @include(if: $i3) {\n    ...SearchIssuesResults\n  }\n  u4: search(first: 100, query: $q4, type: ISSUE) @include(if: $i4) {\n    ...SearchIssuesResults\n  }\n  u5: search(first: 100, query: $q5, type: ISSUE) @include(if: $i5) {\n    ...SearchIssuesResults\n  }\n  u6: search(first: 100, query: $q6, type: ISSUE) @include(if: $i6) {\n    ...SearchIssuesResults\n  }\n  u7: search(first: 100, query: $q7, type: ISSUE) @include(if: $i7) {\n    ...SearchIssuesResults\n  }\n  u8: search(first: 100, query: $q8, type: ISSUE) @include(if: $i8) {\n    ...SearchIssuesResults\n  }\n  u9: search(first: 100, query: $q9, type: ISSUE) @include(if: $i9) {\n    ...SearchIssuesResults\n  }\n}\n\nquery TeamInfo($org: String!, $slug: String!, $after: String) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  organization(login: $org) {\n    team(slug: $slug) {\n      databaseId\n      name\n      slug\n      members(first: 100, after: $after) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          ...UserFragment\n        }\n      }\n    }\n  }\n}\n\nquery GetPullRequestInfo(\n  $owner: String!\n  $repo: String!\n  $prid: Int!\n  $commentsAfter: String\n  $reviewsAfter: String\n  $participantsAfter: String\n) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    pullRequest(number: $prid) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      isDraft\n      mergeable\n      mergeStateStatus\n      baseRefName\n      headRefName\n      mergeQueueEntry {\n        position\n        state\n        enqueuedAt\n        estimatedTimeToMerge\n      }\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      totalCommentsCount\n      comments(first: 100, after: $commentsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n        }\n      }\n      reviews(first: 100, after: $reviewsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          author {\n            __typename\n            ...UserFragment\n          }\n          state\n        }\n      }\n      participants(first: 100, after: $participantsAfter) {\n        pageInfo {\n          hasNextPage\n          endCursor\n        }\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      commits(last: 1) {\n        nodes {\n          commit {\n            statusCheckRollup {\n              state\n              contexts(first: 100) {\n                nodes {\n                  __typename\n                  ... on CheckRun {\n                    name\n                    status\n                    conclusion\n                    detailsUrl\n                  }\n                  ... on StatusContext {\n                    context\n                    state\n                    description\n                    targetUrl\n                  }\n                }\n              }\n            }\n          }\n        }\n      }\n    }\n  }\n}\n\nfragment LinkedPullRequestFragment on PullRequest {\n  number\n  title\n  url\n  state\n  repository {\n    owner {\n      __typename\n      login\n    }\n    name\n  }\n}\n\nquery GetIssueInfo($owner: String!, $repo: String!, $number: Int!) {\n  rateLimit {\n    cost\n    limit\n    remaining\n    resetAt\n  }\n  repository(owner: $owner, name: $repo) {\n    issue(number: $number) {\n      number\n      title\n      bodyHTML\n      author {\n        __typename\n        ...UserFragment\n      }\n      repository {\n        owner {\n          __typename\n          login\n        }\n        name\n      }\n      url\n      state\n      milestone {\n        title\n        state\n        dueOn\n      }\n      labels(first: 100) {\n        nodes {\n          color\n          name\n        }\n      }\n      comments {\n        totalCount\n      }\n      assignees(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      participants(first: 100) {\n        nodes {\n          login\n          name\n          avatarUrl\n          databaseId\n        }\n      }\n      reactionGroups {\n        content\n        reactors {\n          totalCount\n        }\n      }\n      timelineItems(\n        first: 100\n        itemTypes: [CONNECTED_EVENT, CROSS_REFERENCED_EVENT]\n      ) {\n        nodes {\n          __typename\n          ... on ConnectedEvent {\n            subject {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n          ... on CrossReferencedEvent {\n            willCloseTarget\n            source {\n              __typename\n              ...LinkedPullRequestFragment\n            }\n          }\n        }\n      }\n    }\n  }\n}\n" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type DateTime = crate::gh::gql::custom_types::DateTime;
+    type HTML = crate::gh::gql::custom_types::HTML;
+    type URI = crate::gh::gql::custom_types::URI;
+    #[derive(Debug)]
+    pub enum IssueState {
+        CLOSED,
+        OPEN,
+        Other(String),
+    }
+    impl ::serde::Serialize for IssueState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                IssueState::CLOSED => "CLOSED",
+                IssueState::OPEN => "OPEN",
+                IssueState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for IssueState {
         fn deserialize<D: ::serde::Deserializer<'de>>(
             deserializer: D,
         ) -> Result<Self, D::Error> {
             let s: String = ::serde::Deserialize::deserialize(deserializer)?;
             match s.as_str() {
-                "APPROVED" => Ok(PullRequestReviewState::APPROVED),
-                "CHANGES_REQUESTED" => {
-                    Ok(PullRequestReviewState::CHANGES_REQUESTED)
-                }
-                "COMMENTED" => Ok(PullRequestReviewState::COMMENTED),
-                "DISMISSED" => Ok(PullRequestReviewState::DISMISSED),
-                "PENDING" => Ok(PullRequestReviewState::PENDING),
-                _ => Ok(PullRequestReviewState::Other(s)),
+                "CLOSED" => Ok(IssueState::CLOSED),
+                "OPEN" => Ok(IssueState::OPEN),
+                _ => Ok(IssueState::Other(s)),
+            }
+        }
+    }
+    #[derive(Debug)]
+    pub enum MilestoneState {
+        CLOSED,
+        OPEN,
+        Other(String),
+    }
+    impl ::serde::Serialize for MilestoneState {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                MilestoneState::CLOSED => "CLOSED",
+                MilestoneState::OPEN => "OPEN",
+                MilestoneState::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for MilestoneState {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CLOSED" => Ok(MilestoneState::CLOSED),
+                "OPEN" => Ok(MilestoneState::OPEN),
+                _ => Ok(MilestoneState::Other(s)),
             }
         }
     }
@@ -621,11 +2550,59 @@ pub mod get_pull_request_info {
             }
         }
     }
+    #[derive(Debug)]
+    pub enum ReactionContent {
+        CONFUSED,
+        EYES,
+        HEART,
+        HOORAY,
+        LAUGH,
+        ROCKET,
+        THUMBS_DOWN,
+        THUMBS_UP,
+        Other(String),
+    }
+    impl ::serde::Serialize for ReactionContent {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            ser: S,
+        ) -> Result<S::Ok, S::Error> {
+            ser.serialize_str(match *self {
+                ReactionContent::CONFUSED => "CONFUSED",
+                ReactionContent::EYES => "EYES",
+                ReactionContent::HEART => "HEART",
+                ReactionContent::HOORAY => "HOORAY",
+                ReactionContent::LAUGH => "LAUGH",
+                ReactionContent::ROCKET => "ROCKET",
+                ReactionContent::THUMBS_DOWN => "THUMBS_DOWN",
+                ReactionContent::THUMBS_UP => "THUMBS_UP",
+                ReactionContent::Other(ref s) => &s,
+            })
+        }
+    }
+    impl<'de> ::serde::Deserialize<'de> for ReactionContent {
+        fn deserialize<D: ::serde::Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Self, D::Error> {
+            let s: String = ::serde::Deserialize::deserialize(deserializer)?;
+            match s.as_str() {
+                "CONFUSED" => Ok(ReactionContent::CONFUSED),
+                "EYES" => Ok(ReactionContent::EYES),
+                "HEART" => Ok(ReactionContent::HEART),
+                "HOORAY" => Ok(ReactionContent::HOORAY),
+                "LAUGH" => Ok(ReactionContent::LAUGH),
+                "ROCKET" => Ok(ReactionContent::ROCKET),
+                "THUMBS_DOWN" => Ok(ReactionContent::THUMBS_DOWN),
+                "THUMBS_UP" => Ok(ReactionContent::THUMBS_UP),
+                _ => Ok(ReactionContent::Other(s)),
+            }
+        }
+    }
     #[derive(Serialize)]
     pub struct Variables {
         pub owner: String,
         pub repo: String,
-        pub prid: Int,
+        pub number: Int,
     }
     impl Variables {}
     #[derive(Deserialize, Debug)]
@@ -638,136 +2615,138 @@ pub mod get_pull_request_info {
         pub avatar_url: URI,
     }
     #[derive(Deserialize, Debug)]
+    pub struct LinkedPullRequestFragment {
+        pub number: Int,
+        pub title: String,
+        pub url: URI,
+        pub state: PullRequestState,
+        pub repository: LinkedPullRequestFragmentRepository,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LinkedPullRequestFragmentRepository {
+        pub owner: LinkedPullRequestFragmentRepositoryOwner,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LinkedPullRequestFragmentRepositoryOwner {
+        pub login: String,
+        #[serde(flatten)]
+        pub on: LinkedPullRequestFragmentRepositoryOwnerOn,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum LinkedPullRequestFragmentRepositoryOwnerOn {
+        Organization,
+        User,
+    }
+    #[derive(Deserialize, Debug)]
     pub struct ResponseData {
-        pub repository: Option<GetPullRequestInfoRepository>,
+        #[serde(rename = "rateLimit")]
+        pub rate_limit: Option<GetIssueInfoRateLimit>,
+        pub repository: Option<GetIssueInfoRepository>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepository {
-        #[serde(rename = "pullRequest")]
-        pub pull_request: Option<GetPullRequestInfoRepositoryPullRequest>,
+    pub struct GetIssueInfoRateLimit {
+        pub cost: Int,
+        pub limit: Int,
+        pub remaining: Int,
+        #[serde(rename = "resetAt")]
+        pub reset_at: DateTime,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequest {
+    pub struct GetIssueInfoRepository {
+        pub issue: Option<GetIssueInfoRepositoryIssue>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetIssueInfoRepositoryIssue {
         pub number: Int,
         pub title: String,
         #[serde(rename = "bodyHTML")]
         pub body_html: HTML,
-        pub author: Option<GetPullRequestInfoRepositoryPullRequestAuthor>,
-        pub repository: GetPullRequestInfoRepositoryPullRequestRepository,
+        pub author: Option<GetIssueInfoRepositoryIssueAuthor>,
+        pub repository: GetIssueInfoRepositoryIssueRepository,
         pub url: URI,
-        pub state: PullRequestState,
-        #[serde(rename = "isDraft")]
-        pub is_draft: Boolean,
-        pub milestone: Option<GetPullRequestInfoRepositoryPullRequestMilestone>,
-        pub labels: Option<GetPullRequestInfoRepositoryPullRequestLabels>,
-        #[serde(rename = "totalCommentsCount")]
-        pub total_comments_count: Option<Int>,
-        pub comments: GetPullRequestInfoRepositoryPullRequestComments,
-        pub reviews: Option<GetPullRequestInfoRepositoryPullRequestReviews>,
-        pub participants: GetPullRequestInfoRepositoryPullRequestParticipants,
+        pub state: IssueState,
+        pub milestone: Option<GetIssueInfoRepositoryIssueMilestone>,
+        pub labels: Option<GetIssueInfoRepositoryIssueLabels>,
+        pub comments: GetIssueInfoRepositoryIssueComments,
+        pub assignees: GetIssueInfoRepositoryIssueAssignees,
+        pub participants: GetIssueInfoRepositoryIssueParticipants,
+        #[serde(rename = "reactionGroups")]
+        pub reaction_groups:
+            Option<Vec<GetIssueInfoRepositoryIssueReactionGroups>>,
+        #[serde(rename = "timelineItems")]
+        pub timeline_items: GetIssueInfoRepositoryIssueTimelineItems,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum GetPullRequestInfoRepositoryPullRequestAuthor {
+    pub enum GetIssueInfoRepositoryIssueAuthor {
         Bot,
         EnterpriseUserAccount,
         Mannequin,
         Organization,
-        User(GetPullRequestInfoRepositoryPullRequestAuthorOnUser),
+        User(GetIssueInfoRepositoryIssueAuthorOnUser),
     }
-    pub type GetPullRequestInfoRepositoryPullRequestAuthorOnUser = UserFragment;
+    pub type GetIssueInfoRepositoryIssueAuthorOnUser = UserFragment;
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestRepository {
-        pub owner: GetPullRequestInfoRepositoryPullRequestRepositoryOwner,
+    pub struct GetIssueInfoRepositoryIssueRepository {
+        pub owner: GetIssueInfoRepositoryIssueRepositoryOwner,
         pub name: String,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestRepositoryOwner {
+    pub struct GetIssueInfoRepositoryIssueRepositoryOwner {
         pub login: String,
         #[serde(flatten)]
-        pub on: GetPullRequestInfoRepositoryPullRequestRepositoryOwnerOn,
+        pub on: GetIssueInfoRepositoryIssueRepositoryOwnerOn,
     }
     #[derive(Deserialize, Debug)]
     #[serde(tag = "__typename")]
-    pub enum GetPullRequestInfoRepositoryPullRequestRepositoryOwnerOn {
+    pub enum GetIssueInfoRepositoryIssueRepositoryOwnerOn {
         Organization,
         User,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestMilestone {
+    pub struct GetIssueInfoRepositoryIssueMilestone {
         pub title: String,
         pub state: MilestoneState,
         #[serde(rename = "dueOn")]
         pub due_on: Option<DateTime>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestLabels {
-        pub nodes: Option<
-            Vec<Option<GetPullRequestInfoRepositoryPullRequestLabelsNodes>>,
-        >,
+    pub struct GetIssueInfoRepositoryIssueLabels {
+        pub nodes: Option<Vec<Option<GetIssueInfoRepositoryIssueLabelsNodes>>>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestLabelsNodes {
+    pub struct GetIssueInfoRepositoryIssueLabelsNodes {
         pub color: String,
         pub name: String,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestComments {
-        pub nodes: Option<
-            Vec<Option<GetPullRequestInfoRepositoryPullRequestCommentsNodes>>,
-        >,
-    }
-    #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestCommentsNodes {
-        pub author:
-            Option<GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthor>,
-    }
-    #[derive(Deserialize, Debug)]
-    #[serde(tag = "__typename")]
-    pub enum GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthor {
-        Bot,
-        EnterpriseUserAccount,
-        Mannequin,
-        Organization,
-        User(GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthorOnUser),
-    }
-    pub type GetPullRequestInfoRepositoryPullRequestCommentsNodesAuthorOnUser =
-        UserFragment;
-    #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestReviews {
-        pub nodes: Option<
-            Vec<Option<GetPullRequestInfoRepositoryPullRequestReviewsNodes>>,
-        >,
+    pub struct GetIssueInfoRepositoryIssueComments {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestReviewsNodes {
-        pub author:
-            Option<GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthor>,
-        pub state: PullRequestReviewState,
+    pub struct GetIssueInfoRepositoryIssueAssignees {
+        pub nodes:
+            Option<Vec<Option<GetIssueInfoRepositoryIssueAssigneesNodes>>>,
     }
     #[derive(Deserialize, Debug)]
-    #[serde(tag = "__typename")]
-    pub enum GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthor {
-        Bot,
-        EnterpriseUserAccount,
-        Mannequin,
-        Organization,
-        User(GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthorOnUser),
+    pub struct GetIssueInfoRepositoryIssueAssigneesNodes {
+        pub login: String,
+        pub name: Option<String>,
+        #[serde(rename = "avatarUrl")]
+        pub avatar_url: URI,
+        #[serde(rename = "databaseId")]
+        pub database_id: Option<Int>,
     }
-    pub type GetPullRequestInfoRepositoryPullRequestReviewsNodesAuthorOnUser =
-        UserFragment;
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestParticipants {
-        pub nodes: Option<
-            Vec<
-                Option<
-                    GetPullRequestInfoRepositoryPullRequestParticipantsNodes,
-                >,
-            >,
-        >,
+    pub struct GetIssueInfoRepositoryIssueParticipants {
+        pub nodes:
+            Option<Vec<Option<GetIssueInfoRepositoryIssueParticipantsNodes>>>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct GetPullRequestInfoRepositoryPullRequestParticipantsNodes {
+    pub struct GetIssueInfoRepositoryIssueParticipantsNodes {
         pub login: String,
         pub name: Option<String>,
         #[serde(rename = "avatarUrl")]
@@ -775,17 +2754,88 @@ pub mod get_pull_request_info {
         #[serde(rename = "databaseId")]
         pub database_id: Option<Int>,
     }
+    #[derive(Deserialize, Debug)]
+    pub struct GetIssueInfoRepositoryIssueReactionGroups {
+        pub content: ReactionContent,
+        pub reactors: GetIssueInfoRepositoryIssueReactionGroupsReactors,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetIssueInfoRepositoryIssueReactionGroupsReactors {
+        #[serde(rename = "totalCount")]
+        pub total_count: Int,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetIssueInfoRepositoryIssueTimelineItems {
+        pub nodes:
+            Option<Vec<Option<GetIssueInfoRepositoryIssueTimelineItemsNodes>>>,
+    }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetIssueInfoRepositoryIssueTimelineItemsNodes {
+        AddedToProjectEvent,
+        AssignedEvent,
+        ClosedEvent,
+        CommentDeletedEvent,
+        ConnectedEvent(
+            GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEvent,
+        ),
+        ConvertedNoteToIssueEvent,
+        ConvertedToDiscussionEvent,
+        CrossReferencedEvent(
+            GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEvent,
+        ),
+        DemilestonedEvent,
+        DisconnectedEvent,
+        IssueComment,
+        LabeledEvent,
+        LockedEvent,
+        MarkedAsDuplicateEvent,
+        MentionedEvent,
+        MilestonedEvent,
+        MovedColumnsInProjectEvent,
+        PinnedEvent,
+        ReferencedEvent,
+        RemovedFromProjectEvent,
+        RenamedTitleEvent,
+        ReopenedEvent,
+        SubscribedEvent,
+        TransferredEvent,
+        UnassignedEvent,
+        UnlabeledEvent,
+        UnlockedEvent,
+        UnmarkedAsDuplicateEvent,
+        UnpinnedEvent,
+        UnsubscribedEvent,
+        UserBlockedEvent,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEvent { pub subject : GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEventSubject , }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEventSubject {
+        Issue , PullRequest (GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEventSubjectOnPullRequest) }
+    pub type GetIssueInfoRepositoryIssueTimelineItemsNodesOnConnectedEventSubjectOnPullRequest =
+        LinkedPullRequestFragment;
+    #[derive(Deserialize, Debug)]
+    pub struct GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEvent { # [serde (rename = "willCloseTarget")] pub will_close_target : Boolean , pub source : GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEventSource , }
+    #[derive(Deserialize, Debug)]
+    #[serde(tag = "__typename")]
+    pub enum GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEventSource
+    {
+        Issue , PullRequest (GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEventSourceOnPullRequest) }
+    pub type GetIssueInfoRepositoryIssueTimelineItemsNodesOnCrossReferencedEventSourceOnPullRequest =
+        LinkedPullRequestFragment;
 }
-impl graphql_client::GraphQLQuery for GetPullRequestInfo {
-    type Variables = get_pull_request_info::Variables;
-    type ResponseData = get_pull_request_info::ResponseData;
+impl graphql_client::GraphQLQuery for GetIssueInfo {
+    type Variables = get_issue_info::Variables;
+    type ResponseData = get_issue_info::ResponseData;
     fn build_query(
         variables: Self::Variables,
     ) -> ::graphql_client::QueryBody<Self::Variables> {
         graphql_client::QueryBody {
             variables,
-            query: get_pull_request_info::QUERY,
-            operation_name: get_pull_request_info::OPERATION_NAME,
+            query: get_issue_info::QUERY,
+            operation_name: get_issue_info::OPERATION_NAME,
         }
     }
 }
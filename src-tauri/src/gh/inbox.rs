@@ -0,0 +1,352 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use crate::{db::DB, errors::GHDError};
+
+use super::{
+    teams,
+    types::{
+        GithubUser, InboxItem, InboxReason, InboxReasonEntry, IssueTableEntry,
+    },
+};
+
+/// Open Pull Requests for which a review has been requested from the user,
+/// and which the user has not yet reviewed. Github drops the request once the
+/// review is submitted.
+///
+const REVIEW_REQUESTED_QUERY: &str = "
+    SELECT issues.*
+    FROM
+        issues JOIN pull_request_review_requests AS requests
+    ON
+        requests.pr_id = issues.id
+    WHERE
+        requests.login = ? COLLATE NOCASE
+        AND issues.state = 'open'
+        AND issues.archived_at IS NULL
+    ";
+
+/// Open Pull Requests authored by the user, on which changes were requested.
+///
+const CHANGES_REQUESTED_QUERY: &str = "
+    SELECT issues.*
+    FROM
+        issues JOIN pull_requests ON pull_requests.id = issues.id
+    WHERE
+        issues.author = ? COLLATE NOCASE
+        AND issues.state = 'open'
+        AND issues.archived_at IS NULL
+        AND pull_requests.review_decision = 'changes_requested'
+    ";
+
+/// Open Pull Requests authored by the user, whose checks are failing.
+///
+const CHECKS_FAILING_QUERY: &str = "
+    SELECT issues.*
+    FROM
+        issues JOIN pull_requests ON pull_requests.id = issues.id
+    WHERE
+        issues.author = ? COLLATE NOCASE
+        AND issues.state = 'open'
+        AND issues.archived_at IS NULL
+        AND pull_requests.checks_state IN ('failure', 'error')
+    ";
+
+/// Open Pull Requests for which a review has been requested from a team.
+///
+const TEAM_REVIEW_REQUESTED_QUERY: &str = "
+    SELECT issues.*
+    FROM
+        issues JOIN pull_request_team_review_requests AS requests
+    ON
+        requests.pr_id = issues.id
+    WHERE
+        requests.team = ? COLLATE NOCASE
+        AND issues.state = 'open'
+        AND issues.archived_at IS NULL
+    ";
+
+/// Open Issues and Pull Requests, not authored by the user, where the user
+/// has been mentioned, and which have not been viewed since they were last
+/// updated.
+///
+const MENTIONED_QUERY: &str = "
+    SELECT issues.*
+    FROM
+        issues JOIN issue_mentions AS mentions
+    ON
+        mentions.issue_id = issues.id
+    WHERE
+        mentions.login = ? COLLATE NOCASE
+        AND issues.author != ? COLLATE NOCASE
+        AND issues.state = 'open'
+        AND issues.archived_at IS NULL
+        AND (
+            issues.last_viewed IS NULL
+            OR issues.updated_at > issues.last_viewed
+        )
+    ";
+
+/// Pull Requests associated with the user that have been updated since the
+/// user last viewed them.
+///
+const UPDATED_QUERY: &str = "
+    SELECT issues.*
+    FROM
+        issues JOIN user_issues ON user_issues.issue_id = issues.id
+    WHERE
+        user_issues.user_id = (
+            SELECT id FROM users WHERE login = ?
+        )
+        AND issues.is_pull_request = True
+        AND issues.archived_at IS NULL
+        AND issues.last_viewed IS NOT NULL
+        AND issues.updated_at > issues.last_viewed
+    ";
+
+/// Obtain a human readable explanation for the provided `reason`.
+///
+fn explain(reason: &InboxReason) -> String {
+    String::from(match reason {
+        InboxReason::ReviewRequested => "your review has been requested",
+        InboxReason::ChangesRequested => "changes have been requested",
+        InboxReason::ChecksFailing => "checks are failing",
+        InboxReason::TeamReviewRequested => {
+            "a team's review has been requested"
+        }
+        InboxReason::Mentioned => "you have been mentioned",
+        InboxReason::Updated => "updated since you last viewed it",
+    })
+}
+
+/// Obtain the Issues matching `query`, a query for Issues that need attention
+/// for the provided `reason`.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `reason` - The reason the query is looking for.
+/// * `query` - The query to run.
+/// * `args` - The arguments to bind to the query, in order.
+///
+async fn get_reason_issues(
+    db: &DB,
+    reason: &InboxReason,
+    query: &str,
+    args: &[&String],
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    let mut q = sqlx::query_as::<_, IssueTableEntry>(query);
+    for arg in args {
+        q = q.bind(*arg);
+    }
+    match q.fetch_all(db.pool()).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining inbox issues for reason {:?}", reason),
+            err,
+        )),
+    }
+}
+
+/// Group the provided candidates by Issue, ranking the resulting items by
+/// their most important reason, and then by most recently updated.
+///
+/// # Arguments
+///
+/// * `candidates` - Reasons, their explanations, and the Issues they apply to.
+///
+fn rank_items(
+    candidates: Vec<(InboxReason, String, Vec<IssueTableEntry>)>,
+) -> Vec<InboxItem> {
+    let mut items: HashMap<i64, InboxItem> = HashMap::new();
+    for (reason, explanation, issues) in candidates {
+        for issue in issues {
+            let entry = InboxReasonEntry {
+                reason,
+                explanation: explanation.clone(),
+            };
+            match items.get_mut(&issue.id) {
+                Some(item) => item.reasons.push(entry),
+                None => {
+                    items.insert(
+                        issue.id,
+                        InboxItem {
+                            issue,
+                            reasons: vec![entry],
+                        },
+                    );
+                }
+            };
+        }
+    }
+
+    let mut res: Vec<InboxItem> = items
+        .into_values()
+        .map(|mut item| {
+            item.reasons.sort_by_key(|r| r.reason);
+            item
+        })
+        .collect();
+    res.sort_by(|a, b| {
+        a.reasons[0]
+            .reason
+            .cmp(&b.reasons[0].reason)
+            .then(b.issue.updated_at.cmp(&a.issue.updated_at))
+    });
+    res
+}
+
+/// Obtain the Issues and Pull Requests needing the provided user's attention,
+/// computed from what is currently in the database. Items are ranked by their
+/// most important reason.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `user` - The user to obtain the inbox for, usually the main user.
+///
+pub async fn get_inbox(
+    db: &DB,
+    user: &GithubUser,
+) -> Result<Vec<InboxItem>, GHDError> {
+    let login = &user.login;
+    let mut candidates: Vec<(InboxReason, String, Vec<IssueTableEntry>)> =
+        vec![];
+
+    for (reason, query, nargs) in [
+        (InboxReason::ReviewRequested, REVIEW_REQUESTED_QUERY, 1),
+        (InboxReason::ChangesRequested, CHANGES_REQUESTED_QUERY, 1),
+        (InboxReason::ChecksFailing, CHECKS_FAILING_QUERY, 1),
+        (InboxReason::Mentioned, MENTIONED_QUERY, 2),
+        (InboxReason::Updated, UPDATED_QUERY, 1),
+    ] {
+        let args = vec![login; nargs];
+        match get_reason_issues(&db, &reason, query, &args).await {
+            Ok(res) => candidates.push((reason, explain(&reason), res)),
+            Err(err) => return Err(err),
+        };
+    }
+
+    let user_teams = match teams::get_user_teams(&db, &user.id).await {
+        Ok(res) => res,
+        Err(err) => return Err(err),
+    };
+    for team in &user_teams {
+        let reason = InboxReason::TeamReviewRequested;
+        let slug = team.combined_slug();
+        match get_reason_issues(
+            &db,
+            &reason,
+            TEAM_REVIEW_REQUESTED_QUERY,
+            &[&slug],
+        )
+        .await
+        {
+            Ok(res) => candidates.push((
+                reason,
+                format!("review requested from team '{}'", slug),
+                res,
+            )),
+            Err(err) => return Err(err),
+        };
+    }
+
+    Ok(rank_items(candidates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(id: i64, updated_at: i64) -> IssueTableEntry {
+        IssueTableEntry {
+            id,
+            number: id,
+            title: format!("issue {}", id),
+            author: String::from("author"),
+            author_id: 1,
+            url: format!("https://github.com/owner/repo/issues/{}", id),
+            repo_owner: String::from("owner"),
+            repo_name: String::from("repo"),
+            state: String::from("open"),
+            created_at: 0,
+            updated_at,
+            closed_at: None,
+            is_pull_request: true,
+            last_viewed: None,
+        }
+    }
+
+    fn candidate(
+        reason: InboxReason,
+        issues: Vec<IssueTableEntry>,
+    ) -> (InboxReason, String, Vec<IssueTableEntry>) {
+        (reason, explain(&reason), issues)
+    }
+
+    #[test]
+    fn reasons_are_grouped_by_issue() {
+        let items = rank_items(vec![
+            candidate(InboxReason::Updated, vec![issue(1, 10), issue(2, 20)]),
+            candidate(InboxReason::Mentioned, vec![issue(1, 10)]),
+            candidate(InboxReason::ReviewRequested, vec![issue(1, 10)]),
+        ]);
+        assert_eq!(items.len(), 2);
+
+        let item = &items[0];
+        assert_eq!(item.issue.id, 1);
+        let reasons: Vec<InboxReason> =
+            item.reasons.iter().map(|r| r.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                InboxReason::ReviewRequested,
+                InboxReason::Mentioned,
+                InboxReason::Updated,
+            ]
+        );
+        assert_eq!(
+            item.reasons[0].explanation,
+            explain(&InboxReason::ReviewRequested)
+        );
+
+        assert_eq!(items[1].issue.id, 2);
+        assert_eq!(items[1].reasons.len(), 1);
+    }
+
+    #[test]
+    fn items_are_ranked_by_top_reason_then_update() {
+        let items = rank_items(vec![
+            candidate(InboxReason::Updated, vec![issue(1, 50), issue(2, 40)]),
+            candidate(
+                InboxReason::ChecksFailing,
+                vec![issue(3, 10), issue(4, 30)],
+            ),
+            candidate(InboxReason::ReviewRequested, vec![issue(5, 20)]),
+            // ranked by its most important reason only.
+            candidate(InboxReason::Mentioned, vec![issue(2, 40)]),
+        ]);
+        let ids: Vec<i64> = items.iter().map(|i| i.issue.id).collect();
+        assert_eq!(ids, vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn no_candidates_no_items() {
+        assert!(rank_items(vec![]).is_empty());
+        assert!(rank_items(vec![candidate(InboxReason::Updated, vec![])])
+            .is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{db::DB, errors::GHDError};
+
+use super::types::{Involvement, IssueTableEntry};
+
+/// Obtain all Issues from the provided author `login`. Pull Requests are not
+/// included.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - The author's login.
+///
+pub async fn get_issues_by_author(
+    db: &DB,
+    login: &String,
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    match sqlx::query_as::<_, IssueTableEntry>(
+        "
+        SELECT
+            issues.*
+        FROM
+            issues
+        WHERE
+            issues.is_pull_request = False
+            AND
+            issues.author = ?
+            AND
+            issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&login)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining issues by author", err)),
+    }
+}
+
+/// Obtain all Issues the provided user `login` is involved with, except those
+/// authored by `login`. This means mentions, assignments, or where the user may
+/// have commented.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - The user's login.
+///
+pub async fn get_involved_issues(
+    db: &DB,
+    login: &String,
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    match sqlx::query_as::<_, IssueTableEntry>(
+        "
+        SELECT
+            issues.*
+        FROM
+            issues LEFT JOIN user_issues
+        ON
+            issues.id = user_issues.issue_id
+        WHERE
+            user_issues.user_id = (
+                SELECT id FROM users WHERE login = ?
+            )
+            AND issues.is_pull_request = False
+            AND issues.archived_at IS NULL
+            AND issues.author != ?
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&login)
+    .bind(&login)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining involved issues", err)),
+    }
+}
+
+/// Obtain all Issues the provided user `login` is involved with in the
+/// specified way; e.g., where the user has been mentioned.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - The user's login.
+/// * `involvement` - How the user must be involved with the Issues.
+///
+pub async fn get_issues_by_involvement(
+    db: &DB,
+    login: &String,
+    involvement: &Involvement,
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    match sqlx::query_as::<_, IssueTableEntry>(
+        "
+        SELECT
+            issues.*
+        FROM
+            issues INNER JOIN user_issues
+        ON
+            issues.id = user_issues.issue_id
+        WHERE
+            user_issues.user_id = (
+                SELECT id FROM users WHERE login = ?
+            )
+            AND user_issues.involvement & ? != 0
+            AND issues.is_pull_request = False
+            AND issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&login)
+    .bind(&involvement.bit())
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining issues by involvement", err)),
+    }
+}
+
+/// Obtain all Issues assigned to the provided user `login`.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - The assignee's login.
+///
+pub async fn get_assigned_issues(
+    db: &DB,
+    login: &String,
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    match sqlx::query_as::<_, IssueTableEntry>(
+        "
+        SELECT
+            issues.*
+        FROM
+            issues INNER JOIN issue_assignees
+        ON
+            issues.id = issue_assignees.issue_id
+        WHERE
+            issue_assignees.login = ?
+            AND issues.is_pull_request = False
+            AND issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&login)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining assigned issues", err)),
+    }
+}
@@ -0,0 +1,495 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use log::warn;
+
+use crate::{common, db::DB, errors::GHDError};
+
+use super::types::{
+    CheckInfo, GithubUser, Label, MergeQueueEntry, Milestone, PullRequestInfo,
+    PullRequestInfoTableEntry, PullRequestUserTableEntry, UserReview,
+};
+
+/// Obtain a `GithubUser` from a cached Pull Request user entry.
+///
+fn user_entry_to_user(entry: &PullRequestUserTableEntry) -> GithubUser {
+    GithubUser {
+        id: entry.user_id,
+        login: entry.login.clone(),
+        name: entry.name.clone(),
+        avatar_url: entry.avatar_url.clone(),
+    }
+}
+
+/// Obtain the cached information for the Pull Request with database ID `prid`,
+/// if any, along with the timestamp at which it was fetched from Github.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `prid` - The Pull Request's database ID.
+///
+pub async fn get_cached_info(
+    db: &DB,
+    prid: &i64,
+) -> Result<Option<(PullRequestInfo, i64)>, GHDError> {
+    let entry = match sqlx::query_as::<_, PullRequestInfoTableEntry>(
+        "SELECT * FROM pull_request_info WHERE id = ?",
+    )
+    .bind(&prid)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => res,
+        Err(sqlx::Error::RowNotFound) => return Ok(None),
+        Err(err) => {
+            return Err(GHDError::db(
+                "obtaining cached pull request info",
+                err,
+            ));
+        }
+    };
+
+    let labels = match sqlx::query_as::<_, (String, String)>(
+        "SELECT name, color FROM pull_request_labels WHERE pr_id = ?",
+    )
+    .bind(&prid)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => res
+            .into_iter()
+            .map(|(name, color)| Label { name, color })
+            .collect(),
+        Err(err) => {
+            return Err(GHDError::db(
+                "obtaining cached pull request labels",
+                err,
+            ));
+        }
+    };
+
+    let participants = match sqlx::query_as::<_, PullRequestUserTableEntry>(
+        "
+        SELECT
+            user_id, login, name, avatar_url, NULL AS state
+        FROM
+            pull_request_participants
+        WHERE
+            pr_id = ?
+        ",
+    )
+    .bind(&prid)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => res.iter().map(user_entry_to_user).collect(),
+        Err(err) => {
+            return Err(GHDError::db(
+                "obtaining cached pull request participants",
+                err,
+            ));
+        }
+    };
+
+    let reviews = match sqlx::query_as::<_, PullRequestUserTableEntry>(
+        "
+        SELECT
+            user_id, login, name, avatar_url, state
+        FROM
+            pull_request_reviews
+        WHERE
+            pr_id = ?
+        ORDER BY seq ASC
+        ",
+    )
+    .bind(&prid)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => res
+            .iter()
+            .map(|e| UserReview {
+                author: user_entry_to_user(&e),
+                state: e.state.clone().unwrap_or(String::from("unknown")),
+            })
+            .collect(),
+        Err(err) => {
+            return Err(GHDError::db(
+                "obtaining cached pull request reviews",
+                err,
+            ));
+        }
+    };
+
+    let checks = match sqlx::query_as::<_, CheckInfo>(
+        "
+        SELECT
+            name, state, description, url
+        FROM
+            pull_request_checks
+        WHERE
+            pr_id = ?
+        ORDER BY seq ASC
+        ",
+    )
+    .bind(&prid)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                "obtaining cached pull request checks",
+                err,
+            ));
+        }
+    };
+
+    let milestone = match &entry.milestone_title {
+        None => None,
+        Some(title) => {
+            let due_on = match entry.milestone_due_on {
+                None => None,
+                Some(ts) => common::ts_to_datetime(ts).ok(),
+            };
+            Some(Milestone {
+                title: title.clone(),
+                state: entry
+                    .milestone_state
+                    .clone()
+                    .unwrap_or(String::from("unknown")),
+                due_on,
+                due_on_ts: entry.milestone_due_on,
+            })
+        }
+    };
+
+    let merge_queue = match (
+        entry.merge_queue_position,
+        &entry.merge_queue_state,
+        entry.merge_queue_enqueued_at,
+    ) {
+        (Some(position), Some(state), Some(ts)) => {
+            match common::ts_to_datetime(ts) {
+                Ok(enqueued_at) => Some(MergeQueueEntry {
+                    position,
+                    state: state.clone(),
+                    enqueued_at,
+                    enqueued_at_ts: ts,
+                    estimated_time_to_merge: entry.merge_queue_eta,
+                }),
+                Err(_) => None,
+            }
+        }
+        _ => None,
+    };
+
+    let info = PullRequestInfo {
+        number: entry.number,
+        title: entry.title,
+        body_html: entry.body_html,
+        author: GithubUser {
+            id: entry.author_id,
+            login: entry.author_login,
+            name: entry.author_name,
+            avatar_url: entry.author_avatar_url,
+        },
+        repo_owner: entry.repo_owner,
+        repo_name: entry.repo_name,
+        url: entry.url,
+        state: entry.state,
+        is_draft: entry.is_draft,
+        milestone,
+        labels,
+        total_comments: entry.total_comments,
+        participants,
+        reviews,
+        checks_state: entry.checks_state,
+        checks,
+        mergeable: entry.mergeable.unwrap_or(String::from("unknown")),
+        merge_state_status: entry
+            .merge_state_status
+            .unwrap_or(String::from("unknown")),
+        base_ref_name: entry.base_ref_name.unwrap_or_default(),
+        head_ref_name: entry.head_ref_name.unwrap_or_default(),
+        merge_queue,
+    };
+
+    Ok(Some((info, entry.fetched_at)))
+}
+
+/// Store the provided Pull Request information in the database, replacing any
+/// previously cached information for the Pull Request with database ID `prid`.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `prid` - The Pull Request's database ID.
+/// * `info` - The Pull Request's information, as obtained from Github.
+///
+pub async fn store_info(
+    db: &DB,
+    prid: &i64,
+    info: &PullRequestInfo,
+) -> Result<(), GHDError> {
+    let mut tx = match db.begin().await {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                "starting transaction to store pull request info",
+                err,
+            ));
+        }
+    };
+
+    match _store_info(&mut tx, prid, info).await {
+        Ok(_) => {}
+        Err(err) => {
+            if let Err(rb_err) = tx.rollback().await {
+                warn!("Unable to rollback broken transaction: {}", rb_err);
+            }
+            return Err(err);
+        }
+    };
+
+    match tx.commit().await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            "committing transaction to store pull request info",
+            err,
+        )),
+    }
+}
+
+/// Store the provided Pull Request information as part of transaction `tx`.
+///
+async fn _store_info(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    prid: &i64,
+    info: &PullRequestInfo,
+) -> Result<(), GHDError> {
+    let now = chrono::Utc::now().timestamp();
+
+    for table in [
+        "pull_request_labels",
+        "pull_request_participants",
+        "pull_request_reviews",
+        "pull_request_checks",
+    ] {
+        match sqlx::query(&format!("DELETE FROM {} WHERE pr_id = ?", table))
+            .bind(&prid)
+            .execute(&mut *tx)
+            .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("clearing cached '{}' for pr {}", table, prid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    let (milestone_title, milestone_state, milestone_due_on) = match &info
+        .milestone
+    {
+        None => (None, None, None),
+        Some(m) => (Some(m.title.clone()), Some(m.state.clone()), m.due_on_ts),
+    };
+
+    match sqlx::query(
+        "
+        INSERT OR REPLACE INTO pull_request_info (
+            id, number, title, body_html,
+            author_id, author_login, author_name, author_avatar_url,
+            repo_owner, repo_name, url, state, is_draft,
+            milestone_title, milestone_state, milestone_due_on,
+            total_comments, checks_state,
+            mergeable, merge_state_status, base_ref_name, head_ref_name,
+            merge_queue_position, merge_queue_state,
+            merge_queue_enqueued_at, merge_queue_eta,
+            fetched_at
+        ) VALUES (
+            ?, ?, ?, ?,
+            ?, ?, ?, ?,
+            ?, ?, ?, ?, ?,
+            ?, ?, ?,
+            ?, ?,
+            ?, ?, ?, ?,
+            ?, ?,
+            ?, ?,
+            ?
+        )
+        ",
+    )
+    .bind(&prid)
+    .bind(&info.number)
+    .bind(&info.title)
+    .bind(&info.body_html)
+    .bind(&info.author.id)
+    .bind(&info.author.login)
+    .bind(&info.author.name)
+    .bind(&info.author.avatar_url)
+    .bind(&info.repo_owner)
+    .bind(&info.repo_name)
+    .bind(&info.url)
+    .bind(&info.state)
+    .bind(&info.is_draft)
+    .bind(&milestone_title)
+    .bind(&milestone_state)
+    .bind(&milestone_due_on)
+    .bind(&info.total_comments)
+    .bind(&info.checks_state)
+    .bind(&info.mergeable)
+    .bind(&info.merge_state_status)
+    .bind(&info.base_ref_name)
+    .bind(&info.head_ref_name)
+    .bind(info.merge_queue.as_ref().map(|q| q.position))
+    .bind(info.merge_queue.as_ref().map(|q| q.state.clone()))
+    .bind(info.merge_queue.as_ref().map(|q| q.enqueued_at_ts))
+    .bind(
+        info.merge_queue
+            .as_ref()
+            .and_then(|q| q.estimated_time_to_merge),
+    )
+    .bind(&now)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("caching info for pr {}", prid),
+                err,
+            ));
+        }
+    };
+
+    for label in &info.labels {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO pull_request_labels (
+                pr_id, name, color
+            ) VALUES (
+                ?, ?, ?
+            )
+            ",
+        )
+        .bind(&prid)
+        .bind(&label.name)
+        .bind(&label.color)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("caching labels for pr {}", prid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    for user in &info.participants {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO pull_request_participants (
+                pr_id, user_id, login, name, avatar_url
+            ) VALUES (
+                ?, ?, ?, ?, ?
+            )
+            ",
+        )
+        .bind(&prid)
+        .bind(&user.id)
+        .bind(&user.login)
+        .bind(&user.name)
+        .bind(&user.avatar_url)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("caching participants for pr {}", prid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    for (seq, review) in info.reviews.iter().enumerate() {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO pull_request_reviews (
+                pr_id, seq, user_id, login, name, avatar_url, state
+            ) VALUES (
+                ?, ?, ?, ?, ?, ?, ?
+            )
+            ",
+        )
+        .bind(&prid)
+        .bind(seq as i64)
+        .bind(&review.author.id)
+        .bind(&review.author.login)
+        .bind(&review.author.name)
+        .bind(&review.author.avatar_url)
+        .bind(&review.state)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("caching reviews for pr {}", prid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    for (seq, check) in info.checks.iter().enumerate() {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO pull_request_checks (
+                pr_id, seq, name, state, description, url
+            ) VALUES (
+                ?, ?, ?, ?, ?, ?
+            )
+            ",
+        )
+        .bind(&prid)
+        .bind(seq as i64)
+        .bind(&check.name)
+        .bind(&check.state)
+        .bind(&check.description)
+        .bind(&check.url)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("caching checks for pr {}", prid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    Ok(())
+}
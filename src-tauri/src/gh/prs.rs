@@ -12,14 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use log::info;
+use std::collections::HashSet;
+
+use log::{debug, info, warn};
 
 use crate::{common, db::DB, errors::GHDError};
 
+use super::sync;
 use super::types::{
-    Issue, IssueTableEntry, PullRequest, PullRequestTableEntry,
+    GithubTeam, GithubUser, Involvement, Issue, IssueChangeFields,
+    IssueTableEntry, IssuesChangeset, PullRequest, PullRequestTableEntry,
 };
 
+/// Involvement that, once observed, is kept for as long as the user is linked
+/// to the issue. A refresh only sees the first page of comments, the latest
+/// reviews, and the last comment's mentions.
+///
+const STICKY_INVOLVEMENT: i64 = Involvement::Mentioned as i64
+    | Involvement::Commenter as i64
+    | Involvement::Reviewed as i64;
+
 /// Obtain all Pull Requests from the database.
 ///
 pub async fn get_all_prs_from_db(
@@ -29,7 +41,13 @@ pub async fn get_all_prs_from_db(
         "
         SELECT
             issues.*, pull_requests.is_draft, pull_requests.review_decision,
-            pull_requests.merged_at
+            pull_requests.merged_at, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
         FROM
             pull_requests LEFT JOIN issues
         ON
@@ -40,9 +58,7 @@ pub async fn get_all_prs_from_db(
     .await
     {
         Ok(res) => Ok(res),
-        Err(err) => {
-            panic!("Unable to obtain pull requests from db: {}", err);
-        }
+        Err(err) => Err(GHDError::db("obtaining pull requests", err)),
     }
 }
 
@@ -56,7 +72,13 @@ pub async fn get_prs_by_author(
         "
         SELECT
             issues.*, pull_requests.is_draft, pull_requests.review_decision,
-            pull_requests.merged_at
+            pull_requests.merged_at, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
         FROM
             pull_requests LEFT JOIN issues
         ON
@@ -73,9 +95,7 @@ pub async fn get_prs_by_author(
     .await
     {
         Ok(res) => Ok(res),
-        Err(err) => {
-            panic!("Unable to obtain pull requests from db: {}", err);
-        }
+        Err(err) => Err(GHDError::db("obtaining pull requests", err)),
     }
 }
 
@@ -91,7 +111,13 @@ pub async fn get_involved_prs(
         "
         SELECT
             issues.*, pull_requests.is_draft, pull_requests.merged_at,
-            pull_requests.review_decision
+            pull_requests.review_decision, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
         FROM pull_requests INNER JOIN (
             SELECT
                 issues.*
@@ -115,15 +141,65 @@ pub async fn get_involved_prs(
     .bind(&login)
     .fetch_all(db.pool())
     .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining involved pull requests", err)),
+    }
+}
+
+/// Obtain all Pull Requests the provided user `login` is involved with in the
+/// specified way; e.g., those for which the user's review has been requested.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - The user's login.
+/// * `involvement` - How the user must be involved with the Pull Requests.
+///
+pub async fn get_prs_by_involvement(
+    db: &DB,
+    login: &String,
+    involvement: &Involvement,
+) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+    match sqlx::query_as::<_, PullRequestTableEntry>(
+        "
+        SELECT
+            issues.*, pull_requests.is_draft, pull_requests.merged_at,
+            pull_requests.review_decision, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
+        FROM
+            pull_requests
+            INNER JOIN issues ON pull_requests.id = issues.id
+            INNER JOIN user_issues ON issues.id = user_issues.issue_id
+        WHERE
+            user_issues.user_id = (
+                SELECT id FROM users WHERE login = ?
+            )
+            AND user_issues.involvement & ? != 0
+            AND issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&login)
+    .bind(&involvement.bit())
+    .fetch_all(db.pool())
+    .await
     {
         Ok(res) => Ok(res),
         Err(err) => {
-            panic!("Unable to obtain data from database: {}", err);
+            Err(GHDError::db("obtaining pull requests by involvement", err))
         }
     }
 }
 
-/// Insert the given issue into the database.
+/// Insert the given issue into the database, or update it if it already
+/// exists. An existing issue keeps when it was last viewed, unless `issue`
+/// says otherwise.
 ///
 async fn consume_issue(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
@@ -131,19 +207,35 @@ async fn consume_issue(
 ) -> Result<(), GHDError> {
     match sqlx::query(
         "
-        INSERT OR REPLACE INTO issues (
+        INSERT INTO issues (
             id, number, title, author, author_id,
             url, repo_owner, repo_name, state,
             created_at, updated_at, closed_at,
             is_pull_request,
-            last_viewed
+            last_viewed, total_comments
         ) VALUES (
             ?, ?, ?, ?, ?,
             ?, ?, ?, ?,
             ?, ?, ?,
             ?,
-            ?
+            ?, ?
         )
+        ON CONFLICT (id) DO UPDATE SET
+            number = excluded.number,
+            title = excluded.title,
+            author = excluded.author,
+            author_id = excluded.author_id,
+            url = excluded.url,
+            repo_owner = excluded.repo_owner,
+            repo_name = excluded.repo_name,
+            state = excluded.state,
+            created_at = excluded.created_at,
+            updated_at = excluded.updated_at,
+            closed_at = excluded.closed_at,
+            is_pull_request = excluded.is_pull_request,
+            last_viewed = COALESCE(excluded.last_viewed, issues.last_viewed),
+            archived_at = NULL,
+            total_comments = excluded.total_comments
         ",
     )
     .bind(&issue.id)
@@ -160,105 +252,787 @@ async fn consume_issue(
     .bind(common::dt_opt_to_ts(&issue.closed_at))
     .bind(&issue.is_pull_request)
     .bind(common::dt_opt_to_ts(&issue.last_viewed))
+    .bind(&issue.total_comments)
     .execute(&mut *tx)
     .await
     {
         Ok(_) => {}
         Err(err) => {
-            panic!("Unable to consume issue: {}", err);
+            return Err(GHDError::db(
+                &format!("consuming issue {}", issue.id),
+                err,
+            ));
         }
     };
+
+    // assignees may have changed since we last saw this issue.
+    match sqlx::query("DELETE FROM issue_assignees WHERE issue_id = ?")
+        .bind(&issue.id)
+        .execute(&mut *tx)
+        .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("clearing assignees for issue {}", issue.id),
+                err,
+            ));
+        }
+    };
+    for login in &issue.assignees {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO issue_assignees (
+                issue_id, login
+            ) VALUES (
+                ?, ?
+            )
+            ",
+        )
+        .bind(&issue.id)
+        .bind(&login)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("adding assignee for issue {}", issue.id),
+                    err,
+                ));
+            }
+        };
+    }
+
+    // mentions are kept for as long as we know the issue, because we only see
+    // the body and the last comment on each refresh.
+    let mentions = issue
+        .body_mentions
+        .iter()
+        .chain(&issue.last_comment_mentions);
+    for login in mentions {
+        match sqlx::query(
+            "
+            INSERT OR IGNORE INTO issue_mentions (
+                issue_id, login
+            ) VALUES (
+                ?, ?
+            )
+            ",
+        )
+        .bind(&issue.id)
+        .bind(&login)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("adding mention for issue {}", issue.id),
+                    err,
+                ));
+            }
+        };
+    }
+
     Ok(())
 }
 
+/// Consume a Pull Request's specific data, including its review requests. The
+/// Pull Request's issue data must be consumed separately.
+///
+async fn consume_pull_request(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    entry: &PullRequest,
+) -> Result<(), GHDError> {
+    match sqlx::query(
+        "
+        INSERT OR REPLACE INTO pull_requests (
+            id, is_draft, review_decision, merged_at, checks_state,
+            mergeable, merge_state_status, base_ref_name, head_ref_name,
+            merge_queue_position, merge_queue_state,
+            merge_queue_enqueued_at, merge_queue_eta
+        ) VALUES (
+            ?, ?, ?, ?, ?,
+            ?, ?, ?, ?,
+            ?, ?,
+            ?, ?
+        )
+        ",
+    )
+    .bind(&entry.issue.id)
+    .bind(&entry.is_draft)
+    .bind(&entry.review_decision)
+    .bind(common::dt_opt_to_ts(&entry.merged_at))
+    .bind(&entry.checks_state)
+    .bind(&entry.mergeable)
+    .bind(&entry.merge_state_status)
+    .bind(&entry.base_ref_name)
+    .bind(&entry.head_ref_name)
+    .bind(entry.merge_queue.as_ref().map(|q| q.position))
+    .bind(entry.merge_queue.as_ref().map(|q| q.state.clone()))
+    .bind(entry.merge_queue.as_ref().map(|q| q.enqueued_at_ts))
+    .bind(
+        entry
+            .merge_queue
+            .as_ref()
+            .and_then(|q| q.estimated_time_to_merge),
+    )
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("consuming pull request {}", entry.issue.id),
+                err,
+            ));
+        }
+    };
+
+    // review requests may have changed since we last saw this pr.
+    match sqlx::query(
+        "DELETE FROM pull_request_review_requests WHERE pr_id = ?",
+    )
+    .bind(&entry.issue.id)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!(
+                    "clearing review requests for pull request {}",
+                    entry.issue.id
+                ),
+                err,
+            ));
+        }
+    };
+    for login in &entry.review_requests {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO pull_request_review_requests (
+                pr_id, login
+            ) VALUES (
+                ?, ?
+            )
+            ",
+        )
+        .bind(&entry.issue.id)
+        .bind(&login)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!(
+                        "adding review request for pull request {}",
+                        entry.issue.id
+                    ),
+                    err,
+                ));
+            }
+        };
+    }
+
+    // as have team review requests.
+    match sqlx::query(
+        "DELETE FROM pull_request_team_review_requests WHERE pr_id = ?",
+    )
+    .bind(&entry.issue.id)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!(
+                    "clearing team review requests for pull request {}",
+                    entry.issue.id
+                ),
+                err,
+            ));
+        }
+    };
+    for team in &entry.team_review_requests {
+        match sqlx::query(
+            "
+            INSERT OR REPLACE INTO pull_request_team_review_requests (
+                pr_id, team
+            ) VALUES (
+                ?, ?
+            )
+            ",
+        )
+        .bind(&entry.issue.id)
+        .bind(&team)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!(
+                        "adding team review request for pull request {}",
+                        entry.issue.id
+                    ),
+                    err,
+                ));
+            }
+        };
+    }
+
+    Ok(())
+}
+
+/// Obtain the tracked fields for the Issue or Pull Request with database ID
+/// `id`, as they currently are in the database, if it exists.
+///
+async fn get_change_fields(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    id: &i64,
+) -> Result<Option<IssueChangeFields>, GHDError> {
+    match sqlx::query_as::<_, IssueChangeFields>(
+        "
+        SELECT
+            issues.title, issues.state, issues.updated_at, issues.closed_at,
+            pull_requests.is_draft, pull_requests.review_decision,
+            pull_requests.merged_at
+        FROM
+            issues LEFT JOIN pull_requests
+        ON
+            issues.id = pull_requests.id
+        WHERE
+            issues.id = ?
+        ",
+    )
+    .bind(&id)
+    .fetch_one(&mut *tx)
+    .await
+    {
+        Ok(res) => Ok(Some(res)),
+        Err(sqlx::Error::RowNotFound) => Ok(None),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining fields for issue {}", id),
+            err,
+        )),
+    }
+}
+
+/// Obtain the tracked fields for the provided `issue`, and its associated Pull
+/// Request `pr`, if any.
+///
+fn get_issue_change_fields(
+    issue: &Issue,
+    pr: Option<&PullRequest>,
+) -> IssueChangeFields {
+    IssueChangeFields {
+        title: issue.title.clone(),
+        state: issue.state.clone(),
+        updated_at: issue.updated_at.timestamp(),
+        closed_at: common::dt_opt_to_ts(&issue.closed_at),
+        is_draft: pr.map(|p| p.is_draft),
+        review_decision: pr.map(|p| p.review_decision.clone()),
+        merged_at: match pr {
+            None => None,
+            Some(p) => common::dt_opt_to_ts(&p.merged_at),
+        },
+    }
+}
+
+/// Check whether `issue` is older than its stored copy, whose tracked fields
+/// are `before`, if any; logging it if so.
+///
+fn is_stale_entry(before: &Option<IssueChangeFields>, issue: &Issue) -> bool {
+    let stored = before.as_ref().map(|f| f.updated_at);
+    if sync::is_stale(&stored, &issue.updated_at) {
+        debug!(
+            "skipping stale copy of {}/{}#{}",
+            issue.repo_owner, issue.repo_name, issue.number
+        );
+        return true;
+    }
+    false
+}
+
+/// Obtain how the user `login` is involved with the provided `issue`, and its
+/// associated Pull Request `pr`, if any, as a bit mask of `Involvement`.
+///
+fn get_involvement(
+    login: &String,
+    issue: &Issue,
+    pr: Option<&PullRequest>,
+) -> i64 {
+    let has =
+        |lst: &Vec<String>| lst.iter().any(|v| v.eq_ignore_ascii_case(login));
+    let mut res: i64 = 0;
+
+    if issue.author.eq_ignore_ascii_case(login) {
+        res |= Involvement::Author.bit();
+    }
+    if has(&issue.assignees) {
+        res |= Involvement::Assignee.bit();
+    }
+    if has(&issue.body_mentions) || has(&issue.last_comment_mentions) {
+        res |= Involvement::Mentioned.bit();
+    }
+    let last_commenter = issue.last_commenter.as_ref();
+    if has(&issue.commenters)
+        || last_commenter.map_or(false, |c| c.eq_ignore_ascii_case(login))
+    {
+        res |= Involvement::Commenter.bit();
+    }
+    if let Some(pr) = pr {
+        if has(&pr.review_requests) {
+            res |= Involvement::ReviewRequested.bit();
+        }
+        if has(&pr.reviewers) {
+            res |= Involvement::Reviewed.bit();
+        }
+    }
+    res
+}
+
 /// Consume all issues and Pull Requests provided as arguments, writing them to
-/// the database, associating them with the provided `userid`.
+/// the database, associating them with the provided `user`, along with how the
+/// user is involved with each of them. Returns the resulting changes.
+///
+/// Consuming the same items again results in no changes, and copies older
+/// than what is stored, as returned by lagging searches, are not written.
 ///
 /// # Arguments
 ///
 /// * `tx` - A transaction handle.
-/// * `userid` - The user ID to associate the issues and Pull Requests with.
+/// * `user` - The user to associate the issues and Pull Requests with.
 /// * `issues` - A Vector of Issues associated with the provided user.
 /// * `prs` - A Vector of Pull Requests associated with the provided user.
 ///
 pub async fn consume_issues(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
-    userid: &i64,
+    user: &GithubUser,
     issues: &Vec<Issue>,
     prs: &Vec<PullRequest>,
-) -> Result<(), GHDError> {
+) -> Result<IssuesChangeset, GHDError> {
     info!("consuming {} issues, {} prs", issues.len(), prs.len());
 
-    let mut issue_ids: Vec<i64> = vec![];
+    let userid = &user.id;
+    let mut issue_ids: Vec<(i64, i64)> = vec![];
+    let mut changes = IssuesChangeset::new(&userid);
 
     for entry in issues {
+        issue_ids.push((entry.id, get_involvement(&user.login, &entry, None)));
+        let before = match get_change_fields(tx, &entry.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale_entry(&before, &entry) {
+            continue;
+        }
         match consume_issue(tx, &entry).await {
             Ok(_) => {}
-            Err(err) => {
-                panic!("unexpected error: {:?}", err);
-            }
+            Err(err) => return Err(err),
         };
-        issue_ids.push(entry.id);
+        changes.record(
+            &entry.id,
+            false,
+            before,
+            get_issue_change_fields(&entry, None),
+        );
     }
 
     for entry in prs {
+        issue_ids.push((
+            entry.issue.id,
+            get_involvement(&user.login, &entry.issue, Some(&entry)),
+        ));
+        let before = match get_change_fields(tx, &entry.issue.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale_entry(&before, &entry.issue) {
+            continue;
+        }
         match consume_issue(tx, &entry.issue).await {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+
+        match consume_pull_request(tx, &entry).await {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        changes.record(
+            &entry.issue.id,
+            true,
+            before,
+            get_issue_change_fields(&entry.issue, Some(&entry)),
+        );
+    }
+
+    for (id, involvement) in &issue_ids {
+        match sqlx::query(
+            "
+            INSERT INTO user_issues (
+                user_id, issue_id, archived, involvement
+            ) VALUES (
+                ?, ?, False, ?
+            )
+            ON CONFLICT (user_id, issue_id) DO UPDATE SET
+                involvement =
+                    excluded.involvement | (user_issues.involvement & ?)
+            ",
+        )
+        .bind(&userid)
+        .bind(id)
+        .bind(involvement)
+        .bind(&STICKY_INVOLVEMENT)
+        .execute(&mut *tx)
+        .await
+        {
+            Ok(_) => {}
+            Err(err) => {
+                return Err(GHDError::db(
+                    &format!("tracking issue {} for user {}", id, userid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    Ok(changes)
+}
+
+/// Reconcile the provided user's open Issues and Pull Requests with `seen`,
+/// the IDs of those Github reported as open and involving the user as of
+/// `snapshot`. Associations with open entries not in `seen` are removed, as
+/// the entries have been deleted, transferred, made private, or no longer
+/// involve the user; entries no longer referenced by anything else are then
+/// removed. Entries updated since `snapshot` are kept, as `seen` can't account
+/// for them. Returns the IDs of the entries no longer associated with the
+/// user.
+///
+/// # Arguments
+///
+/// * `tx` - A transaction handle.
+/// * `userid` - The user's database ID.
+/// * `seen` - IDs of the user's currently open Issues and Pull Requests.
+/// * `snapshot` - When `seen` was obtained.
+///
+pub async fn reconcile_user_issues(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    userid: &i64,
+    seen: &HashSet<i64>,
+    snapshot: &chrono::DateTime<chrono::Utc>,
+) -> Result<Vec<i64>, GHDError> {
+    let stored = match sqlx::query_scalar::<_, i64>(
+        "
+        SELECT
+            user_issues.issue_id
+        FROM
+            user_issues JOIN issues ON issues.id = user_issues.issue_id
+        WHERE
+            user_issues.user_id = ?
+            AND issues.state = 'open'
+            AND issues.updated_at < ?
+        ",
+    )
+    .bind(&userid)
+    .bind(&snapshot.timestamp())
+    .fetch_all(&mut *tx)
+    .await
+    {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("obtaining open issues for user {}", userid),
+                err,
+            ));
+        }
+    };
+
+    let removed: Vec<i64> =
+        stored.into_iter().filter(|id| !seen.contains(id)).collect();
+    for id in &removed {
+        match sqlx::query(
+            "DELETE FROM user_issues WHERE user_id = ? AND issue_id = ?",
+        )
+        .bind(&userid)
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        {
             Ok(_) => {}
             Err(err) => {
-                panic!("unexpected error: {:?}", err);
+                return Err(GHDError::db(
+                    &format!("untracking issue {} for user {}", id, userid),
+                    err,
+                ));
+            }
+        };
+    }
+
+    if !removed.is_empty() {
+        match remove_orphaned_issues(tx).await {
+            Ok(n) => {
+                info!(
+                    "reconciled user {}: untracked {} issues, removed {}",
+                    userid,
+                    removed.len(),
+                    n
+                );
             }
+            Err(err) => return Err(err),
+        };
+    }
+
+    Ok(removed)
+}
+
+/// Consume Issues and Pull Requests from a tracked repository. These are not
+/// associated with any user; they are associated with the repository by their
+/// owner and name. Copies older than what is stored are not written.
+///
+/// # Arguments
+///
+/// * `tx` - A transaction handle.
+/// * `issues` - A Vector of Issues from the repository.
+/// * `prs` - A Vector of Pull Requests from the repository.
+///
+pub async fn consume_repo_issues(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    issues: &Vec<Issue>,
+    prs: &Vec<PullRequest>,
+) -> Result<(), GHDError> {
+    info!("consuming {} repo issues, {} prs", issues.len(), prs.len());
+
+    for entry in issues {
+        let before = match get_change_fields(tx, &entry.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale_entry(&before, &entry) {
+            continue;
+        }
+        match consume_issue(tx, &entry).await {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+    }
+    for entry in prs {
+        let before = match get_change_fields(tx, &entry.issue.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale_entry(&before, &entry.issue) {
+            continue;
+        }
+        match consume_issue(tx, &entry.issue).await {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match consume_pull_request(tx, &entry).await {
+            Ok(_) => {}
+            Err(err) => return Err(err),
         };
+    }
+
+    Ok(())
+}
 
-        // consume pull request
+/// Consume Issues and Pull Requests resulting from a saved query, associating
+/// them with the query.
+///
+/// # Arguments
+///
+/// * `tx` - A transaction handle.
+/// * `query_id` - The saved query ID to associate the results with.
+/// * `issues` - A Vector of Issues matching the saved query.
+/// * `prs` - A Vector of Pull Requests matching the saved query.
+///
+pub async fn consume_query_issues(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    query_id: &i64,
+    issues: &Vec<Issue>,
+    prs: &Vec<PullRequest>,
+) -> Result<(), GHDError> {
+    match consume_repo_issues(tx, &issues, &prs).await {
+        Ok(_) => {}
+        Err(err) => return Err(err),
+    };
+
+    let ids = issues.iter().chain(prs.iter().map(|pr| &pr.issue));
+    for issue in ids {
         match sqlx::query(
             "
-            INSERT OR REPLACE INTO pull_requests (
-                id, is_draft, review_decision, merged_at
+            INSERT OR REPLACE INTO query_issues (
+                query_id, issue_id
             ) VALUES (
-                ?, ?, ?, ?
+                ?, ?
             )
             ",
         )
-        .bind(&entry.issue.id)
-        .bind(&entry.is_draft)
-        .bind(&entry.review_decision)
-        .bind(common::dt_opt_to_ts(&entry.merged_at))
+        .bind(&query_id)
+        .bind(&issue.id)
         .execute(&mut *tx)
         .await
         {
             Ok(_) => {}
             Err(err) => {
-                panic!("unable to consume pull request: {}", err);
+                return Err(GHDError::db(
+                    &format!(
+                        "tracking issue {} for saved query {}",
+                        issue.id, query_id
+                    ),
+                    err,
+                ));
             }
         };
-        issue_ids.push(entry.issue.id);
     }
 
-    for id in &issue_ids {
+    Ok(())
+}
+
+/// Consume Pull Requests for which a review has been requested from a given
+/// team, replacing any Pull Requests previously associated with it. Review
+/// requests for the team on Pull Requests not provided are dropped, as they
+/// have since been fulfilled or withdrawn, and Pull Requests no longer
+/// tracked otherwise are removed. Copies older than what is stored are not
+/// written.
+///
+/// # Arguments
+///
+/// * `tx` - A transaction handle.
+/// * `team` - The team to associate the Pull Requests with.
+/// * `prs` - A Vector of Pull Requests associated with the provided team.
+///
+pub async fn consume_team_pulls(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    team: &GithubTeam,
+    prs: &Vec<PullRequest>,
+) -> Result<(), GHDError> {
+    let team_id = &team.id;
+    info!(
+        "consuming {} prs for team {}",
+        prs.len(),
+        team.combined_slug()
+    );
+
+    match sqlx::query("DELETE FROM team_issues WHERE team_id = ?")
+        .bind(&team_id)
+        .execute(&mut *tx)
+        .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("clearing issues for team {}", team_id),
+                err,
+            ));
+        }
+    };
+    match sqlx::query(
+        "DELETE FROM pull_request_team_review_requests WHERE team = ?",
+    )
+    .bind(team.combined_slug())
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("clearing review requests for team {}", team_id),
+                err,
+            ));
+        }
+    };
+
+    for entry in prs {
+        let before = match get_change_fields(tx, &entry.issue.id).await {
+            Ok(v) => v,
+            Err(err) => return Err(err),
+        };
+        if is_stale_entry(&before, &entry.issue) {
+            // the stored copy is newer, but we have just cleared its review
+            // request for the team.
+            match sqlx::query(
+                "
+                INSERT OR REPLACE INTO pull_request_team_review_requests (
+                    pr_id, team
+                ) VALUES (
+                    ?, ?
+                )
+                ",
+            )
+            .bind(&entry.issue.id)
+            .bind(team.combined_slug())
+            .execute(&mut *tx)
+            .await
+            {
+                Ok(_) => {}
+                Err(err) => {
+                    return Err(GHDError::db(
+                        &format!(
+                            "restoring review request for team {} on {}",
+                            team_id, entry.issue.id
+                        ),
+                        err,
+                    ));
+                }
+            };
+        } else {
+            match consume_issue(tx, &entry.issue).await {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+            match consume_pull_request(tx, &entry).await {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
         match sqlx::query(
             "
-            INSERT OR REPLACE INTO user_issues (
-                user_id, issue_id
+            INSERT OR REPLACE INTO team_issues (
+                team_id, issue_id
             ) VALUES (
                 ?, ?
             )
             ",
         )
-        .bind(&userid)
-        .bind(id)
+        .bind(&team_id)
+        .bind(&entry.issue.id)
         .execute(&mut *tx)
         .await
         {
             Ok(_) => {}
             Err(err) => {
-                panic!(
-                    "unable to track issue {} for user {}: {}",
-                    id, userid, err
-                );
+                return Err(GHDError::db(
+                    &format!(
+                        "tracking issue {} for team {}",
+                        entry.issue.id, team_id
+                    ),
+                    err,
+                ));
             }
         };
     }
 
+    // Pull Requests no longer requesting a review from the team may not be
+    // tracked otherwise.
+    match remove_orphaned_issues(tx).await {
+        Ok(n) => {
+            if n > 0 {
+                info!(
+                    "removed {} orphaned issues for team {}",
+                    n,
+                    team.combined_slug()
+                );
+            }
+        }
+        Err(err) => return Err(err),
+    };
+
     Ok(())
 }
 
@@ -289,7 +1063,10 @@ async fn _mark_viewed(
             return Err(GHDError::NotFoundError);
         }
         Err(err) => {
-            panic!("Unexpected error marking pr '{}' viewed: {}", prid, err);
+            return Err(GHDError::db(
+                &format!("marking pr '{}' viewed", prid),
+                err,
+            ));
         }
     };
     Ok(())
@@ -303,10 +1080,13 @@ async fn _mark_viewed(
 /// * `prid` - The Pull Request's database ID.
 ///
 pub async fn mark_viewed(db: &DB, prid: &i64) -> Result<(), GHDError> {
-    let mut tx = match db.pool().begin().await {
+    let mut tx = match db.begin().await {
         Ok(res) => res,
         Err(err) => {
-            panic!("Error starting transaction to mark PR viewed: {}", err);
+            return Err(GHDError::db(
+                "starting transaction to mark PR viewed",
+                err,
+            ));
         }
     };
 
@@ -315,10 +1095,13 @@ pub async fn mark_viewed(db: &DB, prid: &i64) -> Result<(), GHDError> {
         Err(err) => return Err(err),
     };
 
-    tx.commit().await.unwrap_or_else(|err| {
-        panic!("Unable to commit transaction to mark PR viewed: {}", err);
-    });
-    Ok(())
+    match tx.commit().await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            "committing transaction to mark PR viewed",
+            err,
+        )),
+    }
 }
 
 /// Mark multiple Pull Requests as having been viewed.
@@ -329,30 +1112,35 @@ pub async fn mark_viewed(db: &DB, prid: &i64) -> Result<(), GHDError> {
 /// * `prs` - A Vector containing one or more Pull Request database IDs.
 ///
 pub async fn mark_viewed_many(db: &DB, prs: &Vec<i64>) -> Result<(), GHDError> {
-    let mut tx = db.pool().begin().await.unwrap_or_else(|err| {
-        panic!(
-            "Error starting transaction to mark multiple PRs as viewed: {}",
-            err
-        );
-    });
+    let mut tx = match db.begin().await {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                "starting transaction to mark multiple PRs as viewed",
+                err,
+            ));
+        }
+    };
 
     for prid in prs {
         match _mark_viewed(&mut tx, prid).await {
             Ok(_) => {}
             Err(err) => {
-                tx.rollback().await.unwrap_or_else(|err| {
-                    panic!("Unable to rollback broken transaction: {}", err);
-                });
+                if let Err(rb_err) = tx.rollback().await {
+                    warn!("Unable to rollback broken transaction: {}", rb_err);
+                }
                 return Err(err);
             }
         };
     }
 
-    tx.commit().await.unwrap_or_else(|err| {
-        panic!("Unable to commit transaction to mark PRs viewed: {}", err);
-    });
-
-    Ok(())
+    match tx.commit().await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            "committing transaction to mark PRs viewed",
+            err,
+        )),
+    }
 }
 
 /// Mark a specified Issue as having been archived.
@@ -379,7 +1167,10 @@ async fn _archive_issue(
             return Err(GHDError::NotFoundError);
         }
         Err(err) => {
-            panic!("Unexpected error archiving issue '{}': {}", issue_id, err);
+            return Err(GHDError::db(
+                &format!("archiving issue '{}'", issue_id),
+                err,
+            ));
         }
     };
 
@@ -394,10 +1185,13 @@ async fn _archive_issue(
 /// * `issue_id` - The Issue database ID to be archived.
 ///
 pub async fn archive_issue(db: &DB, issue_id: &i64) -> Result<(), GHDError> {
-    let mut tx = match db.pool().begin().await {
+    let mut tx = match db.begin().await {
         Ok(res) => res,
         Err(err) => {
-            panic!("Error starting transaction to archive issue: {}", err);
+            return Err(GHDError::db(
+                "starting transaction to archive issue",
+                err,
+            ));
         }
     };
 
@@ -406,10 +1200,12 @@ pub async fn archive_issue(db: &DB, issue_id: &i64) -> Result<(), GHDError> {
         Err(err) => return Err(err),
     };
 
-    tx.commit().await.unwrap_or_else(|err| {
-        panic!("Unable to commit transaction to archive issue: {}", err);
-    });
-    Ok(())
+    match tx.commit().await {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            Err(GHDError::db("committing transaction to archive issue", err))
+        }
+    }
 }
 
 /// Mark a Vector of Issues as having been archived.
@@ -423,33 +1219,97 @@ pub async fn archive_issue_many(
     db: &DB,
     issues: &Vec<i64>,
 ) -> Result<(), GHDError> {
-    let mut tx = db.pool().begin().await.unwrap_or_else(|err| {
-        panic!(
-            "Error starting transaction to archive multiple issues: {}",
-            err
-        );
-    });
+    let mut tx = match db.begin().await {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                "starting transaction to archive multiple issues",
+                err,
+            ));
+        }
+    };
 
     for issueid in issues {
         match _archive_issue(&mut tx, issueid).await {
             Ok(_) => {}
             Err(err) => {
-                tx.rollback().await.unwrap_or_else(|err| {
-                    panic!("Unable to rollback broken transaction: {}", err);
-                });
+                if let Err(rb_err) = tx.rollback().await {
+                    warn!("Unable to rollback broken transaction: {}", rb_err);
+                }
                 return Err(err);
             }
         };
     }
 
-    tx.commit().await.unwrap_or_else(|err| {
-        panic!(
-            "Unable to commit transaction to archive multiple issues: {}",
-            err
-        );
-    });
+    match tx.commit().await {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            "committing transaction to archive multiple issues",
+            err,
+        )),
+    }
+}
 
-    Ok(())
+/// Tables referencing issues, and the column holding the issue ID, ordered
+/// such that dependent rows are removed before the rows they depend on.
+///
+const ISSUE_DEPENDENT_TABLES: [(&str, &str); 10] = [
+    ("pull_request_labels", "pr_id"),
+    ("pull_request_participants", "pr_id"),
+    ("pull_request_reviews", "pr_id"),
+    ("pull_request_checks", "pr_id"),
+    ("pull_request_info", "id"),
+    ("pull_request_review_requests", "pr_id"),
+    ("pull_request_team_review_requests", "pr_id"),
+    ("pull_requests", "id"),
+    ("issue_assignees", "issue_id"),
+    ("issue_mentions", "issue_id"),
+];
+
+/// Remove all Issues and Pull Requests no longer associated with any tracked
+/// user, team, repository or saved query, along with any data depending on
+/// them. Returns the number of Issues and Pull Requests removed.
+///
+/// # Arguments
+///
+/// * `tx` - The transaction to perform the removal as part of.
+///
+pub async fn remove_orphaned_issues(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+) -> Result<u64, GHDError> {
+    let orphaned = "SELECT id FROM issues WHERE id NOT IN (
+        SELECT DISTINCT issue_id FROM user_issues
+    ) AND id NOT IN (
+        SELECT DISTINCT issue_id FROM team_issues
+    ) AND id NOT IN (
+        SELECT DISTINCT issue_id FROM query_issues
+    ) AND NOT EXISTS (
+        SELECT 1 FROM tracked_repos
+        WHERE owner = issues.repo_owner AND name = issues.repo_name
+    )";
+
+    for (table, column) in ISSUE_DEPENDENT_TABLES {
+        if let Err(err) = sqlx::query(&format!(
+            "DELETE FROM {} WHERE {} IN ({})",
+            table, column, orphaned
+        ))
+        .execute(&mut *tx)
+        .await
+        {
+            return Err(GHDError::db(
+                &format!("removing orphaned entries from '{}'", table),
+                err,
+            ));
+        }
+    }
+
+    match sqlx::query(&format!("DELETE FROM issues WHERE id IN ({})", orphaned))
+        .execute(&mut *tx)
+        .await
+    {
+        Ok(res) => Ok(res.rows_affected()),
+        Err(err) => Err(GHDError::db("removing orphaned issues", err)),
+    }
 }
 
 /// Obtain a specific issue by ID.
@@ -474,10 +1334,315 @@ pub async fn get_issue_by_id(
     {
         Ok(res) => Ok(res),
         Err(sqlx::Error::RowNotFound) => {
-            return Err(GHDError::PullRequestNotFoundError);
+            return Err(GHDError::IssueNotFoundError);
+        }
+        Err(err) => {
+            return Err(GHDError::db("obtaining issue by id", err));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gh::users;
+    use chrono::{TimeZone, Utc};
+
+    fn at(secs: i64) -> chrono::DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn new_issue(id: i64, title: &str, updated_at: i64) -> Issue {
+        Issue {
+            id,
+            number: id,
+            title: String::from(title),
+            author: String::from("author"),
+            author_id: 1,
+            url: format!("https://github.com/owner/repo/issues/{}", id),
+            repo_owner: String::from("owner"),
+            repo_name: String::from("repo"),
+            state: String::from("open"),
+            created_at: at(0),
+            updated_at: at(updated_at),
+            closed_at: None,
+            is_pull_request: false,
+            last_viewed: None,
+            assignees: vec![],
+            total_comments: 0,
+            last_commenter: None,
+            body_mentions: vec![],
+            last_comment_mentions: vec![],
+            commenters: vec![],
         }
-        Err(_) => {
-            return Err(GHDError::UnknownError);
+    }
+
+    fn new_pull(id: i64, title: &str, updated_at: i64) -> PullRequest {
+        let mut issue = new_issue(id, title, updated_at);
+        issue.is_pull_request = true;
+        PullRequest {
+            issue,
+            is_draft: false,
+            review_decision: String::from("none"),
+            merged_at: None,
+            checks_state: None,
+            mergeable: String::from("mergeable"),
+            merge_state_status: String::from("clean"),
+            base_ref_name: String::from("main"),
+            head_ref_name: String::from("feature"),
+            merge_queue: None,
+            review_requests: vec![],
+            team_review_requests: vec![],
+            reviewers: vec![],
         }
     }
+
+    /// A test database, whose files are removed when dropped.
+    ///
+    struct TestDB {
+        db: DB,
+        path: std::path::PathBuf,
+    }
+
+    impl std::ops::Deref for TestDB {
+        type Target = DB;
+
+        fn deref(self: &Self) -> &DB {
+            &self.db
+        }
+    }
+
+    impl Drop for TestDB {
+        fn drop(self: &mut Self) {
+            for suffix in ["", "-wal", "-shm"] {
+                let _ = std::fs::remove_file(format!(
+                    "{}{}",
+                    self.path.display(),
+                    suffix
+                ));
+            }
+        }
+    }
+
+    /// Set up a fresh database with a tracked user, returning both.
+    ///
+    async fn setup(name: &str) -> (TestDB, GithubUser) {
+        let path = std::env::temp_dir().join(format!(
+            "ghd-test-{}-{}.db",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let db = TestDB {
+            db: DB::open(&path).await.unwrap(),
+            path,
+        };
+
+        let user = GithubUser {
+            id: 1,
+            login: String::from("author"),
+            name: String::from("Author"),
+            avatar_url: String::from("https://example.com/avatar"),
+        };
+        let mut tx = db.begin().await.unwrap();
+        users::add_user_to_db(&mut tx, &user).await.unwrap();
+        tx.commit().await.unwrap();
+        (db, user)
+    }
+
+    async fn consume(
+        db: &DB,
+        user: &GithubUser,
+        issues: &Vec<Issue>,
+        prs: &Vec<PullRequest>,
+    ) -> IssuesChangeset {
+        let mut tx = db.begin().await.unwrap();
+        let changes =
+            consume_issues(&mut tx, &user, &issues, &prs).await.unwrap();
+        tx.commit().await.unwrap();
+        changes
+    }
+
+    async fn stored_title(db: &DB, id: i64) -> String {
+        sqlx::query_scalar::<_, String>("SELECT title FROM issues WHERE id = ?")
+            .bind(&id)
+            .fetch_one(db.pool())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn consuming_again_is_idempotent() {
+        let (db, user) = setup("idempotent").await;
+        let issues = vec![new_issue(10, "issue", 60)];
+        let prs = vec![new_pull(20, "pull", 60)];
+
+        let changes = consume(&db, &user, &issues, &prs).await;
+        assert_eq!(changes.inserted.len(), 2);
+
+        // an overlapping search returns the same items again.
+        let changes = consume(&db, &user, &issues, &prs).await;
+        assert!(changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn stale_copies_are_not_written() {
+        let (db, user) = setup("stale").await;
+        let current = vec![new_pull(20, "current", 120)];
+        consume(&db, &user, &vec![], &current).await;
+
+        // a lagging search returns an older copy.
+        let stale = vec![new_pull(20, "stale", 60)];
+        let changes = consume(&db, &user, &vec![], &stale).await;
+        assert!(changes.is_empty());
+        assert_eq!(stored_title(&db, 20).await, "current");
+    }
+
+    #[tokio::test]
+    async fn newer_copies_are_recorded() {
+        let (db, user) = setup("newer").await;
+        consume(&db, &user, &vec![new_issue(10, "before", 60)], &vec![]).await;
+
+        let newer = new_issue(10, "after", 120);
+        let changes = consume(&db, &user, &vec![newer], &vec![]).await;
+        assert_eq!(changes.updated, vec![10]);
+        assert_eq!(stored_title(&db, 10).await, "after");
+    }
+
+    #[tokio::test]
+    async fn reconciliation_removes_disappeared_issues() {
+        let (db, user) = setup("reconcile").await;
+        let mut closed = new_issue(30, "closed", 60);
+        closed.state = String::from("closed");
+        let issues = vec![new_issue(10, "kept", 60), closed];
+        let prs = vec![new_pull(20, "gone", 60), new_pull(40, "recent", 180)];
+        consume(&db, &user, &issues, &prs).await;
+
+        // closed issues are not in the open set, but are kept; so are those
+        // updated after the open set was obtained.
+        let seen = HashSet::from([10]);
+        let mut tx = db.begin().await.unwrap();
+        let removed = reconcile_user_issues(&mut tx, &user.id, &seen, &at(120))
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        assert_eq!(removed, vec![20]);
+
+        let ids = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM issues ORDER BY id ASC",
+        )
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+        assert_eq!(ids, vec![10, 30, 40]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_consumers_do_not_fail() {
+        let (db, user) = setup("concurrent").await;
+        let mut tasks = tokio::task::JoinSet::new();
+        for n in 0..8 {
+            let db = db.db.clone();
+            let user = user.clone();
+            tasks.spawn(async move {
+                for round in 0..5 {
+                    let issues = vec![
+                        new_issue(1, "shared", round),
+                        new_issue(100 + n, "own", round),
+                    ];
+                    consume(&db, &user, &issues, &vec![]).await;
+                }
+            });
+        }
+        while let Some(res) = tasks.join_next().await {
+            res.unwrap();
+        }
+    }
+
+    /// Count the rows referencing Issue `id` across all dependent tables.
+    ///
+    async fn dependent_rows(db: &DB, id: i64) -> i64 {
+        let mut n = 0;
+        for (table, column) in ISSUE_DEPENDENT_TABLES {
+            n += sqlx::query_scalar::<_, i64>(&format!(
+                "SELECT COUNT(*) FROM {} WHERE {} = ?",
+                table, column
+            ))
+            .bind(&id)
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        }
+        n
+    }
+
+    #[tokio::test]
+    async fn untracking_removes_orphaned_issues() {
+        let (db, main) = setup("untrack").await;
+        sqlx::query(
+            "INSERT INTO tokens (token, user_id, invalid) VALUES (?, ?, False)",
+        )
+        .bind("token")
+        .bind(&main.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+        let other = GithubUser {
+            id: 2,
+            login: String::from("other"),
+            name: String::from("Other"),
+            avatar_url: String::from("https://example.com/avatar"),
+        };
+        let mut tx = db.begin().await.unwrap();
+        users::add_user_to_db(&mut tx, &other).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let mut issue = new_issue(10, "own issue", 60);
+        issue.assignees = vec![String::from("other")];
+        let mut pull = new_pull(20, "own pull", 60);
+        pull.review_requests = vec![String::from("reviewer")];
+        pull.team_review_requests = vec![String::from("org/team")];
+        let shared = new_issue(30, "shared", 60);
+        consume(&db, &other, &vec![issue, shared], &vec![pull]).await;
+        let shared = new_issue(30, "shared", 60);
+        consume(&db, &main, &vec![shared], &vec![]).await;
+        assert!(dependent_rows(&db, 10).await > 0);
+        assert!(dependent_rows(&db, 20).await > 0);
+
+        let removed =
+            users::remove_tracked_user(&db, &other.login).await.unwrap();
+        assert_eq!(removed.id, other.id);
+        assert!(!users::user_exists(&db, &other.login).await);
+
+        // the shared issue is still tracked by the main user.
+        let ids = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM issues ORDER BY id ASC",
+        )
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+        assert_eq!(ids, vec![30]);
+        assert_eq!(dependent_rows(&db, 10).await, 0);
+        assert_eq!(dependent_rows(&db, 20).await, 0);
+    }
+
+    #[tokio::test]
+    async fn main_user_is_not_untracked() {
+        let (db, main) = setup("untrack-main").await;
+        sqlx::query(
+            "INSERT INTO tokens (token, user_id, invalid) VALUES (?, ?, False)",
+        )
+        .bind("token")
+        .bind(&main.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+        consume(&db, &main, &vec![new_issue(10, "issue", 60)], &vec![]).await;
+
+        let res = users::remove_tracked_user(&db, &main.login).await;
+        assert!(matches!(res, Err(GHDError::InvalidStateError { .. })));
+        assert!(users::user_exists(&db, &main.login).await);
+        assert_eq!(stored_title(&db, 10).await, "issue");
+    }
 }
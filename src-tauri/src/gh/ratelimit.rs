@@ -0,0 +1,438 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::{Arc, Mutex};
+
+use log::{debug, warn};
+
+use crate::common;
+
+/// Number of remaining points below which we will stop issuing requests until
+/// the rate limit resets, leaving some budget for user-triggered requests.
+///
+const RATE_LIMIT_RESERVE: i64 = 10;
+
+/// Percentage of the GraphQL budget below which we consider the budget to be
+/// running low, and start prioritizing which requests to issue.
+///
+const RATE_LIMIT_LOW_PERCENT: i64 = 20;
+
+/// Number of seconds to pause for when hitting a secondary rate limit that
+/// does not tell us how long we should wait for.
+///
+const SECONDARY_RATE_LIMIT_PAUSE: i64 = 60;
+
+/// The API whose rate limit budget is being tracked. Github accounts for the
+/// REST and GraphQL APIs separately.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum RateLimitResource {
+    GraphQL,
+    REST,
+}
+
+/// Represents a rate limit budget, as last reported by the API.
+///
+#[derive(serde::Serialize, Clone, Default)]
+pub struct RateLimitBudget {
+    pub limit: Option<i64>,
+    pub remaining: Option<i64>,
+    pub reset_at: Option<i64>,
+    pub last_cost: Option<i64>,
+}
+
+/// Represents the current rate limit information, for both the GraphQL and the
+/// REST APIs. If `paused_until` is set, we should not issue further requests
+/// until then.
+///
+#[derive(serde::Serialize, Clone, Default)]
+pub struct RateLimitInfo {
+    pub graphql: RateLimitBudget,
+    pub rest: RateLimitBudget,
+    pub paused_until: Option<i64>,
+}
+
+/// Shared rate limit tracker. Cloning the tracker is cheap, and all clones
+/// refer to the same underlying information.
+///
+#[derive(Clone)]
+pub struct RateLimitTracker {
+    info: Arc<Mutex<RateLimitInfo>>,
+}
+
+impl RateLimitTracker {
+    /// Obtain a new rate limit tracker.
+    ///
+    pub fn new() -> Self {
+        RateLimitTracker {
+            info: Arc::new(Mutex::new(RateLimitInfo::default())),
+        }
+    }
+
+    /// Obtain a copy of the current rate limit information.
+    ///
+    pub fn get(self: &Self) -> RateLimitInfo {
+        self.info.lock().unwrap().clone()
+    }
+
+    /// Update the budget for `resource` from the `X-RateLimit-*` headers
+    /// returned by the API.
+    ///
+    pub fn update_from_headers(
+        self: &Self,
+        resource: RateLimitResource,
+        headers: &reqwest::header::HeaderMap,
+    ) {
+        let limit = get_header_i64(&headers, "x-ratelimit-limit");
+        let remaining = get_header_i64(&headers, "x-ratelimit-remaining");
+        let reset_at = get_header_i64(&headers, "x-ratelimit-reset");
+        if limit.is_none() && remaining.is_none() && reset_at.is_none() {
+            return;
+        }
+
+        let mut info = self.info.lock().unwrap();
+        let budget = match resource {
+            RateLimitResource::GraphQL => &mut info.graphql,
+            RateLimitResource::REST => &mut info.rest,
+        };
+        if limit.is_some() {
+            budget.limit = limit;
+        }
+        if remaining.is_some() {
+            budget.remaining = remaining;
+        }
+        if reset_at.is_some() {
+            budget.reset_at = reset_at;
+        }
+    }
+
+    /// Update the GraphQL budget from the `rateLimit` object returned as part
+    /// of a query's result.
+    ///
+    pub fn update_graphql(
+        self: &Self,
+        cost: i64,
+        limit: i64,
+        remaining: i64,
+        reset_at: &str,
+    ) {
+        let mut info = self.info.lock().unwrap();
+        info.graphql.last_cost = Some(cost);
+        info.graphql.limit = Some(limit);
+        info.graphql.remaining = Some(remaining);
+        if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(reset_at) {
+            info.graphql.reset_at = Some(dt.timestamp());
+        }
+        debug!(
+            "graphql rate limit: cost {}, remaining {}/{}",
+            cost, remaining, limit
+        );
+    }
+
+    /// Check whether an unsuccessful response was caused by a rate limit,
+    /// either primary or secondary. If so, pause requests until the API allows
+    /// us to resume, and return `true`. Otherwise, e.g. if a `403` is due to
+    /// bad credentials, return `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The response's HTTP status code.
+    /// * `headers` - The response's headers.
+    /// * `body` - The response's body.
+    ///
+    pub fn check_rate_limited(
+        self: &Self,
+        status: reqwest::StatusCode,
+        headers: &reqwest::header::HeaderMap,
+        body: &str,
+    ) -> bool {
+        if status != reqwest::StatusCode::FORBIDDEN
+            && status != reqwest::StatusCode::TOO_MANY_REQUESTS
+        {
+            return false;
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let until = if let Some(secs) = get_header_i64(&headers, "retry-after")
+        {
+            now + secs
+        } else if get_header_i64(&headers, "x-ratelimit-remaining") == Some(0) {
+            match get_header_i64(&headers, "x-ratelimit-reset") {
+                Some(ts) => ts,
+                None => now + SECONDARY_RATE_LIMIT_PAUSE,
+            }
+        } else if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            || body.to_lowercase().contains("rate limit")
+        {
+            now + SECONDARY_RATE_LIMIT_PAUSE
+        } else {
+            return false;
+        };
+
+        warn!(
+            "rate limited ({}), pausing until {}",
+            status,
+            common::ts_to_datetime(until)
+                .map(|dt| dt.to_rfc3339())
+                .unwrap_or(String::from("unknown"))
+        );
+        self.pause_until(until);
+        true
+    }
+
+    /// Pause requests until the provided timestamp.
+    ///
+    pub fn pause_until(self: &Self, ts: i64) {
+        let mut info = self.info.lock().unwrap();
+        info.paused_until = match info.paused_until {
+            Some(v) if v > ts => Some(v),
+            _ => Some(ts),
+        };
+    }
+
+    /// Pause requests after the GraphQL API reports us as being rate limited,
+    /// until the GraphQL budget resets, if known.
+    ///
+    pub fn pause_graphql(self: &Self) {
+        let now = chrono::Utc::now().timestamp();
+        let until = match self.info.lock().unwrap().graphql.reset_at {
+            Some(ts) if ts > now => ts,
+            _ => now + SECONDARY_RATE_LIMIT_PAUSE,
+        };
+        warn!(
+            "graphql rate limited, pausing until {}",
+            common::ts_to_datetime(until)
+                .map(|dt| dt.to_rfc3339())
+                .unwrap_or(String::from("unknown"))
+        );
+        self.pause_until(until);
+    }
+
+    /// Obtain the time until which requests should be paused, if any. This is
+    /// the case if we have been explicitly told to back off, or if the budget
+    /// for either API has dropped below our reserve and has not reset yet.
+    ///
+    pub fn paused_until(self: &Self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let now = chrono::Utc::now().timestamp();
+        let mut info = self.info.lock().unwrap();
+
+        if let Some(ts) = info.paused_until {
+            if ts > now {
+                return common::ts_to_datetime(ts).ok();
+            }
+            info.paused_until = None;
+        }
+
+        let mut until: Option<i64> = None;
+        for budget in [&info.graphql, &info.rest] {
+            if let (Some(remaining), Some(reset_at)) =
+                (budget.remaining, budget.reset_at)
+            {
+                if remaining <= RATE_LIMIT_RESERVE && reset_at > now {
+                    until = Some(until.map_or(reset_at, |v| v.max(reset_at)));
+                }
+            }
+        }
+
+        match until {
+            None => None,
+            Some(ts) => common::ts_to_datetime(ts).ok(),
+        }
+    }
+
+    /// Check whether the GraphQL budget is running low, i.e., whether its
+    /// remaining points have dropped below `RATE_LIMIT_LOW_PERCENT` of its
+    /// limit and it has not reset yet.
+    ///
+    pub fn is_budget_low(self: &Self) -> bool {
+        let now = chrono::Utc::now().timestamp();
+        let info = self.info.lock().unwrap();
+        match (
+            info.graphql.limit,
+            info.graphql.remaining,
+            info.graphql.reset_at,
+        ) {
+            (Some(limit), Some(remaining), Some(reset_at)) => {
+                reset_at > now
+                    && remaining * 100 < limit * RATE_LIMIT_LOW_PERCENT
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Obtain an `i64` value from the header `name`, if it exists and is valid.
+///
+fn get_header_i64(
+    headers: &reqwest::header::HeaderMap,
+    name: &str,
+) -> Option<i64> {
+    match headers.get(name) {
+        None => None,
+        Some(v) => match v.to_str() {
+            Ok(s) => s.trim().parse::<i64>().ok(),
+            Err(_) => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::{
+        header::{HeaderMap, HeaderName, HeaderValue},
+        StatusCode,
+    };
+
+    fn headers(values: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in values {
+            map.insert(
+                HeaderName::from_static(name),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        map
+    }
+
+    fn paused_ts(tracker: &RateLimitTracker) -> Option<i64> {
+        tracker.paused_until().map(|dt| dt.timestamp())
+    }
+
+    fn reset_in(secs: i64) -> String {
+        (chrono::Utc::now() + chrono::Duration::seconds(secs)).to_rfc3339()
+    }
+
+    #[test]
+    fn retry_after_pauses() {
+        let tracker = RateLimitTracker::new();
+        let now = chrono::Utc::now().timestamp();
+        let hdrs = headers(&[("retry-after", "30")]);
+        assert!(tracker.check_rate_limited(StatusCode::FORBIDDEN, &hdrs, ""));
+
+        let until = paused_ts(&tracker).unwrap();
+        assert!(until >= now + 30 && until <= now + 31);
+    }
+
+    #[test]
+    fn exhausted_budget_pauses_until_reset() {
+        let tracker = RateLimitTracker::new();
+        let reset = chrono::Utc::now().timestamp() + 600;
+        let hdrs = headers(&[
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", &reset.to_string()),
+        ]);
+        assert!(tracker.check_rate_limited(StatusCode::FORBIDDEN, &hdrs, ""));
+        assert_eq!(paused_ts(&tracker), Some(reset));
+    }
+
+    #[test]
+    fn exhausted_budget_without_reset_pauses() {
+        let tracker = RateLimitTracker::new();
+        let now = chrono::Utc::now().timestamp();
+        let hdrs = headers(&[("x-ratelimit-remaining", "0")]);
+        assert!(tracker.check_rate_limited(StatusCode::FORBIDDEN, &hdrs, ""));
+
+        let until = paused_ts(&tracker).unwrap();
+        assert!(until >= now + SECONDARY_RATE_LIMIT_PAUSE);
+        assert!(until <= now + SECONDARY_RATE_LIMIT_PAUSE + 1);
+    }
+
+    #[test]
+    fn forbidden_is_not_always_rate_limited() {
+        let tracker = RateLimitTracker::new();
+        let hdrs = headers(&[("x-ratelimit-remaining", "4999")]);
+        assert!(!tracker.check_rate_limited(
+            StatusCode::FORBIDDEN,
+            &hdrs,
+            r#"{"message": "Bad credentials"}"#,
+        ));
+        assert!(paused_ts(&tracker).is_none());
+
+        assert!(tracker.check_rate_limited(
+            StatusCode::FORBIDDEN,
+            &hdrs,
+            r#"{"message": "You have exceeded a secondary rate limit."}"#,
+        ));
+        assert!(paused_ts(&tracker).is_some());
+    }
+
+    #[test]
+    fn other_statuses_are_not_rate_limited() {
+        let tracker = RateLimitTracker::new();
+        let hdrs = headers(&[("retry-after", "30")]);
+        assert!(!tracker.check_rate_limited(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &hdrs,
+            "rate limit",
+        ));
+        assert!(paused_ts(&tracker).is_none());
+    }
+
+    #[test]
+    fn pause_is_monotonic() {
+        let tracker = RateLimitTracker::new();
+        let now = chrono::Utc::now().timestamp();
+        tracker.pause_until(now + 100);
+        tracker.pause_until(now + 50);
+        assert_eq!(paused_ts(&tracker), Some(now + 100));
+
+        tracker.pause_until(now + 200);
+        assert_eq!(paused_ts(&tracker), Some(now + 200));
+    }
+
+    #[test]
+    fn elapsed_pause_is_cleared() {
+        let tracker = RateLimitTracker::new();
+        tracker.pause_until(chrono::Utc::now().timestamp() - 1);
+        assert!(paused_ts(&tracker).is_none());
+        assert!(tracker.get().paused_until.is_none());
+    }
+
+    #[test]
+    fn reserve_pauses_until_reset() {
+        let tracker = RateLimitTracker::new();
+        let reset = reset_in(600);
+        let reset_ts = chrono::DateTime::parse_from_rfc3339(&reset)
+            .unwrap()
+            .timestamp();
+
+        tracker.update_graphql(1, 5000, RATE_LIMIT_RESERVE + 1, &reset);
+        assert!(paused_ts(&tracker).is_none());
+
+        tracker.update_graphql(1, 5000, RATE_LIMIT_RESERVE, &reset);
+        assert_eq!(paused_ts(&tracker), Some(reset_ts));
+
+        // the budget has since reset.
+        tracker.update_graphql(1, 5000, RATE_LIMIT_RESERVE, &reset_in(-1));
+        assert!(paused_ts(&tracker).is_none());
+    }
+
+    #[test]
+    fn budget_low_threshold() {
+        let tracker = RateLimitTracker::new();
+        assert!(!tracker.is_budget_low());
+
+        let low = 5000 * RATE_LIMIT_LOW_PERCENT / 100;
+        tracker.update_graphql(1, 5000, low, &reset_in(600));
+        assert!(!tracker.is_budget_low());
+
+        tracker.update_graphql(1, 5000, low - 1, &reset_in(600));
+        assert!(tracker.is_budget_low());
+
+        // the budget has since reset.
+        tracker.update_graphql(1, 5000, low - 1, &reset_in(-1));
+        assert!(!tracker.is_budget_low());
+    }
+}
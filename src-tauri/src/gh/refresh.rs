@@ -12,106 +12,283 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{common, db::DB, errors::GHDError};
+use crate::{
+    common,
+    config::{self, SETTING_REFRESH_INTERVAL},
+    db::DB,
+    errors::GHDError,
+};
 
-use super::{types::GithubUser, users};
+use super::types::UserRefreshEntry;
 
-const USER_REFRESH_INTERVAL: i64 = 60;
-
-/// Obtain `chrono::DateTime` from when the user was last refreshed.
+/// Obtain the sync cursor stored by the user's last refresh, from which the
+/// next refresh should search for updates.
 ///
 /// # Arguments
 ///
 /// * `db` - The GHD Database handle.
 /// * `userid` - The user's database ID.
 ///
-pub async fn get_user_refresh(
+pub async fn get_user_sync_cursor(
     db: &DB,
     userid: &i64,
 ) -> Result<chrono::DateTime<chrono::Utc>, GHDError> {
-    match sqlx::query_scalar::<_, i64>(
-        "SELECT refresh_at FROM user_refresh WHERE id = ?",
+    match sqlx::query_as::<_, (i64, Option<i64>)>(
+        "SELECT refresh_at, sync_cursor FROM user_refresh WHERE id = ?",
     )
     .bind(&userid)
     .fetch_one(db.pool())
     .await
     {
-        Ok(res) => {
+        Ok((refresh_at, cursor)) => {
             // if < 0, never refreshed; how do we convey that? Error?
-            if res <= 0 {
+            if refresh_at <= 0 {
                 return Err(GHDError::NeverRefreshedError);
             }
-            Ok(common::ts_to_datetime(res).unwrap())
+            let cursor = cursor.unwrap_or(refresh_at);
+            match common::ts_to_datetime(cursor) {
+                Ok(dt) => Ok(dt),
+                Err(_) => Err(GHDError::unexpected(&format!(
+                    "invalid sync cursor {} for user {}",
+                    cursor, userid
+                ))),
+            }
         }
-        Err(_) => Err(GHDError::UserNotFoundError),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::UserNotFoundError),
+        Err(err) => Err(GHDError::db("obtaining user refresh time", err)),
     }
 }
 
-/// Check whether we should refresh a given user, by login.
+/// Obtain a user's refresh state, by login.
 ///
 /// # Arguments
 ///
 /// * `db` - The GHD Database handle.
 /// * `login` - A String representing the user login.
 ///
-pub async fn should_refresh_user(db: &DB, login: &String) -> bool {
-    let userid: i64 = match users::get_user_by_login(&db, &login).await {
-        Err(GHDError::UserNotFoundError) => {
-            return false;
-        }
-        Err(err) => {
-            panic!("unexpected error: {:?}!", err);
-        }
-        Ok(user) => user.id,
-    };
+pub async fn get_user_refresh_entry(
+    db: &DB,
+    login: &String,
+) -> Result<UserRefreshEntry, GHDError> {
+    match sqlx::query_as::<_, UserRefreshEntry>(
+        "
+        SELECT
+            users.id, users.login,
+            user_refresh.refresh_at,
+            user_refresh.refresh_interval,
+            user_refresh.priority
+        FROM users INNER JOIN user_refresh
+        ON users.id = user_refresh.id
+        WHERE users.login = ?
+        ",
+    )
+    .bind(&login)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::UserNotFoundError),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining refresh state for user '{}'", login),
+            err,
+        )),
+    }
+}
 
-    match get_user_refresh(&db, &userid).await {
-        Ok(val) => {
-            return common::has_expired(&val, USER_REFRESH_INTERVAL);
-        }
-        Err(GHDError::NeverRefreshedError) => {
-            return true;
+/// Obtain all users that are due for a refresh, highest priority first. Users
+/// with the same priority are ordered by how long ago they were last
+/// refreshed.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `default_interval` - Number of seconds between refreshes, for users
+///   without their own refresh interval.
+///
+pub async fn get_to_refresh_users(
+    db: &DB,
+    default_interval: &i64,
+) -> Result<Vec<UserRefreshEntry>, GHDError> {
+    let now = chrono::Utc::now().timestamp();
+
+    match sqlx::query_as::<_, UserRefreshEntry>(
+        "
+        SELECT
+            users.id, users.login,
+            user_refresh.refresh_at,
+            user_refresh.refresh_interval,
+            user_refresh.priority
+        FROM users INNER JOIN user_refresh
+        ON users.id = user_refresh.id
+        WHERE
+            user_refresh.refresh_at
+                + COALESCE(user_refresh.refresh_interval, ?) <= ?
+        ORDER BY
+            user_refresh.priority DESC,
+            user_refresh.refresh_at ASC
+        ",
+    )
+    .bind(&default_interval)
+    .bind(&now)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining users to refresh", err)),
+    }
+}
+
+/// Set how often a user should be refreshed. If `interval` is `None`, the user
+/// will be refreshed at the global refresh interval.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `login` - A String representing the user login.
+/// * `interval` - Number of seconds between refreshes, if any.
+///
+pub async fn set_user_refresh_interval(
+    db: &DB,
+    login: &String,
+    interval: &Option<i64>,
+) -> Result<UserRefreshEntry, GHDError> {
+    if let Some(v) = interval {
+        if let Err(err) = config::check_range(SETTING_REFRESH_INTERVAL, *v) {
+            return Err(err);
         }
-        Err(GHDError::UserNotFoundError) => {
-            println!("Unable to find user '{}' to refresh!", login);
-            return false;
+    }
+
+    match sqlx::query(
+        "
+        UPDATE user_refresh SET refresh_interval = ?
+        WHERE id = (SELECT id FROM users WHERE login = ?)
+        ",
+    )
+    .bind(&interval)
+    .bind(&login)
+    .execute(db.pool())
+    .await
+    {
+        Ok(res) if res.rows_affected() == 0 => {
+            return Err(GHDError::UserNotFoundError);
         }
+        Ok(_) => {}
         Err(err) => {
-            panic!("Unknown error while checking refresh user: {:?}", err);
+            return Err(GHDError::db(
+                &format!("setting refresh interval for user '{}'", login),
+                err,
+            ));
         }
     };
+
+    get_user_refresh_entry(&db, &login).await
 }
 
-/// Obtain all users that are due for a refresh.
+/// Set a user's refresh priority. When the API budget is running low, only the
+/// users with the highest priority are refreshed.
 ///
 /// # Arguments
 ///
 /// * `db` - A GHD Database handle.
+/// * `login` - A String representing the user login.
+/// * `priority` - The user's new priority. Defaults to 0.
 ///
-pub async fn get_to_refresh_users(db: &DB) -> Vec<GithubUser> {
-    let cutoff = match chrono::Utc::now()
-        .checked_sub_signed(chrono::Duration::seconds(USER_REFRESH_INTERVAL))
+pub async fn set_user_priority(
+    db: &DB,
+    login: &String,
+    priority: &i64,
+) -> Result<UserRefreshEntry, GHDError> {
+    match sqlx::query(
+        "
+        UPDATE user_refresh SET priority = ?
+        WHERE id = (SELECT id FROM users WHERE login = ?)
+        ",
+    )
+    .bind(&priority)
+    .bind(&login)
+    .execute(db.pool())
+    .await
     {
-        Some(v) => v.timestamp(),
-        None => {
-            panic!("Unexpected error calculating cutoff time!");
+        Ok(res) if res.rows_affected() == 0 => {
+            return Err(GHDError::UserNotFoundError);
+        }
+        Ok(_) => {}
+        Err(err) => {
+            return Err(GHDError::db(
+                &format!("setting refresh priority for user '{}'", login),
+                err,
+            ));
         }
     };
 
-    match sqlx::query_as::<_, GithubUser>(
+    get_user_refresh_entry(&db, &login).await
+}
+
+/// Obtain all users due for a full reconciliation, least recently reconciled
+/// first. Users that have never been refreshed are populated in full instead,
+/// so they are not included.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `interval` - Number of seconds between reconciliations.
+///
+pub async fn get_to_reconcile_users(
+    db: &DB,
+    interval: &i64,
+) -> Result<Vec<UserRefreshEntry>, GHDError> {
+    let now = chrono::Utc::now().timestamp();
+
+    match sqlx::query_as::<_, UserRefreshEntry>(
         "
-        SELECT users.* FROM users INNER JOIN user_refresh
+        SELECT
+            users.id, users.login,
+            user_refresh.refresh_at,
+            user_refresh.refresh_interval,
+            user_refresh.priority
+        FROM users INNER JOIN user_refresh
         ON users.id = user_refresh.id
-        WHERE user_refresh.refresh_at <= ?
+        WHERE
+            user_refresh.refresh_at > 0
+            AND COALESCE(user_refresh.reconcile_at, 0) + ? <= ?
+        ORDER BY
+            user_refresh.reconcile_at ASC
         ",
     )
-    .bind(&cutoff)
+    .bind(&interval)
+    .bind(&now)
     .fetch_all(db.pool())
     .await
     {
-        Ok(res) => res,
-        Err(err) => {
-            panic!("Unexpected error: {}", err);
-        }
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining users to reconcile", err)),
+    }
+}
+
+/// Update when a user was last fully reconciled. This function requires a
+/// transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `userid` - The user's database ID.
+/// * `when` - When the user was reconciled.
+///
+pub async fn update_user_reconcile(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    userid: &i64,
+    when: &chrono::DateTime<chrono::Utc>,
+) -> Result<(), GHDError> {
+    match sqlx::query("UPDATE user_refresh SET reconcile_at = ? WHERE id = ?")
+        .bind(&when.timestamp())
+        .bind(&userid)
+        .execute(&mut *tx)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("updating user {} reconciliation time", userid),
+            err,
+        )),
     }
 }
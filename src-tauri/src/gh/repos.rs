@@ -0,0 +1,350 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{common, db::DB, errors::GHDError};
+
+use super::{
+    rest,
+    types::{
+        GithubEndpoint, IssueTableEntry, PullRequestTableEntry, TrackedRepo,
+    },
+};
+
+/// Split a repository's full name (e.g., `owner/name`) into its owner and
+/// name. Returns `GHDError::RepositoryNotFoundError` if `repo` is not a valid
+/// full name.
+///
+/// # Arguments
+///
+/// * `repo` - String containing the repository's full name.
+///
+pub fn parse_repo(repo: &String) -> Result<(String, String), GHDError> {
+    match repo.trim().split_once('/') {
+        Some((owner, name))
+            if !owner.is_empty() && !name.is_empty() && !name.contains('/') =>
+        {
+            Ok((String::from(owner), String::from(name)))
+        }
+        _ => Err(GHDError::RepositoryNotFoundError),
+    }
+}
+
+/// Obtain a repository from Github, to make sure it exists and we can see it.
+///
+/// # Arguments
+///
+/// * `endpoint` - The Github API endpoint to use.
+/// * `owner` - String containing the repository's owner.
+/// * `name` - String containing the repository's name.
+///
+pub async fn get_repo_from_github(
+    endpoint: &GithubEndpoint,
+    owner: &String,
+    name: &String,
+) -> Result<TrackedRepo, GHDError> {
+    let ghreq = rest::GithubRequest::new(endpoint);
+    let req = ghreq.get(&format!("/repos/{}/{}", owner, name));
+    match ghreq.send::<rest::GithubRepoReply>(req).await {
+        Ok(res) => Ok(TrackedRepo {
+            id: res.id,
+            owner: res.owner.login,
+            name: res.name,
+        }),
+        Err(GHDError::NotFoundError) => Err(GHDError::RepositoryNotFoundError),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns a tracked repository from the database, if it exists.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `owner` - String containing the repository's owner.
+/// * `name` - String containing the repository's name.
+///
+pub async fn get_repo_by_name(
+    db: &DB,
+    owner: &String,
+    name: &String,
+) -> Result<TrackedRepo, GHDError> {
+    match sqlx::query_as::<_, TrackedRepo>(
+        "
+        SELECT id, owner, name
+        FROM tracked_repos
+        WHERE owner = ? COLLATE NOCASE AND name = ? COLLATE NOCASE
+        ",
+    )
+    .bind(&owner)
+    .bind(&name)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::RepositoryNotFoundError),
+        Err(err) => Err(GHDError::db("obtaining repository by name", err)),
+    }
+}
+
+/// Obtain a Vector of all tracked repositories.
+///
+/// * `db` - The GHD Database handle.
+///
+pub async fn get_tracked_repos(db: &DB) -> Result<Vec<TrackedRepo>, GHDError> {
+    match sqlx::query_as::<_, TrackedRepo>(
+        "
+        SELECT id, owner, name FROM tracked_repos
+        ORDER BY owner ASC, name ASC
+        ",
+    )
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining tracked repositories", err)),
+    }
+}
+
+/// Add a repository to the GHD database. This function requires a
+/// transaction. Presumes the repository does not exist in the database.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `repo` - The repository being added.
+///
+pub async fn add_repo_to_db(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    repo: &TrackedRepo,
+) -> Result<(), GHDError> {
+    match sqlx::query(
+        "
+        INSERT INTO tracked_repos (id, owner, name, refresh_at)
+        VALUES (?, ?, ?, NULL)
+        ",
+    )
+    .bind(&repo.id)
+    .bind(&repo.owner)
+    .bind(&repo.name)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("adding repository '{}'", repo.full_name()),
+            err,
+        )),
+    }
+}
+
+/// Obtain the sync cursor stored by the repository's last refresh, from
+/// which the next refresh should search for updates, if it has ever been
+/// refreshed.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `repo_id` - The repository's database ID.
+///
+pub async fn get_repo_sync_cursor(
+    db: &DB,
+    repo_id: &i64,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>, GHDError> {
+    let ts = match sqlx::query_scalar::<_, Option<i64>>(
+        "SELECT sync_cursor FROM tracked_repos WHERE id = ?",
+    )
+    .bind(&repo_id)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => res,
+        Err(sqlx::Error::RowNotFound) => {
+            return Err(GHDError::RepositoryNotFoundError);
+        }
+        Err(err) => {
+            return Err(GHDError::db("obtaining repository refresh time", err));
+        }
+    };
+
+    match ts {
+        None => Ok(None),
+        Some(v) => match common::ts_to_datetime(v) {
+            Ok(dt) => Ok(Some(dt)),
+            Err(_) => Err(GHDError::unexpected(&format!(
+                "invalid sync cursor {} for repository {}",
+                v, repo_id
+            ))),
+        },
+    }
+}
+
+/// Update when a repository was last refreshed, and the sync cursor to
+/// search from on its next refresh. This function requires a transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `repo_id` - The repository's database ID.
+/// * `when` - When the repository was refreshed.
+/// * `cursor` - The sync cursor to search from on the next refresh, if
+///   any.
+///
+pub async fn update_repo_refresh(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    repo_id: &i64,
+    when: &chrono::DateTime<chrono::Utc>,
+    cursor: &Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<(), GHDError> {
+    match sqlx::query(
+        "UPDATE tracked_repos SET refresh_at = ?, sync_cursor = ? WHERE id = ?",
+    )
+    .bind(&when.timestamp())
+    .bind(&cursor.map(|c| c.timestamp()))
+    .bind(&repo_id)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("updating repository {} refresh time", repo_id),
+            err,
+        )),
+    }
+}
+
+/// Obtain all repositories that are due for a refresh.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `interval` - Number of seconds between refreshes.
+///
+pub async fn get_to_refresh_repos(
+    db: &DB,
+    interval: &i64,
+) -> Result<Vec<TrackedRepo>, GHDError> {
+    let now = chrono::Utc::now().timestamp();
+
+    match sqlx::query_as::<_, TrackedRepo>(
+        "
+        SELECT id, owner, name FROM tracked_repos
+        WHERE COALESCE(refresh_at, 0) + ? <= ?
+        ORDER BY refresh_at ASC
+        ",
+    )
+    .bind(&interval)
+    .bind(&now)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining repositories to refresh", err)),
+    }
+}
+
+/// Obtain all open Pull Requests in the provided repository.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `repo` - The repository to obtain Pull Requests for.
+///
+pub async fn get_open_prs(
+    db: &DB,
+    repo: &TrackedRepo,
+) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+    match sqlx::query_as::<_, PullRequestTableEntry>(
+        "
+        SELECT
+            issues.*, pull_requests.is_draft, pull_requests.review_decision,
+            pull_requests.merged_at, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
+        FROM
+            pull_requests LEFT JOIN issues
+        ON
+            pull_requests.id = issues.id
+        WHERE
+            issues.repo_owner = ?
+            AND
+            issues.repo_name = ?
+            AND
+            issues.state = 'open'
+            AND
+            issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&repo.owner)
+    .bind(&repo.name)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!(
+                "obtaining pull requests for repository '{}'",
+                repo.full_name()
+            ),
+            err,
+        )),
+    }
+}
+
+/// Obtain all open Issues in the provided repository.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `repo` - The repository to obtain Issues for.
+///
+pub async fn get_open_issues(
+    db: &DB,
+    repo: &TrackedRepo,
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    match sqlx::query_as::<_, IssueTableEntry>(
+        "
+        SELECT
+            issues.*
+        FROM
+            issues
+        WHERE
+            issues.is_pull_request = False
+            AND
+            issues.repo_owner = ?
+            AND
+            issues.repo_name = ?
+            AND
+            issues.state = 'open'
+            AND
+            issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&repo.owner)
+    .bind(&repo.name)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining issues for repository '{}'", repo.full_name()),
+            err,
+        )),
+    }
+}
@@ -14,12 +14,16 @@
 
 use log::debug;
 
+use crate::errors::GHDError;
+
+use super::{ratelimit::RateLimitResource, types::GithubEndpoint};
+
 /// Abstracts REST requests. May be used as one GithubRequest per REST
 /// operation, or may be reused.
 ///
 pub struct GithubRequest {
     client: reqwest::Client,
-    token: String,
+    endpoint: GithubEndpoint,
 }
 
 impl GithubRequest {
@@ -27,12 +31,12 @@ impl GithubRequest {
     ///
     /// # Arguments
     ///
-    /// * `token` - String containing the API Token to use.
+    /// * `endpoint` - The Github API endpoint, and token, to use.
     ///
-    pub fn new(token: &String) -> Self {
+    pub fn new(endpoint: &GithubEndpoint) -> Self {
         GithubRequest {
             client: reqwest::Client::new(),
-            token: token.clone(),
+            endpoint: endpoint.clone(),
         }
     }
 
@@ -44,19 +48,20 @@ impl GithubRequest {
     /// * `endpoint` - String containing the target endpoint; e.g., `/user`.
     ///
     pub fn get(self: &Self, endpoint: &str) -> reqwest::RequestBuilder {
-        let ep = match endpoint.strip_prefix("/") {
-            Some(res) => res,
-            None => endpoint,
-        };
-
-        self.client.get(format!("https://api.github.com/{}", ep))
+        self.client.get(self.endpoint.rest_url(endpoint))
     }
 
     /// Send the request and return a result containing either the specified
-    /// type, or a `reqwest::StatusCode` as an error. Requires an existing
+    /// type, or a `GHDError` describing what went wrong. Requires an existing
     /// `reqwest::RequestBuilder` to be provided as argument. This function
     /// handles setting headers and the token.
     ///
+    /// Authentication failures result in a `GHDError::BadTokenError`, rate
+    /// limited requests in a `GHDError::RateLimitedError`, and a missing
+    /// resource in a `GHDError::NotFoundError`. Other forbidden requests, e.g.
+    /// due to SSO enforcement or a missing scope, result in a
+    /// `GHDError::HTTPError`, as the token remains valid.
+    ///
     /// # Arguments
     ///
     /// * `rb` - The pre-built `reqwest::RequestBuilder` to send to the server.
@@ -64,30 +69,63 @@ impl GithubRequest {
     pub async fn send<'a, T>(
         self: &Self,
         rb: reqwest::RequestBuilder,
-    ) -> Result<T, reqwest::StatusCode>
+    ) -> Result<T, GHDError>
     where
         T: for<'de> serde::Deserialize<'de>,
     {
-        let req = rb
-            .bearer_auth(&self.token)
+        let req = match rb
+            .bearer_auth(&self.endpoint.token)
             .header("User-Agent", "GHD")
             .header("Accept", "application/vnd.github+json")
             .send()
             .await
-            .unwrap();
+        {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::network("sending REST request", err));
+            }
+        };
+
+        self.endpoint
+            .ratelimit
+            .update_from_headers(RateLimitResource::REST, req.headers());
 
-        if req.status() != reqwest::StatusCode::OK {
-            return Err(req.status());
+        let status = req.status();
+        if status != reqwest::StatusCode::OK {
+            let headers = req.headers().clone();
+            let body = req.text().await.unwrap_or_default();
+            if self
+                .endpoint
+                .ratelimit
+                .check_rate_limited(status, &headers, &body)
+            {
+                return Err(GHDError::RateLimitedError);
+            }
+            return Err(match status {
+                reqwest::StatusCode::UNAUTHORIZED => GHDError::BadTokenError,
+                reqwest::StatusCode::NOT_FOUND => GHDError::NotFoundError,
+                _ => GHDError::HTTPError {
+                    context: String::from("REST request"),
+                    status: status.as_u16(),
+                },
+            });
         }
 
-        let txt = req.text().await.unwrap();
+        let txt = match req.text().await {
+            Ok(res) => res,
+            Err(err) => {
+                return Err(GHDError::network("reading REST response", err));
+            }
+        };
 
         if std::env::var("GHD_REST_DEBUG").is_ok() {
             debug!("REST(send result): {}", txt);
         }
 
-        let res: T = serde_json::from_str(&txt).unwrap();
-        Ok(res)
+        match serde_json::from_str::<T>(&txt) {
+            Ok(res) => Ok(res),
+            Err(err) => Err(GHDError::decode("decoding REST response", err)),
+        }
     }
 }
 
@@ -99,5 +137,21 @@ pub struct GithubUserReply {
     pub id: i64,
     pub node_id: String,
     pub avatar_url: String,
+    pub name: Option<String>,
+}
+
+/// REST API Repository Owner Reply
+///
+#[derive(serde::Deserialize)]
+pub struct GithubRepoOwnerReply {
+    pub login: String,
+}
+
+/// REST API Repository Reply
+///
+#[derive(serde::Deserialize)]
+pub struct GithubRepoReply {
+    pub id: i64,
     pub name: String,
+    pub owner: GithubRepoOwnerReply,
 }
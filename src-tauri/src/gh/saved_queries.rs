@@ -0,0 +1,424 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use log::{info, warn};
+
+use crate::{common, db::DB, errors::GHDError};
+
+use super::{
+    prs,
+    types::{IssueTableEntry, PullRequestTableEntry, SavedQuery},
+};
+
+/// Check whether the provided saved query name and search query are valid,
+/// returning their trimmed versions.
+///
+/// # Arguments
+///
+/// * `name` - String containing the saved query's name.
+/// * `query` - String containing the Github search query.
+///
+pub fn check_query(
+    name: &String,
+    query: &String,
+) -> Result<(String, String), GHDError> {
+    let name = name.trim();
+    let query = query.trim();
+    if name.is_empty() {
+        return Err(GHDError::InvalidSavedQueryError {
+            context: String::from("name must not be empty"),
+        });
+    }
+    if query.is_empty() {
+        return Err(GHDError::InvalidSavedQueryError {
+            context: String::from("query must not be empty"),
+        });
+    }
+    if query.contains("updated:") {
+        return Err(GHDError::InvalidSavedQueryError {
+            context: String::from(
+                "query must not filter on 'updated:', as it is used to \
+                 refresh results",
+            ),
+        });
+    }
+    Ok((String::from(name), String::from(query)))
+}
+
+/// Returns a saved query from the database, by its ID.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `id` - The saved query's database ID.
+///
+pub async fn get_query_by_id(
+    db: &DB,
+    id: &i64,
+) -> Result<SavedQuery, GHDError> {
+    match sqlx::query_as::<_, SavedQuery>(
+        "SELECT id, name, query FROM saved_queries WHERE id = ?",
+    )
+    .bind(&id)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::SavedQueryNotFoundError),
+        Err(err) => Err(GHDError::db("obtaining saved query by id", err)),
+    }
+}
+
+/// Returns a saved query from the database, by its name.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `name` - String containing the saved query's name.
+///
+pub async fn get_query_by_name(
+    db: &DB,
+    name: &String,
+) -> Result<SavedQuery, GHDError> {
+    match sqlx::query_as::<_, SavedQuery>(
+        "SELECT id, name, query FROM saved_queries WHERE name = ?",
+    )
+    .bind(&name)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::SavedQueryNotFoundError),
+        Err(err) => Err(GHDError::db("obtaining saved query by name", err)),
+    }
+}
+
+/// Obtain a Vector of all saved queries.
+///
+/// * `db` - The GHD Database handle.
+///
+pub async fn get_saved_queries(db: &DB) -> Result<Vec<SavedQuery>, GHDError> {
+    match sqlx::query_as::<_, SavedQuery>(
+        "SELECT id, name, query FROM saved_queries ORDER BY name ASC",
+    )
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining saved queries", err)),
+    }
+}
+
+/// Add a saved query to the GHD database, returning it. This function requires
+/// a transaction. Presumes no saved query with the same name exists in the
+/// database.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `name` - String containing the saved query's name.
+/// * `query` - String containing the Github search query.
+///
+pub async fn add_query_to_db(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    name: &String,
+    query: &String,
+) -> Result<SavedQuery, GHDError> {
+    match sqlx::query(
+        "
+        INSERT INTO saved_queries (name, query, refresh_at)
+        VALUES (?, ?, NULL)
+        ",
+    )
+    .bind(&name)
+    .bind(&query)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(res) => Ok(SavedQuery {
+            id: res.last_insert_rowid(),
+            name: name.clone(),
+            query: query.clone(),
+        }),
+        Err(err) => {
+            Err(GHDError::db(&format!("adding saved query '{}'", name), err))
+        }
+    }
+}
+
+/// Obtain the sync cursor stored by the saved query's last refresh, from
+/// which the next refresh should search for updates, if it has ever been
+/// refreshed.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `query_id` - The saved query's database ID.
+///
+pub async fn get_query_sync_cursor(
+    db: &DB,
+    query_id: &i64,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>, GHDError> {
+    let ts = match sqlx::query_scalar::<_, Option<i64>>(
+        "SELECT sync_cursor FROM saved_queries WHERE id = ?",
+    )
+    .bind(&query_id)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => res,
+        Err(sqlx::Error::RowNotFound) => {
+            return Err(GHDError::SavedQueryNotFoundError);
+        }
+        Err(err) => {
+            return Err(GHDError::db(
+                "obtaining saved query refresh time",
+                err,
+            ));
+        }
+    };
+
+    match ts {
+        None => Ok(None),
+        Some(v) => match common::ts_to_datetime(v) {
+            Ok(dt) => Ok(Some(dt)),
+            Err(_) => Err(GHDError::unexpected(&format!(
+                "invalid sync cursor {} for saved query {}",
+                v, query_id
+            ))),
+        },
+    }
+}
+
+/// Update when a saved query was last refreshed, and the sync cursor to
+/// search from on its next refresh. This function requires a transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `query_id` - The saved query's database ID.
+/// * `when` - When the saved query was refreshed.
+/// * `cursor` - The sync cursor to search from on the next refresh, if
+///   any.
+///
+pub async fn update_query_refresh(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    query_id: &i64,
+    when: &chrono::DateTime<chrono::Utc>,
+    cursor: &Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<(), GHDError> {
+    match sqlx::query(
+        "UPDATE saved_queries SET refresh_at = ?, sync_cursor = ? WHERE id = ?",
+    )
+    .bind(&when.timestamp())
+    .bind(&cursor.map(|c| c.timestamp()))
+    .bind(&query_id)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("updating saved query {} refresh time", query_id),
+            err,
+        )),
+    }
+}
+
+/// Obtain all saved queries that are due for a refresh.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `interval` - Number of seconds between refreshes.
+///
+pub async fn get_to_refresh_queries(
+    db: &DB,
+    interval: &i64,
+) -> Result<Vec<SavedQuery>, GHDError> {
+    let now = chrono::Utc::now().timestamp();
+
+    match sqlx::query_as::<_, SavedQuery>(
+        "
+        SELECT id, name, query FROM saved_queries
+        WHERE COALESCE(refresh_at, 0) + ? <= ?
+        ORDER BY refresh_at ASC
+        ",
+    )
+    .bind(&interval)
+    .bind(&now)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => {
+            Err(GHDError::db("obtaining saved queries to refresh", err))
+        }
+    }
+}
+
+/// Obtain all Pull Requests matching the provided saved query.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `query` - The saved query to obtain Pull Requests for.
+///
+pub async fn get_query_prs(
+    db: &DB,
+    query: &SavedQuery,
+) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+    match sqlx::query_as::<_, PullRequestTableEntry>(
+        "
+        SELECT
+            issues.*, pull_requests.is_draft, pull_requests.review_decision,
+            pull_requests.merged_at, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
+        FROM
+            query_issues
+            JOIN issues ON issues.id = query_issues.issue_id
+            JOIN pull_requests ON pull_requests.id = issues.id
+        WHERE
+            query_issues.query_id = ?
+            AND
+            issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&query.id)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!(
+                "obtaining pull requests for saved query '{}'",
+                query.name
+            ),
+            err,
+        )),
+    }
+}
+
+/// Obtain all Issues matching the provided saved query.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `query` - The saved query to obtain Issues for.
+///
+pub async fn get_query_issues(
+    db: &DB,
+    query: &SavedQuery,
+) -> Result<Vec<IssueTableEntry>, GHDError> {
+    match sqlx::query_as::<_, IssueTableEntry>(
+        "
+        SELECT
+            issues.*
+        FROM
+            query_issues JOIN issues ON issues.id = query_issues.issue_id
+        WHERE
+            query_issues.query_id = ?
+            AND
+            issues.is_pull_request = False
+            AND
+            issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(&query.id)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining issues for saved query '{}'", query.name),
+            err,
+        )),
+    }
+}
+
+/// Remove the specified saved query, along with any Issues and Pull Requests
+/// no longer referenced by anything else.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `id` - The saved query's database ID.
+///
+pub async fn remove_saved_query(
+    db: &DB,
+    id: &i64,
+) -> Result<SavedQuery, GHDError> {
+    let query = match get_query_by_id(&db, &id).await {
+        Ok(res) => res,
+        Err(err) => return Err(err),
+    };
+
+    let mut tx = match db.begin().await {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                "starting transaction to remove saved query",
+                err,
+            ));
+        }
+    };
+
+    for (table, column) in
+        [("query_issues", "query_id"), ("saved_queries", "id")]
+    {
+        if let Err(err) =
+            sqlx::query(&format!("DELETE FROM {} WHERE {} = ?", table, column))
+                .bind(&id)
+                .execute(&mut tx)
+                .await
+        {
+            if let Err(rb_err) = tx.rollback().await {
+                warn!("Unable to rollback broken transaction: {}", rb_err);
+            }
+            return Err(GHDError::db(
+                &format!("removing saved query {} from '{}'", id, table),
+                err,
+            ));
+        }
+    }
+
+    let removed = match prs::remove_orphaned_issues(&mut tx).await {
+        Ok(res) => res,
+        Err(err) => {
+            if let Err(rb_err) = tx.rollback().await {
+                warn!("Unable to rollback broken transaction: {}", rb_err);
+            }
+            return Err(err);
+        }
+    };
+
+    if let Err(err) = tx.commit().await {
+        return Err(GHDError::db(
+            "committing transaction to remove saved query",
+            err,
+        ));
+    }
+
+    info!(
+        "removed saved query '{}', removed {} orphaned issues",
+        query.name, removed
+    );
+    Ok(query)
+}
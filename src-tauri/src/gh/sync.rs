@@ -0,0 +1,210 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Number of seconds by which a search for updates overlaps the previous one.
+/// Github's search index lags behind, so an item updated shortly before a
+/// search may only be returned by a later search; without the overlap, such
+/// an item would be skipped forever.
+///
+pub const SYNC_OVERLAP_SECS: i64 = 300;
+
+/// Obtain the date since which we should search for updates, given the sync
+/// `cursor` stored by the previous refresh.
+///
+/// # Arguments
+///
+/// * `cursor` - When the previous refresh happened, according to Github.
+///
+pub fn search_since(
+    cursor: &chrono::DateTime<chrono::Utc>,
+) -> chrono::DateTime<chrono::Utc> {
+    *cursor - chrono::Duration::seconds(SYNC_OVERLAP_SECS)
+}
+
+/// Obtain the sync cursor to store after a refresh. Github's clock is the
+/// only one search results can be compared against, so we prefer the time
+/// reported by the server. Lacking it, the most recent update we have seen is
+/// known to be in Github's past; only if we have seen nothing do we rely on
+/// the local clock.
+///
+/// # Arguments
+///
+/// * `server_time` - The time reported by Github when the search ran, if any.
+/// * `max_updated_at` - The most recent update time among the search's
+///   results, if any.
+/// * `local_now` - The local time when the search ran.
+///
+pub fn next_cursor(
+    server_time: &Option<chrono::DateTime<chrono::Utc>>,
+    max_updated_at: &Option<chrono::DateTime<chrono::Utc>>,
+    local_now: &chrono::DateTime<chrono::Utc>,
+) -> chrono::DateTime<chrono::Utc> {
+    match (server_time, max_updated_at) {
+        (Some(t), _) => *t,
+        (None, Some(t)) => *t,
+        (None, None) => *local_now,
+    }
+}
+
+/// Check whether an item seen with update time `seen_updated_at` is older
+/// than the copy we have stored, updated at `stored_updated_at`, if any.
+/// Stale copies are returned by lagging searches, and must not overwrite
+/// what we have.
+///
+/// # Arguments
+///
+/// * `stored_updated_at` - Timestamp of the stored copy's update time, if
+///   the item is stored.
+/// * `seen_updated_at` - The update time of the copy we have just seen.
+///
+pub fn is_stale(
+    stored_updated_at: &Option<i64>,
+    seen_updated_at: &chrono::DateTime<chrono::Utc>,
+) -> bool {
+    match stored_updated_at {
+        None => false,
+        Some(ts) => seen_updated_at.timestamp() < *ts,
+    }
+}
+
+/// Check whether a search's results are truncated; i.e., whether Github has
+/// more results than those we obtained. Github stops returning results past
+/// its search limit without reporting further pages, so we also compare the
+/// number of results we obtained with the number Github reports.
+///
+/// # Arguments
+///
+/// * `has_next_page` - Whether Github reported further pages of results.
+/// * `issue_count` - The number of results reported by Github.
+/// * `obtained` - The number of results we obtained.
+///
+pub fn is_truncated(
+    has_next_page: bool,
+    issue_count: i64,
+    obtained: usize,
+) -> bool {
+    has_next_page || issue_count != obtained as i64
+}
+
+/// Obtain the sync cursor to store after a refresh that obtained `next`.
+/// Truncated results are missing some of the items updated since the
+/// `previous` cursor, and moving past them would skip those items for good;
+/// in that case we keep the `previous` cursor, searching from it again on the
+/// next refresh.
+///
+/// # Arguments
+///
+/// * `previous` - The sync cursor the refresh searched from, if any.
+/// * `next` - The sync cursor obtained by the refresh.
+/// * `truncated` - Whether the refresh's results are truncated.
+///
+pub fn cursor_after_refresh(
+    previous: &Option<chrono::DateTime<chrono::Utc>>,
+    next: &chrono::DateTime<chrono::Utc>,
+    truncated: bool,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    if truncated {
+        *previous
+    } else {
+        Some(*next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn at(secs: i64) -> chrono::DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn cursor_ignores_fast_local_clock() {
+        let server = at(0);
+        let local = server + Duration::hours(1);
+        let cursor = next_cursor(&Some(server), &None, &local);
+        assert_eq!(cursor, server);
+
+        // an item updated right after the search must be found next time.
+        let updated = server + Duration::seconds(1);
+        assert!(updated > search_since(&cursor));
+    }
+
+    #[test]
+    fn cursor_ignores_slow_local_clock() {
+        let server = at(0);
+        let local = server - Duration::hours(1);
+        let cursor = next_cursor(&Some(server), &Some(at(-10)), &local);
+        assert_eq!(cursor, server);
+    }
+
+    #[test]
+    fn cursor_falls_back_to_max_updated_at() {
+        let local = at(3600);
+        let cursor = next_cursor(&None, &Some(at(0)), &local);
+        assert_eq!(cursor, at(0));
+    }
+
+    #[test]
+    fn cursor_falls_back_to_local_clock() {
+        let local = at(0);
+        assert_eq!(next_cursor(&None, &None, &local), local);
+    }
+
+    #[test]
+    fn overlap_covers_search_lag() {
+        // updated before the previous search ran, but only indexed after.
+        let cursor = at(0);
+        let lagging = cursor - Duration::seconds(SYNC_OVERLAP_SECS - 1);
+        assert!(lagging > search_since(&cursor));
+
+        let too_old = cursor - Duration::seconds(SYNC_OVERLAP_SECS + 1);
+        assert!(too_old < search_since(&cursor));
+    }
+
+    #[test]
+    fn stale_copies_are_detected() {
+        let stored = Some(at(0).timestamp());
+        assert!(is_stale(&stored, &at(-1)));
+        assert!(!is_stale(&stored, &at(0)));
+        assert!(!is_stale(&stored, &at(1)));
+        assert!(!is_stale(&None, &at(0)));
+    }
+
+    #[test]
+    fn truncation_is_detected() {
+        assert!(!is_truncated(false, 42, 42));
+        assert!(is_truncated(true, 42, 42));
+        // past Github's search limit, no further pages are reported.
+        assert!(is_truncated(false, 1500, 1000));
+        assert!(is_truncated(false, 10, 12));
+    }
+
+    #[test]
+    fn truncated_refresh_keeps_cursor() {
+        let previous = Some(at(0));
+        let next = at(3600);
+        assert_eq!(cursor_after_refresh(&previous, &next, false), Some(next));
+        assert_eq!(cursor_after_refresh(&previous, &next, true), previous);
+        // never refreshed; search everything again.
+        assert_eq!(cursor_after_refresh(&None, &next, true), None);
+
+        // unfetched results updated after the previous cursor are found by
+        // the next search.
+        let cursor = cursor_after_refresh(&previous, &next, true).unwrap();
+        let unfetched = at(1);
+        assert!(unfetched > search_since(&cursor));
+    }
+}
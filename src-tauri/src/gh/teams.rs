@@ -0,0 +1,331 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{db::DB, errors::GHDError};
+
+use super::types::{
+    GithubTeam, GithubTeamInfo, GithubUser, PullRequestTableEntry,
+};
+
+/// Split a team's combined slug (e.g., `org/team`) into its organization and
+/// slug. Returns `GHDError::TeamNotFoundError` if `team` is not a valid
+/// combined slug.
+///
+/// # Arguments
+///
+/// * `team` - String containing the team's combined slug.
+///
+pub fn parse_team(team: &String) -> Result<(String, String), GHDError> {
+    match team.trim().split_once('/') {
+        Some((org, slug)) if !org.is_empty() && !slug.is_empty() => {
+            Ok((String::from(org), String::from(slug)))
+        }
+        _ => Err(GHDError::TeamNotFoundError),
+    }
+}
+
+/// Returns a team from the database, if it exists.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `org` - String containing the team's organization.
+/// * `slug` - String containing the team's slug.
+///
+pub async fn get_team_by_slug(
+    db: &DB,
+    org: &String,
+    slug: &String,
+) -> Result<GithubTeam, GHDError> {
+    match sqlx::query_as::<_, GithubTeam>(
+        "
+        SELECT id, org, slug, name
+        FROM teams
+        WHERE org = ? AND slug = ?
+        ",
+    )
+    .bind(&org)
+    .bind(&slug)
+    .fetch_one(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::TeamNotFoundError),
+        Err(err) => Err(GHDError::db("obtaining team by slug", err)),
+    }
+}
+
+/// Obtain a Vector of all tracked teams.
+///
+/// * `db` - The GHD Database handle.
+///
+pub async fn get_tracked_teams(db: &DB) -> Result<Vec<GithubTeam>, GHDError> {
+    match sqlx::query_as::<_, GithubTeam>(
+        "
+        SELECT id, org, slug, name FROM teams
+        ",
+    )
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining tracked teams", err)),
+    }
+}
+
+/// Obtain a Vector of all tracked teams the provided user is a member of.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `user_id` - The user's Github database ID.
+///
+pub async fn get_user_teams(
+    db: &DB,
+    user_id: &i64,
+) -> Result<Vec<GithubTeam>, GHDError> {
+    match sqlx::query_as::<_, GithubTeam>(
+        "
+        SELECT teams.id, teams.org, teams.slug, teams.name
+        FROM teams JOIN team_members ON team_members.team_id = teams.id
+        WHERE team_members.user_id = ?
+        ",
+    )
+    .bind(&user_id)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining teams for user {}", user_id),
+            err,
+        )),
+    }
+}
+
+/// Obtain all teams that are due for a refresh.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `interval` - Number of seconds between refreshes.
+///
+pub async fn get_to_refresh_teams(
+    db: &DB,
+    interval: &i64,
+) -> Result<Vec<GithubTeam>, GHDError> {
+    let now = chrono::Utc::now().timestamp();
+
+    match sqlx::query_as::<_, GithubTeam>(
+        "
+        SELECT id, org, slug, name FROM teams
+        WHERE COALESCE(refresh_at, 0) + ? <= ?
+        ORDER BY refresh_at ASC
+        ",
+    )
+    .bind(&interval)
+    .bind(&now)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db("obtaining teams to refresh", err)),
+    }
+}
+
+/// Obtain a team's members, as last obtained from Github.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `team_id` - The team's database ID.
+///
+pub async fn get_team_members(
+    db: &DB,
+    team_id: &i64,
+) -> Result<Vec<GithubUser>, GHDError> {
+    match sqlx::query_as::<_, GithubUser>(
+        "
+        SELECT user_id AS id, login, name, avatar_url
+        FROM team_members
+        WHERE team_id = ?
+        ORDER BY login ASC
+        ",
+    )
+    .bind(&team_id)
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!("obtaining members for team {}", team_id),
+            err,
+        )),
+    }
+}
+
+/// Add or update a team, and its members, in the GHD database. This function
+/// requires a transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `info` - The team, and its members, as obtained from Github.
+///
+pub async fn store_team(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    info: &GithubTeamInfo,
+) -> Result<(), GHDError> {
+    let team = &info.team;
+    if let Err(err) = sqlx::query(
+        "
+        INSERT INTO teams (id, org, slug, name)
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT (id) DO UPDATE SET
+            org = excluded.org, slug = excluded.slug, name = excluded.name
+        ",
+    )
+    .bind(&team.id)
+    .bind(&team.org)
+    .bind(&team.slug)
+    .bind(&team.name)
+    .execute(&mut *tx)
+    .await
+    {
+        return Err(GHDError::db(
+            &format!("storing team '{}'", team.combined_slug()),
+            err,
+        ));
+    }
+
+    // membership may have changed since we last saw this team.
+    if let Err(err) = sqlx::query("DELETE FROM team_members WHERE team_id = ?")
+        .bind(&team.id)
+        .execute(&mut *tx)
+        .await
+    {
+        return Err(GHDError::db(
+            &format!("clearing members for team {}", team.id),
+            err,
+        ));
+    }
+    for member in &info.members {
+        if let Err(err) = sqlx::query(
+            "
+            INSERT OR REPLACE INTO team_members (
+                team_id, user_id, login, name, avatar_url
+            ) VALUES (
+                ?, ?, ?, ?, ?
+            )
+            ",
+        )
+        .bind(&team.id)
+        .bind(&member.id)
+        .bind(&member.login)
+        .bind(&member.name)
+        .bind(&member.avatar_url)
+        .execute(&mut *tx)
+        .await
+        {
+            return Err(GHDError::db(
+                &format!("adding member for team {}", team.id),
+                err,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Update when a team was last refreshed. This function requires a
+/// transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `team_id` - The team's database ID.
+/// * `when` - When the team was refreshed.
+///
+pub async fn update_team_refresh(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    team_id: &i64,
+    when: &chrono::DateTime<chrono::Utc>,
+) -> Result<(), GHDError> {
+    match sqlx::query("UPDATE teams SET refresh_at = ? WHERE id = ?")
+        .bind(&when.timestamp())
+        .bind(&team_id)
+        .execute(&mut *tx)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("updating team {} refresh time", team_id),
+            err,
+        )),
+    }
+}
+
+/// Obtain all open Pull Requests for which a review has been requested from
+/// the provided `team`.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `team` - The team to obtain review requests for.
+///
+pub async fn get_review_requests(
+    db: &DB,
+    team: &GithubTeam,
+) -> Result<Vec<PullRequestTableEntry>, GHDError> {
+    match sqlx::query_as::<_, PullRequestTableEntry>(
+        "
+        SELECT
+            issues.*, pull_requests.is_draft, pull_requests.review_decision,
+            pull_requests.merged_at, pull_requests.checks_state,
+            pull_requests.mergeable, pull_requests.merge_state_status,
+            pull_requests.base_ref_name, pull_requests.head_ref_name,
+            pull_requests.merge_queue_position,
+            pull_requests.merge_queue_state,
+            pull_requests.merge_queue_enqueued_at,
+            pull_requests.merge_queue_eta
+        FROM
+            pull_requests LEFT JOIN issues
+        ON
+            pull_requests.id = issues.id
+        WHERE
+            pull_requests.id IN (
+                SELECT pr_id FROM pull_request_team_review_requests
+                WHERE team = ?
+            )
+            AND
+            issues.state = 'open'
+            AND
+            issues.archived_at IS NULL
+        ORDER BY issues.updated_at DESC
+        ",
+    )
+    .bind(team.combined_slug())
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(GHDError::db(
+            &format!(
+                "obtaining review requests for team '{}'",
+                team.combined_slug()
+            ),
+            err,
+        )),
+    }
+}
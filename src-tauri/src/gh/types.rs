@@ -12,6 +12,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::ratelimit::RateLimitTracker;
+
+/// Default Github API endpoint, as used by github.com.
+///
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// Describes how to reach the Github API: which token to use, and the API's
+/// base URL. The latter allows for using GHD with a Github Enterprise Server
+/// instance, whose API lives at `https://<host>/api/v3`. Requests made through
+/// this endpoint will report rate limit information to `ratelimit`.
+///
+#[derive(Clone)]
+pub struct GithubEndpoint {
+    pub token: String,
+    pub api_url: String,
+    pub ratelimit: RateLimitTracker,
+    pub max_pages: usize,
+}
+
+impl GithubEndpoint {
+    /// Obtain a new endpoint for the provided `token`. If `api_url` is not
+    /// provided, or is empty, defaults to `DEFAULT_API_URL`. Paginated
+    /// queries will follow at most `max_pages` pages.
+    ///
+    pub fn new(
+        token: &String,
+        api_url: &Option<String>,
+        ratelimit: &RateLimitTracker,
+        max_pages: usize,
+    ) -> Self {
+        let url = match api_url {
+            Some(v) if !v.trim().is_empty() => v.trim().trim_end_matches('/'),
+            _ => DEFAULT_API_URL,
+        };
+        GithubEndpoint {
+            token: token.clone(),
+            api_url: String::from(url),
+            ratelimit: ratelimit.clone(),
+            max_pages,
+        }
+    }
+
+    /// Obtain the full REST API URL for the provided `endpoint`; e.g., `/user`.
+    ///
+    pub fn rest_url(self: &Self, endpoint: &str) -> String {
+        let ep = match endpoint.strip_prefix("/") {
+            Some(res) => res,
+            None => endpoint,
+        };
+        format!("{}/{}", self.api_url, ep)
+    }
+
+    /// Obtain the GraphQL API URL. On Github Enterprise Server the REST API
+    /// lives at `/api/v3`, while the GraphQL API lives at `/api/graphql`.
+    ///
+    pub fn graphql_url(self: &Self) -> String {
+        match self.api_url.strip_suffix("/api/v3") {
+            Some(base) => format!("{}/api/graphql", base),
+            None => format!("{}/graphql", self.api_url),
+        }
+    }
+}
+
 // Users
 
 /// Describes a user, as it is kept in the database.
@@ -24,7 +87,84 @@ pub struct GithubUser {
     pub avatar_url: String,
 }
 
-#[derive(sqlx::FromRow)]
+/// Describes an organization's team, as it is kept in the database. Teams are
+/// identified on Github by their organization and slug; e.g., `org/team`.
+///
+#[derive(sqlx::FromRow, serde::Serialize, Clone)]
+pub struct GithubTeam {
+    pub id: i64,
+    pub org: String,
+    pub slug: String,
+    pub name: String,
+}
+
+impl GithubTeam {
+    /// Obtain the team's combined slug; e.g., `org/team`.
+    ///
+    pub fn combined_slug(self: &Self) -> String {
+        format!("{}/{}", self.org, self.slug)
+    }
+}
+
+/// A team, along with its members, as obtained from Github.
+///
+pub struct GithubTeamInfo {
+    pub team: GithubTeam,
+    pub members: Vec<GithubUser>,
+}
+
+/// Describes a tracked repository, as it is kept in the database.
+///
+#[derive(sqlx::FromRow, serde::Serialize, Clone)]
+pub struct TrackedRepo {
+    pub id: i64,
+    pub owner: String,
+    pub name: String,
+}
+
+impl TrackedRepo {
+    /// Obtain the repository's full name; e.g., `owner/name`.
+    ///
+    pub fn full_name(self: &Self) -> String {
+        format!("{}/{}", self.owner, self.name)
+    }
+}
+
+/// Describes a saved Github search query, as it is kept in the database; e.g.,
+/// `label:needs-backport is:open org:ourorg`.
+///
+#[derive(sqlx::FromRow, serde::Serialize, Clone)]
+pub struct SavedQuery {
+    pub id: i64,
+    pub name: String,
+    pub query: String,
+}
+
+/// The Pull Requests and Issues matching a saved query.
+///
+#[derive(serde::Serialize)]
+pub struct SavedQueryResults {
+    pub query: SavedQuery,
+    pub prs: Vec<PullRequestTableEntry>,
+    pub issues: Vec<IssueTableEntry>,
+}
+
+/// Describes a user's refresh state, as it is kept in the database. If
+/// `refresh_interval` is not set, the user is refreshed at the global refresh
+/// interval. Users with a higher `priority` are refreshed first.
+///
+#[derive(sqlx::FromRow, serde::Serialize, Clone)]
+pub struct UserRefreshEntry {
+    pub id: i64,
+    pub login: String,
+    pub refresh_at: Option<i64>,
+    pub refresh_interval: Option<i64>,
+    pub priority: i64,
+}
+
+/// Describes an issue, as it is kept in the database.
+///
+#[derive(sqlx::FromRow, serde::Serialize)]
 pub struct IssueTableEntry {
     pub id: i64,
     pub number: i64,
@@ -65,6 +205,60 @@ pub struct PullRequestTableEntry {
     pub is_draft: bool,
     pub review_decision: String,
     pub merged_at: Option<i64>,
+    pub checks_state: Option<String>,
+    pub mergeable: Option<String>,
+    pub merge_state_status: Option<String>,
+    pub base_ref_name: Option<String>,
+    pub head_ref_name: Option<String>,
+    pub merge_queue_position: Option<i64>,
+    pub merge_queue_state: Option<String>,
+    pub merge_queue_enqueued_at: Option<i64>,
+    pub merge_queue_eta: Option<i64>,
+}
+
+/// Describes a Pull Request's cached detailed information, as it is kept in
+/// the database. Labels, participants and reviews are kept in their own tables.
+///
+#[derive(sqlx::FromRow)]
+pub struct PullRequestInfoTableEntry {
+    pub number: i64,
+    pub title: String,
+    pub body_html: String,
+    pub author_id: i64,
+    pub author_login: String,
+    pub author_name: String,
+    pub author_avatar_url: String,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub url: String,
+    pub state: String,
+    pub is_draft: bool,
+    pub milestone_title: Option<String>,
+    pub milestone_state: Option<String>,
+    pub milestone_due_on: Option<i64>,
+    pub total_comments: i64,
+    pub checks_state: Option<String>,
+    pub mergeable: Option<String>,
+    pub merge_state_status: Option<String>,
+    pub base_ref_name: Option<String>,
+    pub head_ref_name: Option<String>,
+    pub merge_queue_position: Option<i64>,
+    pub merge_queue_state: Option<String>,
+    pub merge_queue_enqueued_at: Option<i64>,
+    pub merge_queue_eta: Option<i64>,
+    pub fetched_at: i64,
+}
+
+/// Describes a user associated with a cached Pull Request, either as a
+/// participant or as a reviewer. `state` is only set for reviews.
+///
+#[derive(sqlx::FromRow)]
+pub struct PullRequestUserTableEntry {
+    pub user_id: i64,
+    pub login: String,
+    pub name: String,
+    pub avatar_url: String,
+    pub state: Option<String>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -89,6 +283,12 @@ pub struct Issue {
     pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub is_pull_request: bool,
     pub last_viewed: Option<chrono::DateTime<chrono::Utc>>,
+    pub assignees: Vec<String>,
+    pub total_comments: i64,
+    pub last_commenter: Option<String>,
+    pub body_mentions: Vec<String>,
+    pub last_comment_mentions: Vec<String>,
+    pub commenters: Vec<String>,
 }
 
 #[derive(serde::Deserialize, serde::Serialize)]
@@ -97,12 +297,274 @@ pub struct PullRequest {
     pub is_draft: bool,
     pub review_decision: String,
     pub merged_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub checks_state: Option<String>,
+    pub mergeable: String,
+    pub merge_state_status: String,
+    pub base_ref_name: String,
+    pub head_ref_name: String,
+    pub merge_queue: Option<MergeQueueEntry>,
+    pub review_requests: Vec<String>,
+    pub team_review_requests: Vec<String>,
+    pub reviewers: Vec<String>,
 }
 
+/// Represents a Pull Request's entry in its base branch's merge queue.
+/// `estimated_time_to_merge` is in seconds, if known.
+///
+#[derive(serde::Deserialize, serde::Serialize)]
+pub struct MergeQueueEntry {
+    pub position: i64,
+    pub state: String,
+    pub enqueued_at: chrono::DateTime<chrono::Utc>,
+    pub enqueued_at_ts: i64,
+    pub estimated_time_to_merge: Option<i64>,
+}
+
+/// Issues and Pull Requests resulting from a search. `cursor` is the sync
+/// cursor to search from on the next refresh, according to Github's clock.
+/// `truncated` is set if there were more results than we obtained.
+///
 pub struct UserUpdate {
-    pub when: chrono::DateTime<chrono::Utc>,
+    pub cursor: chrono::DateTime<chrono::Utc>,
     pub issues: Vec<Issue>,
     pub prs: Vec<PullRequest>,
+    pub truncated: bool,
+}
+
+/// The kinds of activity on a tracked Issue or Pull Request that the main
+/// user may want to be notified about.
+///
+#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    ReviewRequested,
+    Approved,
+    ChangesRequested,
+    Merged,
+    NewComment,
+    Mention,
+}
+
+impl ActivityKind {
+    /// All known activity kinds.
+    ///
+    pub const ALL: [ActivityKind; 6] = [
+        ActivityKind::ReviewRequested,
+        ActivityKind::Approved,
+        ActivityKind::ChangesRequested,
+        ActivityKind::Merged,
+        ActivityKind::NewComment,
+        ActivityKind::Mention,
+    ];
+
+    /// Obtain this activity kind's name, as used in settings and events.
+    ///
+    pub fn name(self: &Self) -> &'static str {
+        match self {
+            ActivityKind::ReviewRequested => "review_requested",
+            ActivityKind::Approved => "approved",
+            ActivityKind::ChangesRequested => "changes_requested",
+            ActivityKind::Merged => "merged",
+            ActivityKind::NewComment => "new_comment",
+            ActivityKind::Mention => "mention",
+        }
+    }
+}
+
+/// The ways in which a user may be involved with an Issue or Pull Request. A
+/// user may be involved in more than one way, so these are kept in the
+/// database as a bit mask.
+///
+#[derive(
+    serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Debug,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Involvement {
+    Author = 1,
+    Assignee = 2,
+    Mentioned = 4,
+    Commenter = 8,
+    ReviewRequested = 16,
+    Reviewed = 32,
+}
+
+impl Involvement {
+    /// Obtain this kind of involvement's bit, as kept in the database.
+    ///
+    pub fn bit(self: &Self) -> i64 {
+        *self as i64
+    }
+}
+
+/// Represents a single piece of activity on a tracked Issue or Pull Request,
+/// as observed when refreshing the main user.
+///
+#[derive(serde::Serialize, Clone)]
+pub struct Activity {
+    pub kind: ActivityKind,
+    pub issue_id: i64,
+    pub number: i64,
+    pub title: String,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub url: String,
+    pub is_pull_request: bool,
+    pub actor: Option<String>,
+}
+
+/// Why an Issue or Pull Request needs the main user's attention. Variants are
+/// declared from most to least important, which is how inbox items are
+/// ranked.
+///
+#[derive(
+    serde::Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum InboxReason {
+    ReviewRequested,
+    ChangesRequested,
+    ChecksFailing,
+    TeamReviewRequested,
+    Mentioned,
+    Updated,
+}
+
+/// A reason for an inbox item, along with a human readable explanation.
+///
+#[derive(serde::Serialize)]
+pub struct InboxReasonEntry {
+    pub reason: InboxReason,
+    pub explanation: String,
+}
+
+/// An Issue or Pull Request needing the main user's attention. `reasons` is
+/// never empty, and is sorted by importance; the first reason is the one the
+/// item is ranked by.
+///
+#[derive(serde::Serialize)]
+pub struct InboxItem {
+    pub issue: IssueTableEntry,
+    pub reasons: Vec<InboxReasonEntry>,
+}
+
+/// The result of refreshing a user. `updated` is true if any Issues or Pull
+/// Requests were updated, in which case `changes` describes how they changed,
+/// and `activity` contains whatever activity may be relevant to the main user.
+///
+pub struct UserRefreshResult {
+    pub updated: bool,
+    pub changes: IssuesChangeset,
+    pub activity: Vec<Activity>,
+}
+
+/// Reports that a user's refresh has completed. `error` is set if the refresh
+/// failed.
+///
+#[derive(serde::Serialize, Clone)]
+pub struct UserRefreshCompleted {
+    pub login: String,
+    pub updated: bool,
+    pub error: Option<String>,
+}
+
+/// The fields of an Issue or Pull Request we track changes for. Pull Request
+/// specific fields are `None` for Issues.
+///
+#[derive(sqlx::FromRow, serde::Serialize, Clone, PartialEq)]
+pub struct IssueChangeFields {
+    pub title: String,
+    pub state: String,
+    pub updated_at: i64,
+    pub closed_at: Option<i64>,
+    pub is_draft: Option<bool>,
+    pub review_decision: Option<String>,
+    pub merged_at: Option<i64>,
+}
+
+/// Describes how an Issue or Pull Request changed. `before` is `None` if it
+/// was not previously in the database.
+///
+#[derive(serde::Serialize, Clone)]
+pub struct IssueChange {
+    pub id: i64,
+    pub is_pull_request: bool,
+    pub before: Option<IssueChangeFields>,
+    pub after: IssueChangeFields,
+}
+
+/// Describes the changes resulting from consuming a user's Issues and Pull
+/// Requests. Each list contains database IDs; `changes` contains the fields
+/// before and after the change for each inserted or updated entry. `removed`
+/// lists entries no longer associated with the user.
+///
+#[derive(serde::Serialize, Clone)]
+pub struct IssuesChangeset {
+    pub user_id: i64,
+    pub inserted: Vec<i64>,
+    pub updated: Vec<i64>,
+    pub state_changed: Vec<i64>,
+    pub closed: Vec<i64>,
+    pub merged: Vec<i64>,
+    pub removed: Vec<i64>,
+    pub changes: Vec<IssueChange>,
+}
+
+impl IssuesChangeset {
+    pub fn new(user_id: &i64) -> Self {
+        IssuesChangeset {
+            user_id: *user_id,
+            inserted: vec![],
+            updated: vec![],
+            state_changed: vec![],
+            closed: vec![],
+            merged: vec![],
+            removed: vec![],
+            changes: vec![],
+        }
+    }
+
+    /// Whether nothing has changed.
+    ///
+    pub fn is_empty(self: &Self) -> bool {
+        self.changes.is_empty() && self.removed.is_empty()
+    }
+
+    /// Record a change for the Issue or Pull Request with database ID `id`,
+    /// classifying it according to its fields `before` and `after`. Nothing is
+    /// recorded if the fields have not changed.
+    ///
+    pub fn record(
+        self: &mut Self,
+        id: &i64,
+        is_pull_request: bool,
+        before: Option<IssueChangeFields>,
+        after: IssueChangeFields,
+    ) {
+        match &before {
+            None => self.inserted.push(*id),
+            Some(prev) => {
+                if prev == &after {
+                    return;
+                }
+                self.updated.push(*id);
+                if prev.state != after.state {
+                    self.state_changed.push(*id);
+                    if after.state == "closed" {
+                        self.closed.push(*id);
+                    }
+                }
+                if prev.merged_at.is_none() && after.merged_at.is_some() {
+                    self.merged.push(*id);
+                }
+            }
+        };
+        self.changes.push(IssueChange {
+            id: *id,
+            is_pull_request,
+            before,
+            after,
+        });
+    }
 }
 
 /// Represents a Pull Request's detailed information.
@@ -123,6 +585,68 @@ pub struct PullRequestInfo {
     pub total_comments: i64,
     pub participants: Vec<GithubUser>,
     pub reviews: Vec<UserReview>,
+    pub checks_state: Option<String>,
+    pub checks: Vec<CheckInfo>,
+    pub mergeable: String,
+    pub merge_state_status: String,
+    pub base_ref_name: String,
+    pub head_ref_name: String,
+    pub merge_queue: Option<MergeQueueEntry>,
+}
+
+/// Represents a single CI check for a Pull Request's head commit, be it a
+/// check run or a commit status. `state` is the check's conclusion if it has
+/// completed, or its status otherwise.
+///
+#[derive(sqlx::FromRow, serde::Serialize)]
+pub struct CheckInfo {
+    pub name: String,
+    pub state: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+}
+
+/// Represents an Issue's detailed information.
+///
+#[derive(serde::Serialize)]
+pub struct IssueInfo {
+    pub number: i64,
+    pub title: String,
+    pub body_html: String,
+    pub author: GithubUser,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub url: String,
+    pub state: String,
+    pub milestone: Option<Milestone>,
+    pub labels: Vec<Label>,
+    pub total_comments: i64,
+    pub assignees: Vec<GithubUser>,
+    pub participants: Vec<GithubUser>,
+    pub linked_prs: Vec<LinkedPullRequest>,
+    pub reactions: Vec<Reaction>,
+}
+
+/// Represents a Pull Request linked to an Issue, either because it references
+/// the Issue, or because it has been manually connected to it.
+///
+#[derive(serde::Serialize)]
+pub struct LinkedPullRequest {
+    pub number: i64,
+    pub title: String,
+    pub url: String,
+    pub state: String,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub will_close: bool,
+}
+
+/// Represents the number of reactions of a given kind; e.g., `thumbs_up`.
+///
+#[derive(serde::Serialize)]
+pub struct Reaction {
+    pub content: String,
+    pub count: i64,
 }
 
 /// Represents a milestone.
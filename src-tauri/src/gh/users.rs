@@ -12,21 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use log::{debug, warn};
+use log::{debug, info, warn};
 
 use crate::{db::DB, errors::GHDError};
 
-use super::{rest, types::GithubUser};
+use super::{
+    prs, rest,
+    types::{GithubEndpoint, GithubUser},
+};
 
-/// Find out who I am, based on the provided API token. Returns a
+/// Find out who I am, based on the provided API endpoint's token. Returns a
 /// `GithubUser` struct with the user's information.
 ///
 /// # Arguments
 ///
-/// * `token` - String containing an API Token.
+/// * `endpoint` - The Github API endpoint, including an API Token.
 ///
-pub async fn whoami(token: &String) -> Result<GithubUser, reqwest::StatusCode> {
-    let ghreq = rest::GithubRequest::new(token);
+pub async fn whoami(endpoint: &GithubEndpoint) -> Result<GithubUser, GHDError> {
+    let ghreq = rest::GithubRequest::new(endpoint);
     let req = ghreq.get("/user");
     match ghreq.send::<rest::GithubUserReply>(req).await {
         Ok(res) => Ok(user_reply_to_user(res)),
@@ -57,7 +60,8 @@ pub async fn get_user_by_login(
     .await
     {
         Ok(res) => Ok(res),
-        Err(_) => Err(GHDError::UserNotFoundError),
+        Err(sqlx::Error::RowNotFound) => Err(GHDError::UserNotFoundError),
+        Err(err) => Err(GHDError::db("obtaining user by login", err)),
     }
 }
 
@@ -86,8 +90,8 @@ pub async fn user_exists(db: &DB, login: &String) -> bool {
 pub async fn add_user_to_db(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
     user: &GithubUser,
-) {
-    sqlx::query(
+) -> Result<(), GHDError> {
+    if let Err(err) = sqlx::query(
         "
         INSERT into users (id, login, name, avatar_url)
         VALUES (?, ?, ?, ?)
@@ -99,33 +103,158 @@ pub async fn add_user_to_db(
     .bind(&user.avatar_url)
     .execute(&mut *tx)
     .await
-    .unwrap_or_else(|err| {
-        panic!("Error inserting user into database: {}", err);
-    });
-
-    sqlx::query("INSERT into user_refresh (id, refresh_at) VALUES (?, -1)")
-        .bind(&user.id)
-        .execute(&mut *tx)
-        .await
-        .unwrap_or_else(|err| {
-            panic!("Error inserting user into refresh table: {}", err);
-        });
+    {
+        return Err(GHDError::db("inserting user into database", err));
+    }
+
+    if let Err(err) =
+        sqlx::query("INSERT into user_refresh (id, refresh_at) VALUES (?, -1)")
+            .bind(&user.id)
+            .execute(&mut *tx)
+            .await
+    {
+        return Err(GHDError::db("inserting user into refresh table", err));
+    }
+
+    Ok(())
 }
 
+/// Update when a user was last refreshed, according to the local clock, and
+/// the sync cursor to search from on the next refresh, according to Github's.
+/// This function requires a transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `userid` - The user's database ID.
+/// * `when` - When the user was refreshed.
+/// * `cursor` - The sync cursor to search from on the next refresh, if
+///   any.
+///
 pub async fn update_user_refresh(
     tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
     userid: &i64,
     when: &chrono::DateTime<chrono::Utc>,
-) {
-    let now = when.timestamp();
-    sqlx::query("UPDATE user_refresh SET refresh_at = ? WHERE id = ?")
-        .bind(&now)
-        .bind(&userid)
-        .execute(&mut *tx)
-        .await
-        .unwrap_or_else(|err| {
-            panic!("Error updating user {} refresh table: {}", userid, err);
-        });
+    cursor: &Option<chrono::DateTime<chrono::Utc>>,
+) -> Result<(), GHDError> {
+    match sqlx::query(
+        "UPDATE user_refresh SET refresh_at = ?, sync_cursor = ? WHERE id = ?",
+    )
+    .bind(&when.timestamp())
+    .bind(&cursor.map(|c| c.timestamp()))
+    .bind(&userid)
+    .execute(&mut *tx)
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("updating user {} refresh table", userid),
+            err,
+        )),
+    }
+}
+
+/// Remove a user from the GHD database, along with its refresh state and its
+/// association with Issues and Pull Requests. This function requires a
+/// transaction.
+///
+/// # Arguments
+///
+/// * `tx` - The sqlx transaction to piggy-back on.
+/// * `userid` - The database ID of the user being removed.
+///
+async fn remove_user_from_db(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    userid: &i64,
+) -> Result<(), GHDError> {
+    for (table, column) in [
+        ("user_issues", "user_id"),
+        ("user_refresh", "id"),
+        ("users", "id"),
+    ] {
+        if let Err(err) =
+            sqlx::query(&format!("DELETE FROM {} WHERE {} = ?", table, column))
+                .bind(&userid)
+                .execute(&mut *tx)
+                .await
+        {
+            return Err(GHDError::db(
+                &format!("removing user {} from '{}'", userid, table),
+                err,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Stop tracking a user, removing it from the database along with any Issues
+/// and Pull Requests no longer associated with a tracked user. GHD's main user,
+/// tied to the API token, can't be removed. Returns the removed user.
+///
+/// # Arguments
+///
+/// * `db` - The GHD Database handle.
+/// * `login` - A String representing the user login.
+///
+pub async fn remove_tracked_user(
+    db: &DB,
+    login: &String,
+) -> Result<GithubUser, GHDError> {
+    let user = match get_user_by_login(&db, &login).await {
+        Ok(res) => res,
+        Err(err) => return Err(err),
+    };
+
+    match get_main_user(&db).await {
+        Ok(main) if main.id == user.id => {
+            return Err(GHDError::invalid_state(&format!(
+                "unable to stop tracking main user '{}'",
+                login
+            )));
+        }
+        Ok(_) | Err(GHDError::UserNotSetError) => {}
+        Err(err) => return Err(err),
+    };
+
+    let mut tx = match db.begin().await {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db(
+                "starting transaction to remove tracked user",
+                err,
+            ));
+        }
+    };
+
+    if let Err(err) = remove_user_from_db(&mut tx, &user.id).await {
+        if let Err(rb_err) = tx.rollback().await {
+            warn!("Unable to rollback broken transaction: {}", rb_err);
+        }
+        return Err(err);
+    }
+
+    let removed = match prs::remove_orphaned_issues(&mut tx).await {
+        Ok(res) => res,
+        Err(err) => {
+            if let Err(rb_err) = tx.rollback().await {
+                warn!("Unable to rollback broken transaction: {}", rb_err);
+            }
+            return Err(err);
+        }
+    };
+
+    if let Err(err) = tx.commit().await {
+        return Err(GHDError::db(
+            "committing transaction to remove tracked user",
+            err,
+        ));
+    }
+
+    info!(
+        "stopped tracking user '{}', removed {} orphaned issues",
+        login, removed
+    );
+    Ok(user)
 }
 
 /// Obtain GHD's main user.
@@ -152,10 +281,13 @@ pub async fn get_main_user(db: &DB) -> Result<GithubUser, GHDError> {
             debug!("has user: {}", res.login);
             res
         }
-        Err(_) => {
+        Err(sqlx::Error::RowNotFound) => {
             warn!("no user found!");
             return Err(GHDError::UserNotSetError);
         }
+        Err(err) => {
+            return Err(GHDError::db("obtaining main user", err));
+        }
     };
 
     Ok(val)
@@ -175,7 +307,7 @@ pub async fn get_tracked_users(db: &DB) -> Result<Vec<GithubUser>, GHDError> {
     .await
     {
         Ok(res) => Ok(res),
-        Err(_) => Err(GHDError::UnknownError),
+        Err(err) => Err(GHDError::db("obtaining tracked users", err)),
     }
 }
 
@@ -186,6 +318,6 @@ pub fn user_reply_to_user(res: rest::GithubUserReply) -> GithubUser {
         login: res.login,
         id: res.id,
         avatar_url: res.avatar_url,
-        name: res.name,
+        name: res.name.unwrap_or(String::from("unknown")),
     }
 }
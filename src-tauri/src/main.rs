@@ -30,35 +30,39 @@ mod errors;
 mod events;
 mod gh;
 mod gh_types;
+mod notifications;
 mod paths;
 mod state;
 
 struct ManagedState {
-    state: tokio::sync::Mutex<state::State>,
+    state: state::State,
+    settings_changed: tokio::sync::Notify,
 }
 
 impl ManagedState {
-    pub async fn state(
-        self: &Self,
-    ) -> tokio::sync::MutexGuard<'_, state::State> {
-        self.state.lock().await
+    /// Obtain a handle to the shared state. This is cheap and doesn't lock
+    /// anything, so commands won't block behind a running refresh.
+    ///
+    pub fn state(self: &Self) -> state::State {
+        self.state.clone()
     }
 }
 
 #[tauri::command]
 async fn set_token(
     token: String,
+    api_url: Option<String>,
     window: tauri::Window,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<(), u16> {
-    debug!("set token to {}", token);
+) -> Result<(), GHDError> {
+    debug!("set token");
 
-    let state = &mstate.state().await;
+    let state = &mstate.state();
 
     let db = &state.db;
     let gh = &state.gh;
     match gh
-        .set_token(&db, &token, |user| {
+        .set_token(&db, &token, &api_url, |user| {
             events::emit_token_set(&window);
             events::emit_user_update(&window, &user);
         })
@@ -66,8 +70,8 @@ async fn set_token(
     {
         Ok(_) => {}
         Err(err) => {
-            error!("error setting token: {:?}", err);
-            return Err(err as u16);
+            error!("error setting token: {}", err);
+            return Err(err);
         }
     };
     Ok(())
@@ -77,20 +81,20 @@ async fn set_token(
 async fn get_token(
     window: tauri::Window,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<String, u16> {
-    let state = &mstate.state().await;
+) -> Result<String, GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
-    let token = match &gh.get_token(&db).await {
-        Ok(val) => val.clone(),
+    let token = match gh.get_token(&db).await {
+        Ok(val) => val,
         Err(err) => {
-            match err {
+            match &err {
                 GHDError::BadTokenError => {
                     events::emit_token_invalid(&window);
                 }
                 _ => {}
             };
-            return Err(*err as u16);
+            return Err(err);
         }
     };
 
@@ -100,24 +104,76 @@ async fn get_token(
 #[tauri::command]
 async fn get_main_user(
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<gh::types::GithubUser, ()> {
-    let state = &mstate.state().await;
+) -> Result<gh::types::GithubUser, GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     match gh::users::get_main_user(&db).await {
         Ok(res) => Ok(res),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
     }
 }
 
 #[tauri::command]
 async fn get_tracked_users(
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<Vec<gh::types::GithubUser>, ()> {
-    let state = &mstate.state().await;
+) -> Result<Vec<gh::types::GithubUser>, GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     match gh::users::get_tracked_users(&db).await {
         Ok(res) => Ok(res),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn get_user_refresh(
+    login: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::UserRefreshEntry, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::refresh::get_user_refresh_entry(&db, &login).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn set_user_refresh_interval(
+    login: String,
+    interval: Option<i64>,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::UserRefreshEntry, GHDError> {
+    debug!("set refresh interval for '{}' to {:?}", login, interval);
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::refresh::set_user_refresh_interval(&db, &login, &interval).await {
+        Ok(res) => {
+            mstate.settings_changed.notify_one();
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Error setting refresh interval for '{}': {}", login, err);
+            Err(err)
+        }
+    }
+}
+
+#[tauri::command]
+async fn set_user_priority(
+    login: String,
+    priority: i64,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::UserRefreshEntry, GHDError> {
+    debug!("set refresh priority for '{}' to {}", login, priority);
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::refresh::set_user_priority(&db, &login, &priority).await {
+        Ok(res) => Ok(res),
+        Err(err) => {
+            error!("Error setting refresh priority for '{}': {}", login, err);
+            Err(err)
+        }
     }
 }
 
@@ -126,9 +182,9 @@ async fn add_tracked_user(
     username: String,
     window: tauri::Window,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<gh::types::GithubUser, ()> {
+) -> Result<gh::types::GithubUser, GHDError> {
     debug!("track new user: {}", username);
-    let state = &mstate.state().await;
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
     match gh
@@ -138,7 +194,215 @@ async fn add_tracked_user(
         .await
     {
         Ok(res) => Ok(res),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn remove_tracked_user(
+    login: String,
+    window: tauri::Window,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::GithubUser, GHDError> {
+    debug!("untrack user: {}", login);
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::users::remove_tracked_user(&db, &login).await {
+        Ok(res) => {
+            events::emit_user_removed(&window, &res);
+            Ok(res)
+        }
+        Err(err) => {
+            error!("Error untracking user '{}': {}", login, err);
+            Err(err)
+        }
+    }
+}
+
+#[tauri::command]
+async fn add_tracked_team(
+    team: String,
+    window: tauri::Window,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::GithubTeam, GHDError> {
+    debug!("track new team: {}", team);
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh
+        .track_team(&db, &team, |t| {
+            events::emit_team_update(&window, &t);
+        })
+        .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn get_tracked_teams(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::GithubTeam>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::teams::get_tracked_teams(&db).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn team_get_members(
+    team_id: i64,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::GithubUser>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::teams::get_team_members(&db, &team_id).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn team_get_review_requests(
+    team: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::PullRequestTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh.get_team_review_requests(&db, &team).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn add_tracked_repo(
+    repo: String,
+    window: tauri::Window,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::TrackedRepo, GHDError> {
+    debug!("track new repository: {}", repo);
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh
+        .track_repo(&db, &repo, |r| {
+            events::emit_repo_update(&window, &r);
+        })
+        .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn get_tracked_repos(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::TrackedRepo>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::repos::get_tracked_repos(&db).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn repo_get_pull_requests(
+    repo: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::PullRequestTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh.get_repo_pulls(&db, &repo).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn repo_get_issues(
+    repo: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::IssueTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh.get_repo_issues(&db, &repo).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn add_saved_query(
+    name: String,
+    query: String,
+    window: tauri::Window,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::SavedQuery, GHDError> {
+    debug!("save new query '{}': {}", name, query);
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh
+        .save_query(&db, &name, &query, |q| {
+            events::emit_query_update(&window, &q);
+        })
+        .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn get_saved_queries(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::SavedQuery>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::saved_queries::get_saved_queries(&db).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn remove_saved_query(
+    id: i64,
+    window: tauri::Window,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::SavedQuery, GHDError> {
+    debug!("remove saved query {}", id);
+    let state = &mstate.state();
+    let db = &state.db;
+    match gh::saved_queries::remove_saved_query(&db, &id).await {
+        Ok(res) => {
+            events::emit_query_removed(&window, &res);
+            Ok(res)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn query_get_results(
+    id: i64,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::SavedQueryResults, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh.get_query_results(&db, &id).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
     }
 }
 
@@ -146,28 +410,49 @@ async fn add_tracked_user(
 async fn check_user_exists(
     username: String,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<gh::types::GithubUser, ()> {
+) -> Result<gh::types::GithubUser, GHDError> {
     debug!("check user exist: {}", username);
-    let state = &mstate.state().await;
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
     match gh.get_user_by_login(&db, &username).await {
         Ok(res) => Ok(res),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
     }
 }
 
+#[tauri::command]
+async fn get_inbox(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::InboxItem>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+    match gh.get_inbox(&db).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn get_rate_limit(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::ratelimit::RateLimitInfo, GHDError> {
+    let state = &mstate.state();
+    Ok(state.gh.get_rate_limit())
+}
+
 #[tauri::command]
 async fn pr_mark_viewed(
     prid: i64,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<(), ()> {
-    let state = &mstate.state().await;
+) -> Result<(), GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
     match gh.mark_pull_request_viewed(&db, &prid).await {
         Ok(_) => Ok(()),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
     }
 }
 
@@ -175,13 +460,13 @@ async fn pr_mark_viewed(
 async fn pr_mark_viewed_many(
     prs: Vec<i64>,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<(), ()> {
-    let state = &mstate.state().await;
+) -> Result<(), GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
     match gh.mark_pull_request_viewed_many(&db, &prs).await {
         Ok(_) => Ok(()),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
     }
 }
 
@@ -189,14 +474,14 @@ async fn pr_mark_viewed_many(
 async fn pr_get_list_by_author(
     login: String,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<Vec<gh::types::PullRequestTableEntry>, ()> {
-    let state = &mstate.state().await;
+) -> Result<Vec<gh::types::PullRequestTableEntry>, GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
 
     match gh.get_pulls_by_author(&db, &login).await {
         Ok(res) => Ok(res),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
     }
 }
 
@@ -204,34 +489,138 @@ async fn pr_get_list_by_author(
 async fn pr_get_list_by_involved(
     login: String,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<Vec<gh::types::PullRequestTableEntry>, ()> {
-    let state = &mstate.state().await;
+) -> Result<Vec<gh::types::PullRequestTableEntry>, GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
 
     match gh.get_involved_pulls(&db, &login).await {
         Ok(res) => Ok(res),
-        Err(_) => Err(()),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn pr_get_list_by_involvement(
+    login: String,
+    involvement: gh::types::Involvement,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::PullRequestTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+
+    match gh.get_pulls_by_involvement(&db, &login, &involvement).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn issue_get_list_by_author(
+    login: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::IssueTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+
+    match gh.get_issues_by_author(&db, &login).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn issue_get_list_by_involved(
+    login: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::IssueTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+
+    match gh.get_involved_issues(&db, &login).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn issue_get_list_by_involvement(
+    login: String,
+    involvement: gh::types::Involvement,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::IssueTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+
+    match gh
+        .get_issues_by_involvement(&db, &login, &involvement)
+        .await
+    {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
+    }
+}
+
+#[tauri::command]
+async fn issue_get_list_assigned(
+    login: String,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<Vec<gh::types::IssueTableEntry>, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+
+    match gh.get_assigned_issues(&db, &login).await {
+        Ok(res) => Ok(res),
+        Err(err) => Err(err),
     }
 }
 
 #[tauri::command]
 async fn pr_get_info(
     prid: i64,
+    window: tauri::Window,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<PullRequestInfo, ()> {
-    let state = &mstate.state().await;
+) -> Result<PullRequestInfo, GHDError> {
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
 
-    match gh.get_pull_request_info(&db, &prid).await {
+    match gh
+        .get_pull_request_info(&db, &prid, move |id| {
+            events::emit_pr_info_update(&window, &id);
+        })
+        .await
+    {
         Ok(res) => Ok(res),
         Err(err) => {
             warn!(
-                "Error obtaining pull request info, id: {}, err: {:?}",
+                "Error obtaining pull request info, id: {}, err: {}",
                 prid, err
             );
-            return Err(());
+            return Err(err);
+        }
+    }
+}
+
+#[tauri::command]
+async fn issue_get_info(
+    issue_id: i64,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<gh::types::IssueInfo, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    let gh = &state.gh;
+
+    match gh.get_issue_info(&db, &issue_id).await {
+        Ok(res) => Ok(res),
+        Err(err) => {
+            warn!("Error obtaining issue info, id: {}, err: {}", issue_id, err);
+            return Err(err);
         }
     }
 }
@@ -240,17 +629,17 @@ async fn pr_get_info(
 async fn archive_issue(
     issue_id: i64,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<(), ()> {
+) -> Result<(), GHDError> {
     debug!("Marking issue {} as archived", issue_id);
-    let state = &mstate.state().await;
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
 
     match gh.archive_issue(&db, &issue_id).await {
         Ok(_) => {}
         Err(err) => {
-            error!("Error archiving issue '{}': {:?}", issue_id, err);
-            return Err(());
+            error!("Error archiving issue '{}': {}", issue_id, err);
+            return Err(err);
         }
     };
 
@@ -261,43 +650,123 @@ async fn archive_issue(
 async fn archive_issue_many(
     issues: Vec<i64>,
     mstate: tauri::State<'_, ManagedState>,
-) -> Result<(), ()> {
+) -> Result<(), GHDError> {
     debug!("Marking {} issues as archived", issues.len());
-    let state = &mstate.state().await;
+    let state = &mstate.state();
     let db = &state.db;
     let gh = &state.gh;
 
     match gh.archive_issue_many(&db, &issues).await {
         Ok(_) => {}
         Err(err) => {
-            error!("Error archiving multiple issues: {:?}", err);
-            return Err(());
+            error!("Error archiving multiple issues: {}", err);
+            return Err(err);
         }
     };
     Ok(())
 }
 
+#[tauri::command]
+async fn notifications_get_settings(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<notifications::NotificationSettings, GHDError> {
+    let state = &mstate.state();
+    let db = &state.db;
+    match notifications::get_settings(&db).await {
+        Ok(res) => Ok(res),
+        Err(err) => {
+            error!("Error obtaining notification settings: {}", err);
+            Err(err)
+        }
+    }
+}
+
+#[tauri::command]
+async fn notifications_set_enabled(
+    kind: gh::types::ActivityKind,
+    enabled: bool,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<(), GHDError> {
+    debug!("set '{}' notifications enabled: {}", kind.name(), enabled);
+    let state = &mstate.state();
+    let db = &state.db;
+    match notifications::set_enabled(&db, &kind, enabled).await {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            error!(
+                "Error setting notifications for '{}': {}",
+                kind.name(),
+                err
+            );
+            Err(err)
+        }
+    }
+}
+
+#[tauri::command]
+async fn get_settings(
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<config::Config, GHDError> {
+    let state = &mstate.state();
+    Ok(state.get_config().await)
+}
+
+#[tauri::command]
+async fn set_setting(
+    key: String,
+    value: String,
+    window: tauri::Window,
+    mstate: tauri::State<'_, ManagedState>,
+) -> Result<config::Config, GHDError> {
+    debug!("set setting '{}' to '{}'", key, value);
+    let state = &mstate.state();
+    let cfg = {
+        let mut config = state.config.write().await;
+        match config.set(&state.db, &key, &value).await {
+            Ok(_) => state.gh.set_max_pages(config.max_pages),
+            Err(err) => {
+                error!("Error setting '{}': {}", key, err);
+                return Err(err);
+            }
+        };
+        config.clone()
+    };
+
+    events::emit_settings_changed(&window, &cfg);
+    mstate.settings_changed.notify_one();
+    Ok(cfg)
+}
+
 async fn setup_paths() -> paths::Paths {
     paths::Paths::default().init().await
 }
 
-async fn setup_db(path: &std::path::PathBuf) -> db::DB {
-    let mut handle = db::DB::new(&path).setup().await;
-    handle.connect().await;
-
-    handle
+async fn setup_db(path: &std::path::PathBuf) -> Result<db::DB, GHDError> {
+    db::DB::open(&path).await
 }
 
-async fn setup_config() -> config::Config {
-    config::Config::default()
+async fn setup_config(db: &db::DB) -> config::Config {
+    match config::Config::load(&db).await {
+        Ok(cfg) => cfg,
+        Err(err) => {
+            warn!("Unable to load settings, using defaults: {}", err);
+            config::Config::default()
+        }
+    }
 }
 
 #[tokio::main]
 async fn main() {
     env_logger::init();
     let paths = setup_paths().await;
-    let db_handle = setup_db(&paths.db_path).await;
-    let cfg = setup_config().await;
+    let db_handle = match setup_db(&paths.db_path).await {
+        Ok(handle) => handle,
+        Err(err) => {
+            error!("Unable to set up database: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let cfg = setup_config(&db_handle).await;
 
     info!("  user data dir: {}", paths.data_dir.display());
     info!("user config dir: {}", paths.config_dir.display());
@@ -307,27 +776,51 @@ async fn main() {
 
     tauri::Builder::default()
         .manage(ManagedState {
-            state: tokio::sync::Mutex::new(state::State {
-                config: cfg,
-                db: db_handle,
-                paths: paths,
-                gh: gh::Github::new(),
-            }),
+            state: state::State::new(cfg, db_handle, paths),
+            settings_changed: tokio::sync::Notify::new(),
         })
         .invoke_handler(tauri::generate_handler![
             set_token,
             get_token,
             get_main_user,
             get_tracked_users,
+            get_user_refresh,
+            set_user_refresh_interval,
+            set_user_priority,
             add_tracked_user,
+            remove_tracked_user,
+            add_tracked_team,
+            get_tracked_teams,
+            team_get_members,
+            team_get_review_requests,
+            add_tracked_repo,
+            get_tracked_repos,
+            repo_get_pull_requests,
+            repo_get_issues,
+            add_saved_query,
+            get_saved_queries,
+            remove_saved_query,
+            query_get_results,
+            get_inbox,
             check_user_exists,
+            get_rate_limit,
             pr_mark_viewed,
             pr_mark_viewed_many,
             pr_get_list_by_author,
             pr_get_list_by_involved,
+            pr_get_list_by_involvement,
             pr_get_info,
+            issue_get_list_by_author,
+            issue_get_list_by_involved,
+            issue_get_list_by_involvement,
+            issue_get_list_assigned,
+            issue_get_info,
             archive_issue,
             archive_issue_many,
+            notifications_get_settings,
+            notifications_set_enabled,
+            get_settings,
+            set_setting,
         ])
         .setup(|app| {
             let handle = app.app_handle();
@@ -0,0 +1,240 @@
+// Copyright 2023 Joao Eduardo Luis <joao@abysmo.io>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use log::{debug, warn};
+use tauri::api::notification::Notification;
+
+use crate::{
+    config::ConfigEntry,
+    db::DB,
+    errors::GHDError,
+    gh::types::{Activity, ActivityKind},
+};
+
+/// Maximum number of notifications to raise at once. Beyond this, a single
+/// summary notification is raised instead.
+///
+const MAX_NOTIFICATIONS: usize = 5;
+
+/// Per activity kind notification toggles. All kinds are enabled by default.
+///
+#[derive(serde::Deserialize, serde::Serialize, Clone)]
+pub struct NotificationSettings {
+    pub review_requested: bool,
+    pub approved: bool,
+    pub changes_requested: bool,
+    pub merged: bool,
+    pub new_comment: bool,
+    pub mention: bool,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        NotificationSettings {
+            review_requested: true,
+            approved: true,
+            changes_requested: true,
+            merged: true,
+            new_comment: true,
+            mention: true,
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// Check whether notifications for the provided activity `kind` are
+    /// enabled.
+    ///
+    pub fn is_enabled(self: &Self, kind: &ActivityKind) -> bool {
+        match kind {
+            ActivityKind::ReviewRequested => self.review_requested,
+            ActivityKind::Approved => self.approved,
+            ActivityKind::ChangesRequested => self.changes_requested,
+            ActivityKind::Merged => self.merged,
+            ActivityKind::NewComment => self.new_comment,
+            ActivityKind::Mention => self.mention,
+        }
+    }
+
+    fn set_enabled(self: &mut Self, kind: &ActivityKind, value: bool) {
+        match kind {
+            ActivityKind::ReviewRequested => self.review_requested = value,
+            ActivityKind::Approved => self.approved = value,
+            ActivityKind::ChangesRequested => self.changes_requested = value,
+            ActivityKind::Merged => self.merged = value,
+            ActivityKind::NewComment => self.new_comment = value,
+            ActivityKind::Mention => self.mention = value,
+        };
+    }
+}
+
+/// Obtain the settings key for the provided activity `kind`.
+///
+fn setting_key(kind: &ActivityKind) -> String {
+    format!("notifications.{}", kind.name())
+}
+
+/// Obtain the notification settings from the database. Settings that have
+/// never been set take their default value.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+///
+pub async fn get_settings(db: &DB) -> Result<NotificationSettings, GHDError> {
+    let entries = match sqlx::query_as::<_, ConfigEntry>(
+        "SELECT key, value FROM settings WHERE key LIKE 'notifications.%'",
+    )
+    .fetch_all(db.pool())
+    .await
+    {
+        Ok(res) => res,
+        Err(err) => {
+            return Err(GHDError::db("obtaining notification settings", err));
+        }
+    };
+
+    let mut settings = NotificationSettings::default();
+    for kind in &ActivityKind::ALL {
+        let key = setting_key(kind);
+        if let Some(entry) = entries.iter().find(|e| e.key == key) {
+            settings.set_enabled(kind, entry.value == "true");
+        }
+    }
+    Ok(settings)
+}
+
+/// Enable or disable notifications for the provided activity `kind`.
+///
+/// # Arguments
+///
+/// * `db` - A GHD Database handle.
+/// * `kind` - The activity kind to toggle notifications for.
+/// * `enabled` - Whether notifications should be raised for `kind`.
+///
+pub async fn set_enabled(
+    db: &DB,
+    kind: &ActivityKind,
+    enabled: bool,
+) -> Result<(), GHDError> {
+    match sqlx::query(
+        "
+        INSERT OR REPLACE INTO settings (key, value) VALUES (?, ?)
+        ",
+    )
+    .bind(setting_key(kind))
+    .bind(if enabled { "true" } else { "false" })
+    .execute(db.pool())
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(err) => Err(GHDError::db(
+            &format!("setting '{}' notifications", kind.name()),
+            err,
+        )),
+    }
+}
+
+/// Obtain a notification's title and body for the provided `activity`.
+///
+fn describe(activity: &Activity) -> (String, String) {
+    let what = if activity.is_pull_request {
+        "pull request"
+    } else {
+        "issue"
+    };
+    let actor = match &activity.actor {
+        None => String::from("Someone"),
+        Some(login) => format!("@{}", login),
+    };
+
+    let title = match activity.kind {
+        ActivityKind::ReviewRequested => {
+            format!("{} requested your review", actor)
+        }
+        ActivityKind::Approved => format!("Your {} was approved", what),
+        ActivityKind::ChangesRequested => {
+            format!("Changes requested on your {}", what)
+        }
+        ActivityKind::Merged => format!("Your {} was merged", what),
+        ActivityKind::NewComment => format!("{} commented", actor),
+        ActivityKind::Mention => format!("{} mentioned you", actor),
+    };
+    let body = format!(
+        "{}/{}#{}: {}",
+        activity.repo_owner,
+        activity.repo_name,
+        activity.number,
+        activity.title
+    );
+    (title, body)
+}
+
+/// Raise native notifications for the provided `activity`, honoring the
+/// per activity kind settings. If there is too much activity to notify about
+/// individually, a single summary notification is raised instead.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle.
+/// * `db` - A GHD Database handle.
+/// * `activity` - The activity to notify about.
+///
+pub async fn notify(app: &tauri::AppHandle, db: &DB, activity: &Vec<Activity>) {
+    if activity.is_empty() {
+        return;
+    }
+
+    let settings = match get_settings(&db).await {
+        Ok(res) => res,
+        Err(err) => {
+            warn!("Unable to obtain notification settings: {}", err);
+            return;
+        }
+    };
+    let to_notify: Vec<&Activity> = activity
+        .iter()
+        .filter(|a| settings.is_enabled(&a.kind))
+        .collect();
+    if to_notify.is_empty() {
+        return;
+    }
+
+    let identifier = app.config().tauri.bundle.identifier.clone();
+    let mut notifications: Vec<(String, String)> = vec![];
+    if to_notify.len() > MAX_NOTIFICATIONS {
+        notifications.push((
+            String::from("New activity on Github"),
+            format!(
+                "{} updates on your pull requests and issues",
+                to_notify.len()
+            ),
+        ));
+    } else {
+        for a in &to_notify {
+            notifications.push(describe(a));
+        }
+    }
+
+    for (title, body) in notifications {
+        debug!("notify: {}", title);
+        if let Err(err) = Notification::new(&identifier)
+            .title(title)
+            .body(body)
+            .show()
+        {
+            warn!("Unable to show notification: {}", err);
+        }
+    }
+}
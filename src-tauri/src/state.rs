@@ -12,11 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
 use crate::{config::Config, db::DB, gh::Github, paths::Paths};
 
+/// GHD's shared state. Cloning the state is cheap, and all clones refer to the
+/// same database pool, Github client and configuration. Only the configuration
+/// is mutable, and it is locked on its own, so that no lock needs to be held
+/// across network I/O.
+///
+#[derive(Clone)]
 pub struct State {
-    pub config: Config,
+    pub config: Arc<RwLock<Config>>,
     pub db: DB,
     pub gh: Github,
-    pub paths: Paths,
+    pub paths: Arc<Paths>,
+}
+
+impl State {
+    /// Obtain new shared state.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - GHD's configuration, as loaded from the database.
+    /// * `db` - The GHD Database handle.
+    /// * `paths` - GHD's paths.
+    ///
+    pub fn new(config: Config, db: DB, paths: Paths) -> Self {
+        State {
+            gh: Github::new(config.max_pages),
+            config: Arc::new(RwLock::new(config)),
+            db,
+            paths: Arc::new(paths),
+        }
+    }
+
+    /// Obtain a copy of the current configuration.
+    ///
+    pub async fn get_config(self: &Self) -> Config {
+        self.config.read().await.clone()
+    }
 }
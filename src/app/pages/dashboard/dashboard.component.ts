@@ -24,6 +24,8 @@ import {
 } from "src/app/shared/services/github.service";
 import { NgbModal } from "@ng-bootstrap/ng-bootstrap";
 import { TrackUserModalComponent } from "./track-user-modal/track-user-modal.component";
+import { TauriService } from "src/app/shared/services/tauri.service";
+import { GHDError } from "src/app/shared/types";
 
 type GithubUser = {
   id: number;
@@ -53,6 +55,7 @@ export class DashboardComponent
   public constructor(
     private modalSvc: NgbModal,
     private ghSvc: GithubService,
+    private tauriSvc: TauriService,
   ) {}
 
   public ngOnInit(): void {
@@ -80,6 +83,9 @@ export class DashboardComponent
           userlst.push(res[login]);
         });
         this.trackedUsers = [user, ...userlst];
+        if (!!this.selectedUser && !res[this.selectedUser.login]) {
+          this.selectedUser = undefined;
+        }
         if (!this.selectedUser) {
           this.selectedUser = user;
           console.log("select default user: ", this.selectedUser);
@@ -107,6 +113,23 @@ export class DashboardComponent
     this.modalSvc.open(TrackUserModalComponent);
   }
 
+  public canUntrackSelected(): boolean {
+    return (
+      !!this.selectedUser &&
+      this.selectedUser.login !== this.ghSvc.getMainUser()
+    );
+  }
+
+  public untrackSelectedUser(): void {
+    if (!this.selectedUser || !this.canUntrackSelected()) {
+      return;
+    }
+    const login = this.selectedUser.login;
+    this.tauriSvc.removeTrackedUser(login).catch((err: GHDError) => {
+      console.error("Error untracking user ", login, ": ", err);
+    });
+  }
+
   public selectUser(user: GithubUser | undefined): void {
     this.selectedUser = user;
   }
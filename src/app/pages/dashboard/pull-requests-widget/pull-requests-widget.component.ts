@@ -20,6 +20,7 @@ import {
 } from "src/app/shared/services/tauri.service";
 import {
   GithubUser,
+  IssuesChangeset,
   PRTableEntry,
   PullRequestEntry,
   TrackedPRs,
@@ -55,6 +56,7 @@ export class PullRequestsWidgetComponent
   };
 
   private userUpdateSubscription?: Subscription;
+  private changed: Set<number> = new Set();
 
   public constructor(
     private zone: NgZone,
@@ -63,7 +65,7 @@ export class PullRequestsWidgetComponent
   ) {}
 
   public ngOnInit(): void {
-    this.tauriSvc.register(TauriService.events.USER_DATA_UPDATE, this);
+    this.tauriSvc.register(TauriService.events.ISSUES_CHANGED, this);
 
     this.userUpdateSubscription = this.prsSvc
       .getPullRequests(this.user.login)
@@ -76,7 +78,7 @@ export class PullRequestsWidgetComponent
   }
 
   public ngOnDestroy(): void {
-    this.tauriSvc.unregister(TauriService.events.USER_DATA_UPDATE, this);
+    this.tauriSvc.unregister(TauriService.events.ISSUES_CHANGED, this);
     if (!!this.userUpdateSubscription) {
       this.userUpdateSubscription.unsubscribe();
     }
@@ -87,8 +89,15 @@ export class PullRequestsWidgetComponent
   }
 
   public handleEvent(event: TauriListenerEvent): void {
-    if (event.name === TauriService.events.USER_DATA_UPDATE) {
-      console.debug(`received user data update for user '${this.user.login}'`);
+    if (event.name === TauriService.events.ISSUES_CHANGED) {
+      const changes = event.payload as IssuesChangeset;
+      if (changes.user_id !== this.user.id) {
+        return;
+      }
+      console.debug(
+        `received ${changes.changes.length} changes for user '${this.user.login}'`,
+      );
+      this.changed = new Set(changes.changes.map((c) => c.id));
       this.zone.run(() => {
         this.updateUser().then(() => {});
       });
@@ -129,6 +138,11 @@ export class PullRequestsWidgetComponent
           map(() => this.getDateDiff(pr.updated_at)),
         ),
         reviewDecision: pr.review_decision,
+        needsRebase:
+          pr.mergeable === "conflicting" ||
+          pr.merge_state_status === "behind" ||
+          pr.merge_state_status === "dirty",
+        changed: this.changed.has(pr.id),
       };
       if (!!pr.last_viewed && pr.last_viewed >= pr.updated_at) {
         viewed.push(entry);
@@ -8,7 +8,17 @@ import {
 } from "@angular/forms";
 import { invoke } from "@tauri-apps/api";
 import { TauriService } from "src/app/shared/services/tauri.service";
-import { GHDError } from "src/app/shared/types";
+import {
+  ActivityKind,
+  GHDError,
+  GHDErrorType,
+  NotificationSettings,
+  Settings,
+} from "src/app/shared/types";
+
+/// A numeric setting, as shown on the settings page.
+///
+type SettingKind = { key: keyof Settings; label: string; unit: string };
 
 @Component({
   selector: "ghd-settings",
@@ -25,6 +35,34 @@ export class SettingsComponent implements OnInit {
   public successSettingToken = false;
   public tokenIsValidated = false;
   public errorInvalidToken = false;
+  public notificationSettings?: NotificationSettings;
+  public notificationKinds: { kind: ActivityKind; label: string }[] = [
+    { kind: ActivityKind.ReviewRequested, label: "Review requested" },
+    { kind: ActivityKind.Approved, label: "Pull request approved" },
+    { kind: ActivityKind.ChangesRequested, label: "Changes requested" },
+    { kind: ActivityKind.Merged, label: "Pull request merged" },
+    { kind: ActivityKind.NewComment, label: "New comment" },
+    { kind: ActivityKind.Mention, label: "Mentioned" },
+  ];
+
+  public settings?: Settings;
+  public settingErrors: Map<string, string> = new Map();
+  public settingKinds: SettingKind[] = [
+    { key: "refresh_interval", label: "Refresh interval", unit: "seconds" },
+    { key: "poll_interval", label: "Poll interval", unit: "seconds" },
+    { key: "error_backoff", label: "Error backoff", unit: "seconds" },
+    {
+      key: "refresh_concurrency",
+      label: "Concurrent refreshes",
+      unit: "users",
+    },
+    {
+      key: "reconcile_interval",
+      label: "Reconciliation interval",
+      unit: "seconds",
+    },
+    { key: "max_pages", label: "Max result pages", unit: "pages" },
+  ];
 
   private apiToken: string = "";
 
@@ -32,6 +70,8 @@ export class SettingsComponent implements OnInit {
 
   public ngOnInit(): void {
     this.refreshToken();
+    this.refreshNotificationSettings();
+    this.refreshSettings();
   }
 
   public setToken() {
@@ -46,12 +86,12 @@ export class SettingsComponent implements OnInit {
         this.successSettingToken = true;
         this.errorSettingToken = false;
       })
-      .catch((err: number) => {
+      .catch((err: GHDError) => {
         console.error("Error setting token: ", err);
         this.errorSettingToken = true;
         this.successSettingToken = false;
 
-        if (err === GHDError.BadTokenError) {
+        if (err.type === GHDErrorType.BadTokenError) {
           this.errorInvalidToken = true;
         }
       });
@@ -78,6 +118,66 @@ export class SettingsComponent implements OnInit {
     );
   }
 
+  public isNotificationEnabled(kind: ActivityKind): boolean {
+    if (!this.notificationSettings) {
+      return false;
+    }
+    return this.notificationSettings[kind];
+  }
+
+  public toggleNotification(kind: ActivityKind) {
+    const enabled = !this.isNotificationEnabled(kind);
+    this.tauriSvc
+      .setNotificationsEnabled(kind, enabled)
+      .then(() => {
+        if (!!this.notificationSettings) {
+          this.notificationSettings[kind] = enabled;
+        }
+      })
+      .catch((err: GHDError) => {
+        console.error("Error setting notifications: ", err);
+      });
+  }
+
+  public setSetting(key: keyof Settings, value: string) {
+    this.tauriSvc
+      .setSetting(key, value)
+      .then((res: Settings) => {
+        this.settings = res;
+        this.settingErrors.delete(key);
+      })
+      .catch((err: GHDError) => {
+        console.error("Error setting ", key, ": ", err);
+        let msg = "Unable to save setting";
+        if (err.type === GHDErrorType.InvalidSettingError && !!err.context) {
+          msg = err.context;
+        }
+        this.settingErrors.set(key, msg);
+      });
+  }
+
+  private refreshSettings() {
+    this.tauriSvc
+      .getSettings()
+      .then((res: Settings) => {
+        this.settings = res;
+      })
+      .catch((err: GHDError) => {
+        console.error("Error obtaining settings: ", err);
+      });
+  }
+
+  private refreshNotificationSettings() {
+    this.tauriSvc
+      .getNotificationSettings()
+      .then((res: NotificationSettings) => {
+        this.notificationSettings = res;
+      })
+      .catch((err: GHDError) => {
+        console.error("Error obtaining notification settings: ", err);
+      });
+  }
+
   private refreshToken() {
     this.tauriSvc
       .getToken()
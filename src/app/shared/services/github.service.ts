@@ -14,7 +14,7 @@
 
 import { Injectable, NgZone } from "@angular/core";
 import { BehaviorSubject } from "rxjs";
-import { GHDError, GithubUser } from "../types";
+import { GHDError, GHDErrorType, GithubUser } from "../types";
 import {
   TauriEventListener,
   TauriListenerEvent,
@@ -43,6 +43,7 @@ export class GithubService implements TauriEventListener {
   public constructor(private zone: NgZone, private tauriSvc: TauriService) {
     this.tauriSvc.register(TauriService.events.TOKEN_SET, this);
     this.tauriSvc.register(TauriService.events.USER_UPDATE, this);
+    this.tauriSvc.register(TauriService.events.USER_REMOVED, this);
     this.init();
   }
 
@@ -62,6 +63,10 @@ export class GithubService implements TauriEventListener {
         }
         this.users[user.login] = user;
         this.usersSubject.next(this.users);
+      } else if (event.name === TauriService.events.USER_REMOVED) {
+        let user = <GithubUser>event.payload;
+        delete this.users[user.login];
+        this.usersSubject.next(this.users);
       } else if (event.name == TauriService.events.TOKEN_INVALID) {
         this.tokenStatus = { invalid: true, notSet: false };
         this.availableSubject.next(this.isAvailable());
@@ -78,10 +83,10 @@ export class GithubService implements TauriEventListener {
           this.availableSubject.next(this.isAvailable());
         }
       })
-      .catch((err: number) => {
-        if (err === GHDError.BadTokenError) {
+      .catch((err: GHDError) => {
+        if (err.type === GHDErrorType.BadTokenError) {
           this.tokenStatus = { invalid: true, notSet: false };
-        } else if (err === GHDError.TokenNotFoundError) {
+        } else if (err.type === GHDErrorType.TokenNotFoundError) {
           this.tokenStatus = { invalid: false, notSet: true };
         }
         this.availableSubject.next(this.isAvailable());
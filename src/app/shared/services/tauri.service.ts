@@ -18,7 +18,24 @@ import { listen } from "@tauri-apps/api/event";
 import { exit as tauriExit } from "@tauri-apps/api/process";
 import { register } from "@tauri-apps/api/globalShortcut";
 import { getVersion as tauriGetVersion } from "@tauri-apps/api/app";
-import { GithubUser, PullRequestEntry, PullRequestInfo } from "../types";
+import {
+  ActivityKind,
+  GithubTeam,
+  GithubUser,
+  InboxItem,
+  Involvement,
+  IssueEntry,
+  IssueInfo,
+  NotificationSettings,
+  PullRequestEntry,
+  PullRequestInfo,
+  RateLimitInfo,
+  SavedQuery,
+  SavedQueryResults,
+  Settings,
+  TrackedRepo,
+  UserRefreshEntry,
+} from "../types";
 
 export type TauriListenerEvent = {
   name: string;
@@ -37,9 +54,18 @@ export class TauriService {
   public static events = {
     ITERATION: "iteration",
     USER_UPDATE: "user_update",
+    USER_REFRESHED: "user_refreshed",
     TOKEN_SET: "token_set",
-    USER_DATA_UPDATE: "user_data_update",
     TOKEN_INVALID: "token_invalid",
+    RATE_LIMIT_UPDATE: "rate_limit_update",
+    PR_INFO_UPDATE: "pr_info_update",
+    ISSUES_CHANGED: "issues_changed",
+    SETTINGS_CHANGED: "settings_changed",
+    USER_REMOVED: "user_removed",
+    TEAM_UPDATE: "team_update",
+    REPO_UPDATE: "repo_update",
+    QUERY_UPDATE: "query_update",
+    QUERY_REMOVED: "query_removed",
   };
 
   private listeners: Map<string, Map<string, TauriEventListener>>;
@@ -113,8 +139,8 @@ export class TauriService {
     return invoke("get_token");
   }
 
-  public setToken(token: string): Promise<boolean> {
-    return invoke("set_token", { token: token });
+  public setToken(token: string, apiUrl?: string): Promise<boolean> {
+    return invoke("set_token", { token: token, apiUrl: apiUrl });
   }
 
   public checkUserExists(username: string): Promise<GithubUser> {
@@ -125,10 +151,91 @@ export class TauriService {
     return invoke("add_tracked_user", { username: username });
   }
 
+  public removeTrackedUser(login: string): Promise<GithubUser> {
+    return invoke("remove_tracked_user", { login: login });
+  }
+
   public getTrackedUsers(): Promise<GithubUser[]> {
     return invoke("get_tracked_users");
   }
 
+  public getUserRefresh(login: string): Promise<UserRefreshEntry> {
+    return invoke("get_user_refresh", { login: login });
+  }
+
+  public setUserRefreshInterval(
+    login: string,
+    interval?: number,
+  ): Promise<UserRefreshEntry> {
+    return invoke("set_user_refresh_interval", {
+      login: login,
+      interval: interval,
+    });
+  }
+
+  public setUserPriority(
+    login: string,
+    priority: number,
+  ): Promise<UserRefreshEntry> {
+    return invoke("set_user_priority", { login: login, priority: priority });
+  }
+
+  public addTrackedTeam(team: string): Promise<GithubTeam> {
+    return invoke("add_tracked_team", { team: team });
+  }
+
+  public getTrackedTeams(): Promise<GithubTeam[]> {
+    return invoke("get_tracked_teams");
+  }
+
+  public getTeamMembers(teamId: number): Promise<GithubUser[]> {
+    return invoke("team_get_members", { teamId: teamId });
+  }
+
+  public getTeamReviewRequests(team: string): Promise<PullRequestEntry[]> {
+    return invoke("team_get_review_requests", { team: team });
+  }
+
+  public addTrackedRepo(repo: string): Promise<TrackedRepo> {
+    return invoke("add_tracked_repo", { repo: repo });
+  }
+
+  public getTrackedRepos(): Promise<TrackedRepo[]> {
+    return invoke("get_tracked_repos");
+  }
+
+  public getRepoPullRequests(repo: string): Promise<PullRequestEntry[]> {
+    return invoke("repo_get_pull_requests", { repo: repo });
+  }
+
+  public getRepoIssues(repo: string): Promise<IssueEntry[]> {
+    return invoke("repo_get_issues", { repo: repo });
+  }
+
+  public addSavedQuery(name: string, query: string): Promise<SavedQuery> {
+    return invoke("add_saved_query", { name: name, query: query });
+  }
+
+  public getSavedQueries(): Promise<SavedQuery[]> {
+    return invoke("get_saved_queries");
+  }
+
+  public removeSavedQuery(id: number): Promise<SavedQuery> {
+    return invoke("remove_saved_query", { id: id });
+  }
+
+  public getSavedQueryResults(id: number): Promise<SavedQueryResults> {
+    return invoke("query_get_results", { id: id });
+  }
+
+  public getInbox(): Promise<InboxItem[]> {
+    return invoke("get_inbox");
+  }
+
+  public getRateLimit(): Promise<RateLimitInfo> {
+    return invoke("get_rate_limit");
+  }
+
   public markPullRequestViewed(prid: number): Promise<void> {
     return invoke("pr_mark_viewed", { prid: prid });
   }
@@ -145,10 +252,46 @@ export class TauriService {
     return invoke("pr_get_list_by_involved", { login: login });
   }
 
+  public getPullRequestsByInvolvement(
+    login: string,
+    involvement: Involvement,
+  ): Promise<PullRequestEntry[]> {
+    return invoke("pr_get_list_by_involvement", {
+      login: login,
+      involvement: involvement,
+    });
+  }
+
   public getPullRequestInfo(prid: number): Promise<PullRequestInfo> {
     return invoke("pr_get_info", { prid: prid });
   }
 
+  public getIssuesByAuthor(login: string): Promise<IssueEntry[]> {
+    return invoke("issue_get_list_by_author", { login: login });
+  }
+
+  public getInvolvedIssues(login: string): Promise<IssueEntry[]> {
+    return invoke("issue_get_list_by_involved", { login: login });
+  }
+
+  public getIssuesByInvolvement(
+    login: string,
+    involvement: Involvement,
+  ): Promise<IssueEntry[]> {
+    return invoke("issue_get_list_by_involvement", {
+      login: login,
+      involvement: involvement,
+    });
+  }
+
+  public getAssignedIssues(login: string): Promise<IssueEntry[]> {
+    return invoke("issue_get_list_assigned", { login: login });
+  }
+
+  public getIssueInfo(issueId: number): Promise<IssueInfo> {
+    return invoke("issue_get_info", { issueId: issueId });
+  }
+
   public archiveIssue(issueId: number): Promise<void> {
     return invoke("archive_issue", { issueId: issueId });
   }
@@ -156,4 +299,26 @@ export class TauriService {
   public archiveIssueMany(issuelst: number[]): Promise<void> {
     return invoke("archive_issue_many", { issues: issuelst });
   }
+
+  public getNotificationSettings(): Promise<NotificationSettings> {
+    return invoke("notifications_get_settings");
+  }
+
+  public setNotificationsEnabled(
+    kind: ActivityKind,
+    enabled: boolean,
+  ): Promise<void> {
+    return invoke("notifications_set_enabled", {
+      kind: kind,
+      enabled: enabled,
+    });
+  }
+
+  public getSettings(): Promise<Settings> {
+    return invoke("get_settings");
+  }
+
+  public setSetting(key: string, value: string): Promise<Settings> {
+    return invoke("set_setting", { key: key, value: value });
+  }
 }
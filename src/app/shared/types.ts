@@ -14,18 +14,52 @@
 
 import { Observable } from "rxjs";
 
-export enum GHDError {
-  TokenNotFoundError,
-  BadTokenError,
-  UserNotSetError,
-  UserNotFoundError,
-  NeverRefreshedError,
-  BadRequest,
-  UnknownError,
-  NotFoundError,
-  DBVersionInTheFuture,
+export enum GHDErrorType {
+  TokenNotFoundError = "TokenNotFoundError",
+  BadTokenError = "BadTokenError",
+  UserNotSetError = "UserNotSetError",
+  UserNotFoundError = "UserNotFoundError",
+  NeverRefreshedError = "NeverRefreshedError",
+  BadRequest = "BadRequest",
+  NotFoundError = "NotFoundError",
+  DBVersionInTheFuture = "DBVersionInTheFuture",
+  RepositoryNotFoundError = "RepositoryNotFoundError",
+  PullRequestNotFoundError = "PullRequestNotFoundError",
+  IssueNotFoundError = "IssueNotFoundError",
+  TeamNotFoundError = "TeamNotFoundError",
+  SavedQueryNotFoundError = "SavedQueryNotFoundError",
+  RateLimitedError = "RateLimitedError",
+  ForbiddenError = "ForbiddenError",
+  NetworkError = "NetworkError",
+  HTTPError = "HTTPError",
+  GraphQLError = "GraphQLError",
+  DecodeError = "DecodeError",
+  DBError = "DBError",
+  UnexpectedDataError = "UnexpectedDataError",
+  InvalidStateError = "InvalidStateError",
+  SettingNotFoundError = "SettingNotFoundError",
+  InvalidSettingError = "InvalidSettingError",
+  InvalidSavedQueryError = "InvalidSavedQueryError",
 }
 
+export type GQLError = {
+  message: string;
+  type?: string;
+  path?: (string | number)[];
+};
+
+/// Errors returned by the backend. Depending on 'type', some of the optional
+/// fields will be populated.
+///
+export type GHDError = {
+  type: GHDErrorType;
+  context?: string;
+  source?: string;
+  status?: number;
+  errors?: GQLError[];
+  key?: string;
+};
+
 export type GithubUser = {
   id: number;
   login: string;
@@ -33,6 +67,51 @@ export type GithubUser = {
   avatar_url: string;
 };
 
+/// An organization's team, identified by `org/slug`.
+///
+export type GithubTeam = {
+  id: number;
+  org: string;
+  slug: string;
+  name: string;
+};
+
+/// A tracked repository, identified by `owner/name`.
+///
+export type TrackedRepo = {
+  id: number;
+  owner: string;
+  name: string;
+};
+
+/// A named Github search query, whose results are kept up to date.
+///
+export type SavedQuery = {
+  id: number;
+  name: string;
+  query: string;
+};
+
+/// Reports that a user's refresh has completed. `error` is set if the refresh
+/// failed.
+///
+export type UserRefreshCompleted = {
+  login: string;
+  updated: boolean;
+  error?: string;
+};
+
+/// A user's refresh state. If `refresh_interval` is not set, the user is
+/// refreshed at the global refresh interval.
+///
+export type UserRefreshEntry = {
+  id: number;
+  login: string;
+  refresh_at?: number;
+  refresh_interval?: number;
+  priority: number;
+};
+
 export type PullRequestEntry = {
   id: number;
   number: number;
@@ -51,6 +130,110 @@ export type PullRequestEntry = {
   is_draft: boolean;
   review_decision: string;
   merged_at?: number;
+  checks_state?: string;
+  mergeable?: string;
+  merge_state_status?: string;
+  base_ref_name?: string;
+  head_ref_name?: string;
+  merge_queue_position?: number;
+  merge_queue_state?: string;
+  merge_queue_enqueued_at?: number;
+  merge_queue_eta?: number;
+};
+
+export type IssueEntry = {
+  id: number;
+  number: number;
+  title: string;
+  author: string;
+  author_id: string;
+  url: string;
+  repo_owner: string;
+  repo_name: string;
+  state: string;
+  created_at: number;
+  updated_at: number;
+  closed_at?: number;
+  is_pull_request: boolean;
+  last_viewed?: number;
+};
+
+/// The Pull Requests and Issues matching a saved query.
+///
+export type SavedQueryResults = {
+  query: SavedQuery;
+  prs: PullRequestEntry[];
+  issues: IssueEntry[];
+};
+
+/// How a user is involved with an Issue or Pull Request.
+///
+export type Involvement =
+  | "author"
+  | "assignee"
+  | "mentioned"
+  | "commenter"
+  | "review_requested"
+  | "reviewed";
+
+/// Why an Issue or Pull Request needs the main user's attention, from most to
+/// least important.
+///
+export type InboxReason =
+  | "review_requested"
+  | "changes_requested"
+  | "checks_failing"
+  | "team_review_requested"
+  | "mentioned"
+  | "updated";
+
+export type InboxReasonEntry = {
+  reason: InboxReason;
+  explanation: string;
+};
+
+/// An Issue or Pull Request needing the main user's attention. The first
+/// reason is the one the item is ranked by.
+///
+export type InboxItem = {
+  issue: IssueEntry;
+  reasons: InboxReasonEntry[];
+};
+
+/// The fields of an Issue or Pull Request we track changes for.
+///
+export type IssueChangeFields = {
+  title: string;
+  state: string;
+  updated_at: number;
+  closed_at?: number;
+  is_draft?: boolean;
+  review_decision?: string;
+  merged_at?: number;
+};
+
+/// Describes how an Issue or Pull Request changed. 'before' is not set if it
+/// is new.
+///
+export type IssueChange = {
+  id: number;
+  is_pull_request: boolean;
+  before?: IssueChangeFields;
+  after: IssueChangeFields;
+};
+
+/// Payload of the 'issues_changed' event, describing the changes to a user's
+/// Issues and Pull Requests.
+///
+export type IssuesChangeset = {
+  user_id: number;
+  inserted: number[];
+  updated: number[];
+  state_changed: number[];
+  closed: number[];
+  merged: number[];
+  removed: number[];
+  changes: IssueChange[];
 };
 
 /// Used in the Dashboard's Pull Request Table
@@ -67,6 +250,8 @@ export type PRTableEntry = {
   lastUpdate: number;
   lastUpdateObs: Observable<string>;
   reviewDecision: string;
+  needsRebase: boolean;
+  changed: boolean;
 };
 
 /// Used in the Dashboard's Pull Requests Table
@@ -94,6 +279,65 @@ export type PullRequestInfo = {
   total_comments: number;
   participants: GithubUser[];
   reviews: UserReview[];
+  checks_state?: string;
+  checks: CheckInfo[];
+  mergeable: string;
+  merge_state_status: string;
+  base_ref_name: string;
+  head_ref_name: string;
+  merge_queue?: MergeQueueEntry;
+};
+
+/// Represents a Pull Request's entry in its base branch's merge queue.
+///
+export type MergeQueueEntry = {
+  position: number;
+  state: string;
+  enqueued_at: string;
+  enqueued_at_ts: number;
+  estimated_time_to_merge?: number;
+};
+
+export type CheckInfo = {
+  name: string;
+  state: string;
+  description?: string;
+  url?: string;
+};
+
+/// Represents the information for a specific Issue.
+///
+export type IssueInfo = {
+  number: number;
+  title: string;
+  body_html: string;
+  author: GithubUser;
+  repo_owner: string;
+  repo_name: string;
+  url: string;
+  state: string;
+  milestone?: Milestone;
+  labels: Label[];
+  total_comments: number;
+  assignees: GithubUser[];
+  participants: GithubUser[];
+  linked_prs: LinkedPullRequest[];
+  reactions: Reaction[];
+};
+
+export type LinkedPullRequest = {
+  number: number;
+  title: string;
+  url: string;
+  state: string;
+  repo_owner: string;
+  repo_name: string;
+  will_close: boolean;
+};
+
+export type Reaction = {
+  content: string;
+  count: number;
 };
 
 /// Represents a Milestone
@@ -118,3 +362,54 @@ export type UserReview = {
   author: GithubUser;
   state: string;
 };
+
+/// Represents a rate limit budget for one of the Github APIs.
+///
+export type RateLimitBudget = {
+  limit?: number;
+  remaining?: number;
+  reset_at?: number;
+  last_cost?: number;
+};
+
+/// Represents the current rate limit information.
+///
+export type RateLimitInfo = {
+  graphql: RateLimitBudget;
+  rest: RateLimitBudget;
+  paused_until?: number;
+};
+
+/// Kinds of activity on tracked Pull Requests and Issues that may raise
+/// desktop notifications.
+///
+export enum ActivityKind {
+  ReviewRequested = "review_requested",
+  Approved = "approved",
+  ChangesRequested = "changes_requested",
+  Merged = "merged",
+  NewComment = "new_comment",
+  Mention = "mention",
+}
+
+/// Per activity kind notification toggles.
+///
+export type NotificationSettings = {
+  review_requested: boolean;
+  approved: boolean;
+  changes_requested: boolean;
+  merged: boolean;
+  new_comment: boolean;
+  mention: boolean;
+};
+
+/// GHD's settings. Intervals are in seconds.
+///
+export type Settings = {
+  refresh_interval: number;
+  poll_interval: number;
+  error_backoff: number;
+  refresh_concurrency: number;
+  reconcile_interval: number;
+  max_pages: number;
+};